@@ -1,15 +1,667 @@
-use i8080_emulator::Emulator;
+use clap::{App, AppSettings, Arg, ArgMatches, SubCommand};
+use i8080_emulator::{
+    altair::{self, Altair},
+    coverage::CodeDataLogger,
+    cpm::{Cpm, DiskImage, Exit, Geometry, StdConsole},
+    instruction::{Disassembler, Syntax},
+    io::dip_switches::DipSwitches,
+    mmu::Mmu,
+    profiler::Profiler,
+    rom_set::{
+        database::{self, Game},
+        RomSource,
+    },
+    symbols::SymbolTable,
+    trace::{
+        compare::{self, Comparison, Side, TraceLine},
+        CsvTracer, JsonLinesTracer,
+    },
+};
 use simplelog::{Config, LevelFilter, SimpleLogger};
-
-mod rom;
-use self::rom::rom;
+use std::{
+    cell::RefCell,
+    fs::{self, File},
+    io::{self, BufReader, BufWriter, Write},
+    process,
+    rc::Rc,
+};
 
 fn main() {
+    let syntax = Arg::with_name("syntax")
+        .long("syntax")
+        .takes_value(true)
+        .possible_values(&["intel", "zilog"])
+        .default_value("intel")
+        .help("Mnemonic style for instructions");
+    let symbols = Arg::with_name("symbols")
+        .long("symbols")
+        .takes_value(true)
+        .value_name("FILE")
+        .help("Loads labels from a symbol file to name addresses");
+    let rom_path = Arg::with_name("rom")
+        .long("rom")
+        .takes_value(true)
+        .value_name("PATH")
+        .required(true)
+        .help("Loads a known game's ROM chips from a directory or .zip");
+    let matches = App::new("space_invaders")
+        .setting(AppSettings::VersionlessSubcommands)
+        .subcommand(
+            SubCommand::with_name("run")
+                .about("Runs the Space Invaders ROM (default)")
+                .arg(syntax.clone())
+                .arg(symbols.clone())
+                .arg(rom_path.clone())
+                .arg(
+                    Arg::with_name("trace")
+                        .long("trace")
+                        .takes_value(true)
+                        .value_name("FILE")
+                        .help("Writes a per-instruction execution trace to FILE"),
+                )
+                .arg(
+                    Arg::with_name("trace-format")
+                        .long("trace-format")
+                        .takes_value(true)
+                        .possible_values(&["jsonl", "csv"])
+                        .default_value("jsonl")
+                        .help("Format of the execution trace"),
+                )
+                .arg(
+                    Arg::with_name("steps")
+                        .long("steps")
+                        .takes_value(true)
+                        .value_name("N")
+                        .help("Stops after executing N steps"),
+                )
+                .arg(
+                    Arg::with_name("frames")
+                        .long("frames")
+                        .takes_value(true)
+                        .value_name("N")
+                        .conflicts_with("steps")
+                        .help("Stops after running N video frames"),
+                )
+                .arg(
+                    Arg::with_name("profile")
+                        .long("profile")
+                        .takes_value(true)
+                        .value_name("FILE")
+                        .help("Writes a hot-spot and call graph report to FILE on exit"),
+                )
+                .arg(
+                    Arg::with_name("cdl")
+                        .long("cdl")
+                        .takes_value(true)
+                        .value_name("FILE")
+                        .help("Writes a ROM code/data coverage map to FILE on exit"),
+                )
+                .arg(
+                    Arg::with_name("screenshot")
+                        .long("screenshot")
+                        .takes_value(true)
+                        .value_name("FILE")
+                        .help("Saves the screen as a PNG to FILE on exit"),
+                )
+                .arg(
+                    Arg::with_name("dip-config")
+                        .long("dip-config")
+                        .takes_value(true)
+                        .value_name("FILE")
+                        .help("Loads DIP switch settings from a file of key = value lines"),
+                )
+                .arg(
+                    Arg::with_name("lives")
+                        .long("lives")
+                        .takes_value(true)
+                        .possible_values(&["3", "4", "5", "6"])
+                        .help("Sets the DIP switches for bases per game"),
+                )
+                .arg(
+                    Arg::with_name("bonus-life")
+                        .long("bonus-life")
+                        .takes_value(true)
+                        .possible_values(&["1000", "1500"])
+                        .help("Sets the DIP switch for the score that earns a bonus base"),
+                )
+                .arg(
+                    Arg::with_name("coin-info")
+                        .long("coin-info")
+                        .takes_value(true)
+                        .possible_values(&["on", "off"])
+                        .help("Sets the DIP switch showing coin info on the attract screen"),
+                )
+                .arg(
+                    Arg::with_name("break")
+                        .long("break")
+                        .takes_value(true)
+                        .multiple(true)
+                        .number_of_values(1)
+                        .value_name("LOCATION")
+                        .help("Stops at an address or symbol and prints the CPU state"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("disasm")
+                .about("Prints a disassembly of the Space Invaders ROM")
+                .arg(syntax)
+                .arg(symbols)
+                .arg(rom_path),
+        )
+        .subcommand(
+            SubCommand::with_name("cpm")
+                .about("Runs a CP/M .COM program with the console on stdin and stdout")
+                .arg(
+                    Arg::with_name("program")
+                        .required(true)
+                        .value_name("PROGRAM")
+                        .help("The .COM file to run"),
+                )
+                .arg(
+                    Arg::with_name("drive")
+                        .long("drive")
+                        .takes_value(true)
+                        .multiple(true)
+                        .number_of_values(1)
+                        .value_name("X=DIR")
+                        .help("Backs drive X: with a host directory; A: is the current directory by default"),
+                )
+                .arg(
+                    Arg::with_name("disk")
+                        .long("disk")
+                        .takes_value(true)
+                        .multiple(true)
+                        .number_of_values(1)
+                        .value_name("X=IMAGE")
+                        .help("Puts a disk image in drive X: for programs using the BIOS, saving it on exit"),
+                )
+                .arg(
+                    Arg::with_name("disk-format")
+                        .long("disk-format")
+                        .takes_value(true)
+                        .default_value("ibm-3740")
+                        .value_name("GEOMETRY")
+                        .help("Disk image geometry, e.g. tracks=77,sectors=26,skew=6,block=1024,dir=64"),
+                )
+                .arg(
+                    Arg::with_name("args")
+                        .multiple(true)
+                        .value_name("ARGS")
+                        .help("Command tail passed to the program"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("altair")
+                .about("Runs a memory image on an Altair 8800 with the terminal on stdin and stdout")
+                .arg(
+                    Arg::with_name("image")
+                        .required(true)
+                        .value_name("IMAGE")
+                        .help("Binary memory image, or Intel HEX file if it ends in .hex"),
+                )
+                .arg(
+                    Arg::with_name("addr")
+                        .long("addr")
+                        .takes_value(true)
+                        .default_value("0")
+                        .help("Address a binary image is loaded at"),
+                )
+                .arg(
+                    Arg::with_name("start")
+                        .long("start")
+                        .takes_value(true)
+                        .help("Address to start at; defaults to the load address"),
+                )
+                .arg(
+                    Arg::with_name("sense")
+                        .long("sense")
+                        .takes_value(true)
+                        .default_value("0")
+                        .help("Front panel sense switch setting, A15 in the top bit"),
+                )
+                .arg(
+                    Arg::with_name("disk")
+                        .long("disk")
+                        .takes_value(true)
+                        .multiple(true)
+                        .number_of_values(1)
+                        .value_name("N=IMAGE")
+                        .help("Puts a .dsk image in 88-DCDD drive N, saving it when the machine halts"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("trace-diff")
+                .about("Reports the first step where a trace differs from a reference trace")
+                .arg(
+                    Arg::with_name("ours")
+                        .required(true)
+                        .help("Trace written by --trace"),
+                )
+                .arg(
+                    Arg::with_name("reference")
+                        .required(true)
+                        .help("Reference trace, e.g. from another emulator"),
+                )
+                .arg(
+                    Arg::with_name("context")
+                        .long("context")
+                        .takes_value(true)
+                        .default_value("5")
+                        .help("Number of steps to show around the divergence"),
+                ),
+        )
+        .get_matches();
+
+    match matches.subcommand() {
+        ("disasm", Some(args)) => disasm(args),
+        ("cpm", Some(args)) => cpm(args),
+        ("altair", Some(args)) => altair(args),
+        ("trace-diff", Some(args)) => trace_diff(args),
+        ("run", Some(args)) => run(args),
+        _ => run(&ArgMatches::default()),
+    }
+}
+
+fn syntax(args: &ArgMatches) -> Syntax {
+    match args.value_of("syntax") {
+        Some("zilog") => Syntax::Zilog,
+        _ => Syntax::Intel,
+    }
+}
+
+fn load_rom(args: &ArgMatches) -> (&'static Game, Vec<u8>) {
+    let path = match args.value_of("rom") {
+        Some(path) => path,
+        None => {
+            eprintln!(
+                "no ROM set given: pass --rom with a directory or .zip of the game's ROM chips"
+            );
+            process::exit(2);
+        }
+    };
+    match RomSource::open(path).and_then(|mut source| database::identify(&mut source)) {
+        Ok((game, rom)) => {
+            eprintln!("Found {} ({})", game.rom_set.description, game.rom_set.name);
+            (game, rom)
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+    }
+}
+
+fn symbols(args: &ArgMatches) -> Rc<SymbolTable> {
+    match args.value_of("symbols").map(SymbolTable::load) {
+        Some(Ok(symbols)) => Rc::new(symbols),
+        Some(Err(e)) => {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+        None => Rc::new(SymbolTable::new()),
+    }
+}
+
+#[allow(clippy::field_reassign_with_default)]
+fn run(args: &ArgMatches) {
     let mut config = Config::default();
     config.time = None;
     config.level = None;
     SimpleLogger::init(LevelFilter::Info, config).unwrap();
 
-    let mut emulator = Emulator::new(rom());
-    emulator.run();
+    let syntax = syntax(args);
+    let symbols = symbols(args);
+    let breakpoints: Vec<u16> = args
+        .values_of("break")
+        .into_iter()
+        .flatten()
+        .map(|location| match symbols.resolve(location) {
+            Some(addr) => addr,
+            None => {
+                eprintln!("unknown breakpoint location: {}", location);
+                process::exit(2);
+            }
+        })
+        .collect();
+
+    let (game, rom) = load_rom(args);
+    let mut emulator = match game.emulator(rom) {
+        Ok(emulator) => emulator,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+    };
+    emulator.cpu_mut().set_syntax(syntax);
+    emulator.interconnect.io.set_dip_switches(dip_switches(args));
+    if let Some(path) = args.value_of("trace") {
+        let out = BufWriter::new(File::create(path).expect("unable to create trace file"));
+        match args.value_of("trace-format") {
+            Some("csv") => emulator.add_tracer(
+                CsvTracer::new(out)
+                    .with_syntax(syntax)
+                    .with_symbols(symbols.clone()),
+            ),
+            _ => emulator.add_tracer(
+                JsonLinesTracer::new(out)
+                    .with_syntax(syntax)
+                    .with_symbols(symbols.clone()),
+            ),
+        }
+    }
+    // The profiler's shadow call stack doubles as the backtrace printed at
+    // breakpoints.
+    let profiler = if args.is_present("profile") || !breakpoints.is_empty() {
+        let profiler = Profiler::new().with_symbols(symbols.clone());
+        let profiler = Rc::new(RefCell::new(profiler));
+        emulator.add_tracer(profiler.clone());
+        Some(profiler)
+    } else {
+        None
+    };
+    let cdl = args.value_of("cdl").map(|path| {
+        let cdl = Rc::new(RefCell::new(CodeDataLogger::new(emulator.mmu().rom_len())));
+        emulator.add_observer(cdl.clone());
+        (path, cdl)
+    });
+
+    let steps = match args.value_of("steps").map(str::parse::<u64>) {
+        Some(Ok(steps)) => Some(steps),
+        Some(Err(_)) => {
+            eprintln!("--steps must be a number");
+            process::exit(2);
+        }
+        None => None,
+    };
+    let frames = match args.value_of("frames").map(str::parse::<u64>) {
+        Some(Ok(frames)) => Some(frames),
+        Some(Err(_)) => {
+            eprintln!("--frames must be a number");
+            process::exit(2);
+        }
+        None => None,
+    };
+    if steps.is_none() && breakpoints.is_empty() {
+        while frames.is_none_or(|frames| emulator.beam().frame() < frames) {
+            if let Err(e) = emulator.run_frame() {
+                eprintln!("{}", e);
+                break;
+            }
+        }
+    } else {
+        let mut step = 0;
+        while steps.is_none_or(|steps| step < steps)
+            && frames.is_none_or(|frames| emulator.beam().frame() < frames)
+        {
+            if let Err(e) = emulator.try_step() {
+                eprintln!("{}", e);
+                break;
+            }
+            step += 1;
+            let pc = emulator.cpu().pc();
+            if breakpoints.contains(&pc) {
+                eprintln!("Breakpoint at {} after {} steps", symbols.describe(pc), step);
+                eprintln!("{:?}", emulator.cpu().registers());
+                if let Some(profiler) = &profiler {
+                    for routine in profiler.borrow().call_stack().iter().rev() {
+                        eprintln!("    in {}", symbols.describe(*routine));
+                    }
+                }
+                break;
+            }
+        }
+    }
+    emulator.clear_tracers();
+    emulator.clear_observers();
+
+    if let (Some(path), Some(profiler)) = (args.value_of("profile"), profiler) {
+        fs::write(path, profiler.borrow().report(40)).expect("unable to write profile");
+    }
+    if let Some((path, cdl)) = cdl {
+        let cdl = cdl.borrow();
+        let out = File::create(path).expect("unable to create coverage map");
+        cdl.write_map(BufWriter::new(out))
+            .expect("unable to write coverage map");
+        eprintln!("{}", cdl.summary());
+    }
+    if let Some(path) = args.value_of("screenshot") {
+        if let Err(e) = emulator.frame().save_png(path) {
+            eprintln!("unable to save screenshot: {}", e);
+        }
+    }
+}
+
+/// DIP switch settings from `--dip-config`, overridden by the individual
+/// switch options.
+fn dip_switches(args: &ArgMatches) -> DipSwitches {
+    let switches = match args.value_of("dip-config") {
+        Some(path) => DipSwitches::load(path),
+        None => Ok(DipSwitches::default()),
+    };
+    let switches = switches.and_then(|mut switches| {
+        for key in &["lives", "bonus-life", "coin-info"] {
+            if let Some(value) = args.value_of(key) {
+                switches.set(key, value)?;
+            }
+        }
+        Ok(switches)
+    });
+    match switches {
+        Ok(switches) => switches,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+    }
+}
+
+fn disasm(args: &ArgMatches) {
+    let (_, rom) = load_rom(args);
+    let symbols = symbols(args);
+    let listing = Disassembler::new(&rom, 0)
+        .with_symbols(&symbols)
+        .listing(syntax(args));
+    print!("{}", listing);
+}
+
+fn cpm(args: &ArgMatches) {
+    let tail: Vec<&str> = args.values_of("args").into_iter().flatten().collect();
+    let mut cpm = Cpm::new(StdConsole::new());
+    let drive_args = |name| -> Vec<(char, &str)> {
+        let values = args.values_of(name).into_iter().flatten();
+        values
+            .map(|value| {
+                let mut chars = value.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(letter), Some('=')) => (letter, &value[2..]),
+                    _ => {
+                        eprintln!("--{} must look like X=PATH", name);
+                        process::exit(2);
+                    }
+                }
+            })
+            .collect()
+    };
+    let disks = drive_args("disk");
+    let mut drives = drive_args("drive");
+    drives.insert(0, ('A', "."));
+    for (letter, dir) in drives {
+        if let Err(e) = cpm.mount(letter, dir) {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+    }
+    let geometry = match args.value_of("disk-format").unwrap().parse::<Geometry>() {
+        Ok(geometry) => geometry,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+    };
+    for &(letter, path) in &disks {
+        let image = DiskImage::open(geometry, path).and_then(|image| cpm.insert_disk(letter, image));
+        if let Err(e) = image {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+    }
+    if let Err(e) = cpm.load_com_file(args.value_of("program").unwrap(), &tail.join(" ")) {
+        eprintln!("{}", e);
+        process::exit(2);
+    }
+    let exit = cpm.run();
+    let _ = io::stdout().flush();
+    for (letter, path) in disks {
+        if let Some(Err(e)) = cpm.eject_disk(letter).map(|image| image.save(path)) {
+            eprintln!("{}", e);
+        }
+    }
+    match exit {
+        Ok(Exit::WarmBoot) | Ok(Exit::EndOfInput) => {}
+        Ok(Exit::Halted) => eprintln!("Halted at 0x{:04x}", cpm.emulator().cpu().pc()),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Parses a number given in decimal or, with a 0x prefix, in hex.
+fn number(args: &ArgMatches, name: &str) -> Option<u16> {
+    let value = args.value_of(name)?;
+    let parsed = match value.strip_prefix("0x") {
+        Some(hex) => u16::from_str_radix(hex, 16),
+        None => value.parse(),
+    };
+    match parsed {
+        Ok(n) => Some(n),
+        Err(_) => {
+            eprintln!("--{} must be a number from 0 to 0xffff", name);
+            process::exit(2);
+        }
+    }
+}
+
+fn altair(args: &ArgMatches) {
+    let mut altair = Altair::new(StdConsole::new());
+    let sense = number(args, "sense").unwrap_or(0);
+    if sense > 0xff {
+        eprintln!("--sense must be a number from 0 to 0xff");
+        process::exit(2);
+    }
+    altair.set_sense_switches(sense as u8);
+    let mut disks = Vec::new();
+    for value in args.values_of("disk").into_iter().flatten() {
+        let (drive, path) = match value.find('=') {
+            Some(i) => (value[..i].parse::<usize>(), &value[i + 1..]),
+            None => ("".parse::<usize>(), value),
+        };
+        let drive = match drive {
+            Ok(drive) => drive,
+            Err(_) => {
+                eprintln!("--disk must look like N=IMAGE");
+                process::exit(2);
+            }
+        };
+        let inserted = fs::read(path)
+            .map_err(Into::into)
+            .and_then(|image| altair.insert_disk(drive, image));
+        if let Err(e) = inserted {
+            eprintln!("{}: {}", path, e);
+            process::exit(2);
+        }
+        disks.push((drive, path));
+    }
+
+    let path = args.value_of("image").unwrap();
+    let loaded = if path.to_ascii_lowercase().ends_with(".hex") {
+        fs::read_to_string(path)
+            .map_err(Into::into)
+            .and_then(|text| altair.load_hex(&text))
+    } else {
+        let addr = number(args, "addr").unwrap_or(0);
+        let start = number(args, "start").unwrap_or(addr);
+        fs::read(path)
+            .map_err(Into::into)
+            .and_then(|image| altair.load_binary(&image, addr, start))
+    };
+    if let Err(e) = loaded {
+        eprintln!("{}: {}", path, e);
+        process::exit(2);
+    }
+    let exit = altair.run();
+    let _ = io::stdout().flush();
+    match exit {
+        Ok(altair::Exit::EndOfInput) => {}
+        Ok(altair::Exit::Halted) => eprintln!("Halted at 0x{:04x}", altair.emulator().cpu().pc()),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
+    for (drive, path) in disks {
+        if let Some(Err(e)) = altair.eject_disk(drive).map(|image| fs::write(path, image)) {
+            eprintln!("unable to write {}: {}", path, e);
+        }
+    }
+}
+
+fn trace_diff(args: &ArgMatches) {
+    let open = |name| {
+        let path = args.value_of(name).unwrap();
+        match File::open(path) {
+            Ok(file) => BufReader::new(file),
+            Err(e) => {
+                eprintln!("unable to open {}: {}", path, e);
+                process::exit(2);
+            }
+        }
+    };
+    let context = match args.value_of("context").unwrap().parse() {
+        Ok(n) => n,
+        Err(_) => {
+            eprintln!("--context must be a number");
+            process::exit(2);
+        }
+    };
+    let comparison = match compare::compare(open("ours"), open("reference"), context) {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+    };
+
+    let print_step = |marker, (ours, reference): &(TraceLine, TraceLine)| {
+        println!("{} ours {:>8} | {}", marker, ours.number, ours.text);
+        println!("{}  ref {:>8} | {}", marker, reference.number, reference.text);
+    };
+    match comparison {
+        Comparison::Identical { steps } => println!("Traces match for all {} steps", steps),
+        Comparison::Truncated { steps, shorter } => {
+            let shorter = match shorter {
+                Side::Ours => "our trace",
+                Side::Reference => "the reference trace",
+            };
+            println!("Traces match for {} steps, then {} ends", steps, shorter);
+        }
+        Comparison::Diverged(d) => {
+            println!(
+                "First divergence at step {} (ours line {}, reference line {}):",
+                d.step, d.ours.number, d.reference.number
+            );
+            for difference in &d.differences {
+                println!("    {}", difference);
+            }
+            println!();
+            for step in &d.before {
+                print_step(" ", step);
+            }
+            print_step(">", &(d.ours, d.reference));
+            for step in &d.after {
+                print_step(" ", step);
+            }
+            process::exit(1);
+        }
+    }
 }
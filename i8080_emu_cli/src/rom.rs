@@ -1,551 +0,0 @@
-pub fn rom() -> Vec<u8> {
-    vec![
-        0x00, 0x00, 0x00, 0xC3, 0xD4, 0x18, 0x00, 0x00, 0xF5, 0xC5, 0xD5, 0xE5, 0xC3, 0x8C, 0x00,
-        0x00, 0xF5, 0xC5, 0xD5, 0xE5, 0x3E, 0x80, 0x32, 0x72, 0x20, 0x21, 0xC0, 0x20, 0x35, 0xCD,
-        0xCD, 0x17, 0xDB, 0x01, 0x0F, 0xDA, 0x67, 0x00, 0x3A, 0xEA, 0x20, 0xA7, 0xCA, 0x42, 0x00,
-        0x3A, 0xEB, 0x20, 0xFE, 0x99, 0xCA, 0x3E, 0x00, 0xC6, 0x01, 0x27, 0x32, 0xEB, 0x20, 0xCD,
-        0x47, 0x19, 0xAF, 0x32, 0xEA, 0x20, 0x3A, 0xE9, 0x20, 0xA7, 0xCA, 0x82, 0x00, 0x3A, 0xEF,
-        0x20, 0xA7, 0xC2, 0x6F, 0x00, 0x3A, 0xEB, 0x20, 0xA7, 0xC2, 0x5D, 0x00, 0xCD, 0xBF, 0x0A,
-        0xC3, 0x82, 0x00, 0x3A, 0x93, 0x20, 0xA7, 0xC2, 0x82, 0x00, 0xC3, 0x65, 0x07, 0x3E, 0x01,
-        0x32, 0xEA, 0x20, 0xC3, 0x3F, 0x00, 0xCD, 0x40, 0x17, 0x3A, 0x32, 0x20, 0x32, 0x80, 0x20,
-        0xCD, 0x00, 0x01, 0xCD, 0x48, 0x02, 0xCD, 0x13, 0x09, 0x00, 0xE1, 0xD1, 0xC1, 0xF1, 0xFB,
-        0xC9, 0x00, 0x00, 0x00, 0x00, 0xAF, 0x32, 0x72, 0x20, 0x3A, 0xE9, 0x20, 0xA7, 0xCA, 0x82,
-        0x00, 0x3A, 0xEF, 0x20, 0xA7, 0xC2, 0xA5, 0x00, 0x3A, 0xC1, 0x20, 0x0F, 0xD2, 0x82, 0x00,
-        0x21, 0x20, 0x20, 0xCD, 0x4B, 0x02, 0xCD, 0x41, 0x01, 0xC3, 0x82, 0x00, 0xCD, 0x86, 0x08,
-        0xE5, 0x7E, 0x23, 0x66, 0x6F, 0x22, 0x09, 0x20, 0x22, 0x0B, 0x20, 0xE1, 0x2B, 0x7E, 0xFE,
-        0x03, 0xC2, 0xC8, 0x00, 0x3D, 0x32, 0x08, 0x20, 0xFE, 0xFE, 0x3E, 0x00, 0xC2, 0xD3, 0x00,
-        0x3C, 0x32, 0x0D, 0x20, 0xC9, 0x3E, 0x02, 0x32, 0xFB, 0x21, 0x32, 0xFB, 0x22, 0xC3, 0xE4,
-        0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x21, 0x02, 0x20, 0x7E, 0xA7, 0xC2, 0x38, 0x15, 0xE5, 0x3A, 0x06, 0x20, 0x6F, 0x3A,
-        0x67, 0x20, 0x67, 0x7E, 0xA7, 0xE1, 0xCA, 0x36, 0x01, 0x23, 0x23, 0x7E, 0x23, 0x46, 0xE6,
-        0xFE, 0x07, 0x07, 0x07, 0x5F, 0x16, 0x00, 0x21, 0x00, 0x1C, 0x19, 0xEB, 0x78, 0xA7, 0xC4,
-        0x3B, 0x01, 0x2A, 0x0B, 0x20, 0x06, 0x10, 0xCD, 0xD3, 0x15, 0xAF, 0x32, 0x00, 0x20, 0xC9,
-        0x21, 0x30, 0x00, 0x19, 0xEB, 0xC9, 0x3A, 0x68, 0x20, 0xA7, 0xC8, 0x3A, 0x00, 0x20, 0xA7,
-        0xC0, 0x3A, 0x67, 0x20, 0x67, 0x3A, 0x06, 0x20, 0x16, 0x02, 0x3C, 0xFE, 0x37, 0xCC, 0xA1,
-        0x01, 0x6F, 0x46, 0x05, 0xC2, 0x54, 0x01, 0x32, 0x06, 0x20, 0xCD, 0x7A, 0x01, 0x61, 0x22,
-        0x0B, 0x20, 0x7D, 0xFE, 0x28, 0xDA, 0x71, 0x19, 0x7A, 0x32, 0x04, 0x20, 0x3E, 0x01, 0x32,
-        0x00, 0x20, 0xC9, 0x16, 0x00, 0x7D, 0x21, 0x09, 0x20, 0x46, 0x23, 0x4E, 0xFE, 0x0B, 0xFA,
-        0x94, 0x01, 0xDE, 0x0B, 0x5F, 0x78, 0xC6, 0x10, 0x47, 0x7B, 0x14, 0xC3, 0x83, 0x01, 0x68,
-        0xA7, 0xC8, 0x5F, 0x79, 0xC6, 0x10, 0x4F, 0x7B, 0x3D, 0xC3, 0x95, 0x01, 0x15, 0xCA, 0xCD,
-        0x01, 0x21, 0x06, 0x20, 0x36, 0x00, 0x23, 0x4E, 0x36, 0x00, 0xCD, 0xD9, 0x01, 0x21, 0x05,
-        0x20, 0x7E, 0x3C, 0xE6, 0x01, 0x77, 0xAF, 0x21, 0x67, 0x20, 0x66, 0xC9, 0x00, 0x21, 0x00,
-        0x21, 0x06, 0x37, 0x36, 0x01, 0x23, 0x05, 0xC2, 0xC5, 0x01, 0xC9, 0xE1, 0xC9, 0x3E, 0x01,
-        0x06, 0xE0, 0x21, 0x02, 0x24, 0xC3, 0xCC, 0x14, 0x23, 0x46, 0x23, 0x79, 0x86, 0x77, 0x23,
-        0x78, 0x86, 0x77, 0xC9, 0x06, 0xC0, 0x11, 0x00, 0x1B, 0x21, 0x00, 0x20, 0xC3, 0x32, 0x1A,
-        0x21, 0x42, 0x21, 0xC3, 0xF8, 0x01, 0x21, 0x42, 0x22, 0x0E, 0x04, 0x11, 0x20, 0x1D, 0xD5,
-        0x06, 0x2C, 0xCD, 0x32, 0x1A, 0xD1, 0x0D, 0xC2, 0xFD, 0x01, 0xC9, 0x3E, 0x01, 0xC3, 0x1B,
-        0x02, 0x3E, 0x01, 0xC3, 0x14, 0x02, 0xAF, 0x11, 0x42, 0x22, 0xC3, 0x1E, 0x02, 0xAF, 0x11,
-        0x42, 0x21, 0x32, 0x81, 0x20, 0x01, 0x02, 0x16, 0x21, 0x06, 0x28, 0x3E, 0x04, 0xF5, 0xC5,
-        0x3A, 0x81, 0x20, 0xA7, 0xC2, 0x42, 0x02, 0xCD, 0x69, 0x1A, 0xC1, 0xF1, 0x3D, 0xC8, 0xD5,
-        0x11, 0xE0, 0x02, 0x19, 0xD1, 0xC3, 0x29, 0x02, 0xCD, 0x7C, 0x14, 0xC3, 0x35, 0x02, 0x21,
-        0x10, 0x20, 0x7E, 0xFE, 0xFF, 0xC8, 0xFE, 0xFE, 0xCA, 0x81, 0x02, 0x23, 0x46, 0x4F, 0xB0,
-        0x79, 0xC2, 0x77, 0x02, 0x23, 0x7E, 0xA7, 0xC2, 0x88, 0x02, 0x23, 0x5E, 0x23, 0x56, 0xE5,
-        0xEB, 0xE5, 0x21, 0x6F, 0x02, 0xE3, 0xD5, 0xE9, 0xE1, 0x11, 0x0C, 0x00, 0x19, 0xC3, 0x4B,
-        0x02, 0x05, 0x04, 0xC2, 0x7D, 0x02, 0x3D, 0x05, 0x70, 0x2B, 0x77, 0x11, 0x10, 0x00, 0x19,
-        0xC3, 0x4B, 0x02, 0x35, 0x2B, 0x2B, 0xC3, 0x81, 0x02, 0xE1, 0x23, 0x7E, 0xFE, 0xFF, 0xCA,
-        0x3B, 0x03, 0x23, 0x35, 0xC0, 0x47, 0xAF, 0x32, 0x68, 0x20, 0x32, 0x69, 0x20, 0x3E, 0x30,
-        0x32, 0x6A, 0x20, 0x78, 0x36, 0x05, 0x23, 0x35, 0xC2, 0x9B, 0x03, 0x2A, 0x1A, 0x20, 0x06,
-        0x10, 0xCD, 0x24, 0x14, 0x21, 0x10, 0x20, 0x11, 0x10, 0x1B, 0x06, 0x10, 0xCD, 0x32, 0x1A,
-        0x06, 0x00, 0xCD, 0xDC, 0x19, 0x3A, 0x6D, 0x20, 0xA7, 0xC0, 0x3A, 0xEF, 0x20, 0xA7, 0xC8,
-        0x31, 0x00, 0x24, 0xFB, 0xCD, 0xD7, 0x19, 0xCD, 0x2E, 0x09, 0xA7, 0xCA, 0x6D, 0x16, 0xCD,
-        0xE7, 0x18, 0x7E, 0xA7, 0xCA, 0x2C, 0x03, 0x3A, 0xCE, 0x20, 0xA7, 0xCA, 0x2C, 0x03, 0x3A,
-        0x67, 0x20, 0xF5, 0x0F, 0xDA, 0x32, 0x03, 0xCD, 0x0E, 0x02, 0xCD, 0x78, 0x08, 0x73, 0x23,
-        0x72, 0x2B, 0x2B, 0x70, 0x00, 0xCD, 0xE4, 0x01, 0xF1, 0x0F, 0x3E, 0x21, 0x06, 0x00, 0xD2,
-        0x12, 0x03, 0x06, 0x20, 0x3E, 0x22, 0x32, 0x67, 0x20, 0xCD, 0xB6, 0x0A, 0xAF, 0x32, 0x11,
-        0x20, 0x78, 0xD3, 0x05, 0x3C, 0x32, 0x98, 0x20, 0xCD, 0xD6, 0x09, 0xCD, 0x7F, 0x1A, 0xC3,
-        0xF9, 0x07, 0xCD, 0x7F, 0x1A, 0xC3, 0x17, 0x08, 0xCD, 0x09, 0x02, 0xC3, 0xF8, 0x02, 0x00,
-        0x00, 0x00, 0x21, 0x68, 0x20, 0x36, 0x01, 0x23, 0x7E, 0xA7, 0xC3, 0xB0, 0x03, 0x00, 0x2B,
-        0x36, 0x01, 0x3A, 0x1B, 0x20, 0x47, 0x3A, 0xEF, 0x20, 0xA7, 0xC2, 0x63, 0x03, 0x3A, 0x1D,
-        0x20, 0x0F, 0xDA, 0x81, 0x03, 0x0F, 0xDA, 0x8E, 0x03, 0xC3, 0x6F, 0x03, 0xCD, 0xC0, 0x17,
-        0x07, 0x07, 0xDA, 0x81, 0x03, 0x07, 0xDA, 0x8E, 0x03, 0x21, 0x18, 0x20, 0xCD, 0x3B, 0x1A,
-        0xCD, 0x47, 0x1A, 0xCD, 0x39, 0x14, 0x3E, 0x00, 0x32, 0x12, 0x20, 0xC9, 0x78, 0xFE, 0xD9,
-        0xCA, 0x6F, 0x03, 0x3C, 0x32, 0x1B, 0x20, 0xC3, 0x6F, 0x03, 0x78, 0xFE, 0x30, 0xCA, 0x6F,
-        0x03, 0x3D, 0x32, 0x1B, 0x20, 0xC3, 0x6F, 0x03, 0x3C, 0xE6, 0x01, 0x32, 0x15, 0x20, 0x07,
-        0x07, 0x07, 0x07, 0x21, 0x70, 0x1C, 0x85, 0x6F, 0x22, 0x18, 0x20, 0xC3, 0x6F, 0x03, 0xC2,
-        0x4A, 0x03, 0x23, 0x35, 0xC2, 0x4A, 0x03, 0xC3, 0x46, 0x03, 0x11, 0x2A, 0x20, 0xCD, 0x06,
-        0x1A, 0xE1, 0xD0, 0x23, 0x7E, 0xA7, 0xC8, 0xFE, 0x01, 0xCA, 0xFA, 0x03, 0xFE, 0x02, 0xCA,
-        0x0A, 0x04, 0x23, 0xFE, 0x03, 0xC2, 0x2A, 0x04, 0x35, 0xCA, 0x36, 0x04, 0x7E, 0xFE, 0x0F,
-        0xC0, 0xE5, 0xCD, 0x30, 0x04, 0xCD, 0x52, 0x14, 0xE1, 0x23, 0x34, 0x23, 0x23, 0x35, 0x35,
-        0x23, 0x35, 0x35, 0x35, 0x23, 0x36, 0x08, 0xCD, 0x30, 0x04, 0xC3, 0x00, 0x14, 0x3C, 0x77,
-        0x3A, 0x1B, 0x20, 0xC6, 0x08, 0x32, 0x2A, 0x20, 0xCD, 0x30, 0x04, 0xC3, 0x00, 0x14, 0xCD,
-        0x30, 0x04, 0xD5, 0xE5, 0xC5, 0xCD, 0x52, 0x14, 0xC1, 0xE1, 0xD1, 0x3A, 0x2C, 0x20, 0x85,
-        0x6F, 0x32, 0x29, 0x20, 0xCD, 0x91, 0x14, 0x3A, 0x61, 0x20, 0xA7, 0xC8, 0x32, 0x02, 0x20,
-        0xC9, 0xFE, 0x05, 0xC8, 0xC3, 0x36, 0x04, 0x21, 0x27, 0x20, 0xC3, 0x3B, 0x1A, 0xCD, 0x30,
-        0x04, 0xCD, 0x52, 0x14, 0x21, 0x25, 0x20, 0x11, 0x25, 0x1B, 0x06, 0x07, 0xCD, 0x32, 0x1A,
-        0x2A, 0x8D, 0x20, 0x2C, 0x7D, 0xFE, 0x63, 0xDA, 0x53, 0x04, 0x2E, 0x54, 0x22, 0x8D, 0x20,
-        0x2A, 0x8F, 0x20, 0x2C, 0x22, 0x8F, 0x20, 0x3A, 0x84, 0x20, 0xA7, 0xC0, 0x7E, 0xE6, 0x01,
-        0x01, 0x29, 0x02, 0xC2, 0x6E, 0x04, 0x01, 0xE0, 0xFE, 0x21, 0x8A, 0x20, 0x71, 0x23, 0x23,
-        0x70, 0xC9, 0xE1, 0x3A, 0x32, 0x1B, 0x32, 0x32, 0x20, 0x2A, 0x38, 0x20, 0x7D, 0xB4, 0xC2,
-        0x8A, 0x04, 0x2B, 0x22, 0x38, 0x20, 0xC9, 0x11, 0x35, 0x20, 0x3E, 0xF9, 0xCD, 0x50, 0x05,
-        0x3A, 0x46, 0x20, 0x32, 0x70, 0x20, 0x3A, 0x56, 0x20, 0x32, 0x71, 0x20, 0xCD, 0x63, 0x05,
-        0x3A, 0x78, 0x20, 0xA7, 0x21, 0x35, 0x20, 0xC2, 0x5B, 0x05, 0x11, 0x30, 0x1B, 0x21, 0x30,
-        0x20, 0x06, 0x10, 0xC3, 0x32, 0x1A, 0xE1, 0x3A, 0x6E, 0x20, 0xA7, 0xC0, 0x3A, 0x80, 0x20,
-        0xFE, 0x01, 0xC0, 0x11, 0x45, 0x20, 0x3E, 0xED, 0xCD, 0x50, 0x05, 0x3A, 0x36, 0x20, 0x32,
-        0x70, 0x20, 0x3A, 0x56, 0x20, 0x32, 0x71, 0x20, 0xCD, 0x63, 0x05, 0x3A, 0x76, 0x20, 0xFE,
-        0x10, 0xDA, 0xE7, 0x04, 0x3A, 0x48, 0x1B, 0x32, 0x76, 0x20, 0x3A, 0x78, 0x20, 0xA7, 0x21,
-        0x45, 0x20, 0xC2, 0x5B, 0x05, 0x11, 0x40, 0x1B, 0x21, 0x40, 0x20, 0x06, 0x10, 0xCD, 0x32,
-        0x1A, 0x3A, 0x82, 0x20, 0x3D, 0xC2, 0x08, 0x05, 0x3E, 0x01, 0x32, 0x6E, 0x20, 0x2A, 0x76,
-        0x20, 0xC3, 0x7E, 0x06, 0xE1, 0x11, 0x55, 0x20, 0x3E, 0xDB, 0xCD, 0x50, 0x05, 0x3A, 0x46,
-        0x20, 0x32, 0x70, 0x20, 0x3A, 0x36, 0x20, 0x32, 0x71, 0x20, 0xCD, 0x63, 0x05, 0x3A, 0x76,
-        0x20, 0xFE, 0x15, 0xDA, 0x34, 0x05, 0x3A, 0x58, 0x1B, 0x32, 0x76, 0x20, 0x3A, 0x78, 0x20,
-        0xA7, 0x21, 0x55, 0x20, 0xC2, 0x5B, 0x05, 0x11, 0x50, 0x1B, 0x21, 0x50, 0x20, 0x06, 0x10,
-        0xCD, 0x32, 0x1A, 0x2A, 0x76, 0x20, 0x22, 0x58, 0x20, 0xC9, 0x32, 0x7F, 0x20, 0x21, 0x73,
-        0x20, 0x06, 0x0B, 0xC3, 0x32, 0x1A, 0x11, 0x73, 0x20, 0x06, 0x0B, 0xC3, 0x32, 0x1A, 0x21,
-        0x73, 0x20, 0x7E, 0xE6, 0x80, 0xC2, 0xC1, 0x05, 0x3A, 0xC1, 0x20, 0xFE, 0x04, 0x3A, 0x69,
-        0x20, 0xCA, 0xB7, 0x05, 0xA7, 0xC8, 0x23, 0x36, 0x00, 0x3A, 0x70, 0x20, 0xA7, 0xCA, 0x89,
-        0x05, 0x47, 0x3A, 0xCF, 0x20, 0xB8, 0xD0, 0x3A, 0x71, 0x20, 0xA7, 0xCA, 0x96, 0x05, 0x47,
-        0x3A, 0xCF, 0x20, 0xB8, 0xD0, 0x23, 0x7E, 0xA7, 0xCA, 0x1B, 0x06, 0x2A, 0x76, 0x20, 0x4E,
-        0x23, 0x00, 0x22, 0x76, 0x20, 0xCD, 0x2F, 0x06, 0xD0, 0xCD, 0x7A, 0x01, 0x79, 0xC6, 0x07,
-        0x67, 0x7D, 0xD6, 0x0A, 0x6F, 0x22, 0x7B, 0x20, 0x21, 0x73, 0x20, 0x7E, 0xF6, 0x80, 0x77,
-        0x23, 0x34, 0xC9, 0x11, 0x7C, 0x20, 0xCD, 0x06, 0x1A, 0xD0, 0x23, 0x7E, 0xE6, 0x01, 0xC2,
-        0x44, 0x06, 0x23, 0x34, 0xCD, 0x75, 0x06, 0x3A, 0x79, 0x20, 0xC6, 0x03, 0x21, 0x7F, 0x20,
-        0xBE, 0xDA, 0xE2, 0x05, 0xD6, 0x0C, 0x32, 0x79, 0x20, 0x3A, 0x7B, 0x20, 0x47, 0x3A, 0x7E,
-        0x20, 0x80, 0x32, 0x7B, 0x20, 0xCD, 0x6C, 0x06, 0x3A, 0x7B, 0x20, 0xFE, 0x15, 0xDA, 0x12,
-        0x06, 0x3A, 0x61, 0x20, 0xA7, 0xC8, 0x3A, 0x7B, 0x20, 0xFE, 0x1E, 0xDA, 0x12, 0x06, 0xFE,
-        0x27, 0x00, 0xD2, 0x12, 0x06, 0x97, 0x32, 0x15, 0x20, 0x3A, 0x73, 0x20, 0xF6, 0x01, 0x32,
-        0x73, 0x20, 0xC9, 0x3A, 0x1B, 0x20, 0xC6, 0x08, 0x67, 0xCD, 0x6F, 0x15, 0x79, 0xFE, 0x0C,
-        0xDA, 0xA5, 0x05, 0x0E, 0x0B, 0xC3, 0xA5, 0x05, 0x0D, 0x3A, 0x67, 0x20, 0x67, 0x69, 0x16,
-        0x05, 0x7E, 0xA7, 0x37, 0xC0, 0x7D, 0xC6, 0x0B, 0x6F, 0x15, 0xC2, 0x37, 0x06, 0xC9, 0x21,
-        0x78, 0x20, 0x35, 0x7E, 0xFE, 0x03, 0xC2, 0x67, 0x06, 0xCD, 0x75, 0x06, 0x21, 0xDC, 0x1C,
-        0x22, 0x79, 0x20, 0x21, 0x7C, 0x20, 0x35, 0x35, 0x2B, 0x35, 0x35, 0x3E, 0x06, 0x32, 0x7D,
-        0x20, 0xC3, 0x6C, 0x06, 0xA7, 0xC0, 0xC3, 0x75, 0x06, 0x21, 0x79, 0x20, 0xCD, 0x3B, 0x1A,
-        0xC3, 0x91, 0x14, 0x21, 0x79, 0x20, 0xCD, 0x3B, 0x1A, 0xC3, 0x52, 0x14, 0x22, 0x48, 0x20,
-        0xC9, 0xE1, 0x3A, 0x80, 0x20, 0xFE, 0x02, 0xC0, 0x21, 0x83, 0x20, 0x7E, 0xA7, 0xCA, 0x0F,
-        0x05, 0x3A, 0x56, 0x20, 0xA7, 0xC2, 0x0F, 0x05, 0x23, 0x7E, 0xA7, 0xC2, 0xAB, 0x06, 0x3A,
-        0x82, 0x20, 0xFE, 0x08, 0xDA, 0x0F, 0x05, 0x36, 0x01, 0xCD, 0x3C, 0x07, 0x11, 0x8A, 0x20,
-        0xCD, 0x06, 0x1A, 0xD0, 0x21, 0x85, 0x20, 0x7E, 0xA7, 0xC2, 0xD6, 0x06, 0x21, 0x8A, 0x20,
-        0x7E, 0x23, 0x23, 0x86, 0x32, 0x8A, 0x20, 0xCD, 0x3C, 0x07, 0x21, 0x8A, 0x20, 0x7E, 0xFE,
-        0x28, 0xDA, 0xF9, 0x06, 0xFE, 0xE1, 0xD2, 0xF9, 0x06, 0xC9, 0x06, 0xFE, 0xCD, 0xDC, 0x19,
-        0x23, 0x35, 0x7E, 0xFE, 0x1F, 0xCA, 0x4B, 0x07, 0xFE, 0x18, 0xCA, 0x0C, 0x07, 0xA7, 0xC0,
-        0x06, 0xEF, 0x21, 0x98, 0x20, 0x7E, 0xA0, 0x77, 0xE6, 0x20, 0xD3, 0x05, 0x00, 0x00, 0x00,
-        0xCD, 0x42, 0x07, 0xCD, 0xCB, 0x14, 0x21, 0x83, 0x20, 0x06, 0x0A, 0xCD, 0x5F, 0x07, 0x06,
-        0xFE, 0xC3, 0xDC, 0x19, 0x3E, 0x01, 0x32, 0xF1, 0x20, 0x2A, 0x8D, 0x20, 0x46, 0x0E, 0x04,
-        0x21, 0x50, 0x1D, 0x11, 0x4C, 0x1D, 0x1A, 0xB8, 0xCA, 0x28, 0x07, 0x23, 0x13, 0x0D, 0xC2,
-        0x1D, 0x07, 0x7E, 0x32, 0x87, 0x20, 0x26, 0x00, 0x68, 0x29, 0x29, 0x29, 0x29, 0x22, 0xF2,
-        0x20, 0xCD, 0x42, 0x07, 0xC3, 0xF1, 0x08, 0xCD, 0x42, 0x07, 0xC3, 0x39, 0x14, 0x21, 0x87,
-        0x20, 0xCD, 0x3B, 0x1A, 0xC3, 0x47, 0x1A, 0x06, 0x10, 0x21, 0x98, 0x20, 0x7E, 0xB0, 0x77,
-        0xCD, 0x70, 0x17, 0x21, 0x7C, 0x1D, 0x22, 0x87, 0x20, 0xC3, 0x3C, 0x07, 0x11, 0x83, 0x1B,
-        0xC3, 0x32, 0x1A, 0x3E, 0x01, 0x32, 0x93, 0x20, 0x31, 0x00, 0x24, 0xFB, 0xCD, 0x79, 0x19,
-        0xCD, 0xD6, 0x09, 0x21, 0x13, 0x30, 0x11, 0xF3, 0x1F, 0x0E, 0x04, 0xCD, 0xF3, 0x08, 0x3A,
-        0xEB, 0x20, 0x3D, 0x21, 0x10, 0x28, 0x0E, 0x14, 0xC2, 0x57, 0x08, 0x11, 0xCF, 0x1A, 0xCD,
-        0xF3, 0x08, 0xDB, 0x01, 0xE6, 0x04, 0xCA, 0x7F, 0x07, 0x06, 0x99, 0xAF, 0x32, 0xCE, 0x20,
-        0x3A, 0xEB, 0x20, 0x80, 0x27, 0x32, 0xEB, 0x20, 0xCD, 0x47, 0x19, 0x21, 0x00, 0x00, 0x22,
-        0xF8, 0x20, 0x22, 0xFC, 0x20, 0xCD, 0x25, 0x19, 0xCD, 0x2B, 0x19, 0xCD, 0xD7, 0x19, 0x21,
-        0x01, 0x01, 0x7C, 0x32, 0xEF, 0x20, 0x22, 0xE7, 0x20, 0x22, 0xE5, 0x20, 0xCD, 0x56, 0x19,
-        0xCD, 0xEF, 0x01, 0xCD, 0xF5, 0x01, 0xCD, 0xD1, 0x08, 0x32, 0xFF, 0x21, 0x32, 0xFF, 0x22,
-        0xCD, 0xD7, 0x00, 0xAF, 0x32, 0xFE, 0x21, 0x32, 0xFE, 0x22, 0xCD, 0xC0, 0x01, 0xCD, 0x04,
-        0x19, 0x21, 0x78, 0x38, 0x22, 0xFC, 0x21, 0x22, 0xFC, 0x22, 0xCD, 0xE4, 0x01, 0xCD, 0x7F,
-        0x1A, 0xCD, 0x8D, 0x08, 0xCD, 0xD6, 0x09, 0x00, 0xAF, 0x32, 0xC1, 0x20, 0xCD, 0xCF, 0x01,
-        0x3A, 0x67, 0x20, 0x0F, 0xDA, 0x72, 0x08, 0xCD, 0x13, 0x02, 0xCD, 0xCF, 0x01, 0xCD, 0xB1,
-        0x00, 0xCD, 0xD1, 0x19, 0x06, 0x20, 0xCD, 0xFA, 0x18, 0xCD, 0x18, 0x16, 0xCD, 0x0A, 0x19,
-        0xCD, 0xF3, 0x15, 0xCD, 0x88, 0x09, 0x3A, 0x82, 0x20, 0xA7, 0xCA, 0xEF, 0x09, 0xCD, 0x0E,
-        0x17, 0xCD, 0x35, 0x09, 0xCD, 0xD8, 0x08, 0xCD, 0x2C, 0x17, 0xCD, 0x59, 0x0A, 0xCA, 0x49,
-        0x08, 0x06, 0x04, 0xCD, 0xFA, 0x18, 0xCD, 0x75, 0x17, 0xD3, 0x06, 0xCD, 0x04, 0x18, 0xC3,
-        0x1F, 0x08, 0x00, 0x00, 0x00, 0x11, 0xBA, 0x1A, 0xCD, 0xF3, 0x08, 0x06, 0x98, 0xDB, 0x01,
-        0x0F, 0x0F, 0xDA, 0x6D, 0x08, 0x0F, 0xDA, 0x98, 0x07, 0xC3, 0x7F, 0x07, 0x3E, 0x01, 0xC3,
-        0x9B, 0x07, 0xCD, 0x1A, 0x02, 0xC3, 0x14, 0x08, 0x3A, 0x08, 0x20, 0x47, 0x2A, 0x09, 0x20,
-        0xEB, 0xC3, 0x86, 0x08, 0x00, 0x00, 0x00, 0x3A, 0x67, 0x20, 0x67, 0x2E, 0xFC, 0xC9, 0x21,
-        0x11, 0x2B, 0x11, 0x70, 0x1B, 0x0E, 0x0E, 0xCD, 0xF3, 0x08, 0x3A, 0x67, 0x20, 0x0F, 0x3E,
-        0x1C, 0x21, 0x11, 0x37, 0xD4, 0xFF, 0x08, 0x3E, 0xB0, 0x32, 0xC0, 0x20, 0x3A, 0xC0, 0x20,
-        0xA7, 0xC8, 0xE6, 0x04, 0xC2, 0xBC, 0x08, 0xCD, 0xCA, 0x09, 0xCD, 0x31, 0x19, 0xC3, 0xA9,
-        0x08, 0x06, 0x20, 0x21, 0x1C, 0x27, 0x3A, 0x67, 0x20, 0x0F, 0xDA, 0xCB, 0x08, 0x21, 0x1C,
-        0x39, 0xCD, 0xCB, 0x14, 0xC3, 0xA9, 0x08, 0xDB, 0x02, 0xE6, 0x03, 0xC6, 0x03, 0xC9, 0x3A,
-        0x82, 0x20, 0xFE, 0x09, 0xD0, 0x3E, 0xFB, 0x32, 0x7E, 0x20, 0xC9, 0x3A, 0xCE, 0x20, 0xA7,
-        0xC0, 0x21, 0x1C, 0x39, 0x06, 0x20, 0xC3, 0xCB, 0x14, 0x0E, 0x03, 0x1A, 0xD5, 0xCD, 0xFF,
-        0x08, 0xD1, 0x13, 0x0D, 0xC2, 0xF3, 0x08, 0xC9, 0x11, 0x00, 0x1E, 0xE5, 0x26, 0x00, 0x6F,
-        0x29, 0x29, 0x29, 0x19, 0xEB, 0xE1, 0x06, 0x08, 0xD3, 0x06, 0xC3, 0x39, 0x14, 0x3A, 0x09,
-        0x20, 0xFE, 0x78, 0xD0, 0x2A, 0x91, 0x20, 0x7D, 0xB4, 0xC2, 0x29, 0x09, 0x21, 0x00, 0x06,
-        0x3E, 0x01, 0x32, 0x83, 0x20, 0x2B, 0x22, 0x91, 0x20, 0xC9, 0xCD, 0x11, 0x16, 0x2E, 0xFF,
-        0x7E, 0xC9, 0xCD, 0x10, 0x19, 0x2B, 0x2B, 0x7E, 0xA7, 0xC8, 0x06, 0x15, 0xDB, 0x02, 0xE6,
-        0x08, 0xCA, 0x48, 0x09, 0x06, 0x10, 0xCD, 0xCA, 0x09, 0x23, 0x7E, 0xB8, 0xD8, 0xCD, 0x2E,
-        0x09, 0x34, 0x7E, 0xF5, 0x21, 0x01, 0x25, 0x24, 0x24, 0x3D, 0xC2, 0x58, 0x09, 0x06, 0x10,
-        0x11, 0x60, 0x1C, 0xCD, 0x39, 0x14, 0xF1, 0x3C, 0xCD, 0x8B, 0x1A, 0xCD, 0x10, 0x19, 0x2B,
-        0x2B, 0x36, 0x00, 0x3E, 0xFF, 0x32, 0x99, 0x20, 0x06, 0x10, 0xC3, 0xFA, 0x18, 0x21, 0xA0,
-        0x1D, 0xFE, 0x02, 0xD8, 0x23, 0xFE, 0x04, 0xD8, 0x23, 0xC9, 0xCD, 0xCA, 0x09, 0x3A, 0xF1,
-        0x20, 0xA7, 0xC8, 0xAF, 0x32, 0xF1, 0x20, 0xE5, 0x2A, 0xF2, 0x20, 0xEB, 0xE1, 0x7E, 0x83,
-        0x27, 0x77, 0x5F, 0x23, 0x7E, 0x8A, 0x27, 0x77, 0x57, 0x23, 0x7E, 0x23, 0x66, 0x6F, 0xC3,
-        0xAD, 0x09, 0x7A, 0xCD, 0xB2, 0x09, 0x7B, 0xD5, 0xF5, 0x0F, 0x0F, 0x0F, 0x0F, 0xE6, 0x0F,
-        0xCD, 0xC5, 0x09, 0xF1, 0xE6, 0x0F, 0xCD, 0xC5, 0x09, 0xD1, 0xC9, 0xC6, 0x1A, 0xC3, 0xFF,
-        0x08, 0x3A, 0x67, 0x20, 0x0F, 0x21, 0xF8, 0x20, 0xD8, 0x21, 0xFC, 0x20, 0xC9, 0x21, 0x02,
-        0x24, 0x36, 0x00, 0x23, 0x7D, 0xE6, 0x1F, 0xFE, 0x1C, 0xDA, 0xE8, 0x09, 0x11, 0x06, 0x00,
-        0x19, 0x7C, 0xFE, 0x40, 0xDA, 0xD9, 0x09, 0xC9, 0xCD, 0x3C, 0x0A, 0xAF, 0x32, 0xE9, 0x20,
-        0xCD, 0xD6, 0x09, 0x3A, 0x67, 0x20, 0xF5, 0xCD, 0xE4, 0x01, 0xF1, 0x32, 0x67, 0x20, 0x3A,
-        0x67, 0x20, 0x67, 0xE5, 0x2E, 0xFE, 0x7E, 0xE6, 0x07, 0x3C, 0x77, 0x21, 0xA2, 0x1D, 0x23,
-        0x3D, 0xC2, 0x13, 0x0A, 0x7E, 0xE1, 0x2E, 0xFC, 0x77, 0x23, 0x36, 0x38, 0x7C, 0x0F, 0xDA,
-        0x33, 0x0A, 0x3E, 0x21, 0x32, 0x98, 0x20, 0xCD, 0xF5, 0x01, 0xCD, 0x04, 0x19, 0xC3, 0x04,
-        0x08, 0xCD, 0xEF, 0x01, 0xCD, 0xC0, 0x01, 0xC3, 0x04, 0x08, 0xCD, 0x59, 0x0A, 0xC2, 0x52,
-        0x0A, 0x3E, 0x30, 0x32, 0xC0, 0x20, 0x3A, 0xC0, 0x20, 0xA7, 0xC8, 0xCD, 0x59, 0x0A, 0xCA,
-        0x47, 0x0A, 0xCD, 0x59, 0x0A, 0xC2, 0x52, 0x0A, 0xC9, 0x3A, 0x15, 0x20, 0xFE, 0xFF, 0xC9,
-        0x3A, 0xEF, 0x20, 0xA7, 0xCA, 0x7C, 0x0A, 0x48, 0x06, 0x08, 0xCD, 0xFA, 0x18, 0x41, 0x78,
-        0xCD, 0x7C, 0x09, 0x7E, 0x21, 0xF3, 0x20, 0x36, 0x00, 0x2B, 0x77, 0x2B, 0x36, 0x01, 0x21,
-        0x62, 0x20, 0xC9, 0x3E, 0x02, 0x32, 0xC1, 0x20, 0xD3, 0x06, 0x3A, 0xCB, 0x20, 0xA7, 0xCA,
-        0x85, 0x0A, 0xAF, 0x32, 0xC1, 0x20, 0xC9, 0xD5, 0x1A, 0xCD, 0xFF, 0x08, 0xD1, 0x3E, 0x07,
-        0x32, 0xC0, 0x20, 0x3A, 0xC0, 0x20, 0x3D, 0xC2, 0x9E, 0x0A, 0x13, 0x0D, 0xC2, 0x93, 0x0A,
-        0xC9, 0x21, 0x50, 0x20, 0xC3, 0x4B, 0x02, 0x3E, 0x40, 0xC3, 0xD7, 0x0A, 0x3E, 0x80, 0xC3,
-        0xD7, 0x0A, 0xE1, 0xC3, 0x72, 0x00, 0x3A, 0xC1, 0x20, 0x0F, 0xDA, 0xBB, 0x0A, 0x0F, 0xDA,
-        0x68, 0x18, 0x0F, 0xDA, 0xAB, 0x0A, 0xC9, 0x21, 0x14, 0x2B, 0x0E, 0x0F, 0xC3, 0x93, 0x0A,
-        0x32, 0xC0, 0x20, 0x3A, 0xC0, 0x20, 0xA7, 0xC2, 0xDA, 0x0A, 0xC9, 0x21, 0xC2, 0x20, 0x06,
-        0x0C, 0xC3, 0x32, 0x1A, 0xAF, 0xD3, 0x03, 0xD3, 0x05, 0xCD, 0x82, 0x19, 0xFB, 0xCD, 0xB1,
-        0x0A, 0x3A, 0xEC, 0x20, 0xA7, 0x21, 0x17, 0x30, 0x0E, 0x04, 0xC2, 0xE8, 0x0B, 0x11, 0xFA,
-        0x1C, 0xCD, 0x93, 0x0A, 0x11, 0xAF, 0x1D, 0xCD, 0xCF, 0x0A, 0xCD, 0xB1, 0x0A, 0xCD, 0x15,
-        0x18, 0xCD, 0xB6, 0x0A, 0x3A, 0xEC, 0x20, 0xA7, 0xC2, 0x4A, 0x0B, 0x11, 0x95, 0x1A, 0xCD,
-        0xE2, 0x0A, 0xCD, 0x80, 0x0A, 0x11, 0xB0, 0x1B, 0xCD, 0xE2, 0x0A, 0xCD, 0x80, 0x0A, 0xCD,
-        0xB1, 0x0A, 0x11, 0xC9, 0x1F, 0xCD, 0xE2, 0x0A, 0xCD, 0x80, 0x0A, 0xCD, 0xB1, 0x0A, 0x21,
-        0xB7, 0x33, 0x06, 0x0A, 0xCD, 0xCB, 0x14, 0xCD, 0xB6, 0x0A, 0xCD, 0xD6, 0x09, 0x3A, 0xFF,
-        0x21, 0xA7, 0xC2, 0x5D, 0x0B, 0xCD, 0xD1, 0x08, 0x32, 0xFF, 0x21, 0xCD, 0x7F, 0x1A, 0xCD,
-        0xE4, 0x01, 0xCD, 0xC0, 0x01, 0xCD, 0xEF, 0x01, 0xCD, 0x1A, 0x02, 0x3E, 0x01, 0x32, 0xC1,
-        0x20, 0xCD, 0xCF, 0x01, 0xCD, 0x18, 0x16, 0xCD, 0xF1, 0x0B, 0xD3, 0x06, 0xCD, 0x59, 0x0A,
-        0xCA, 0x71, 0x0B, 0xAF, 0x32, 0x25, 0x20, 0xCD, 0x59, 0x0A, 0xC2, 0x83, 0x0B, 0xAF, 0x32,
-        0xC1, 0x20, 0xCD, 0xB1, 0x0A, 0xCD, 0x88, 0x19, 0x0E, 0x0C, 0x21, 0x11, 0x2C, 0x11, 0x90,
-        0x1F, 0xCD, 0xF3, 0x08, 0x3A, 0xEC, 0x20, 0xFE, 0x00, 0xC2, 0xAE, 0x0B, 0x21, 0x11, 0x33,
-        0x3E, 0x02, 0xCD, 0xFF, 0x08, 0x01, 0x9C, 0x1F, 0xCD, 0x56, 0x18, 0xCD, 0x4C, 0x18, 0xDB,
-        0x02, 0x07, 0xDA, 0xC3, 0x0B, 0x01, 0xA0, 0x1F, 0xCD, 0x3A, 0x18, 0xCD, 0xB6, 0x0A, 0x3A,
-        0xEC, 0x20, 0xFE, 0x00, 0xC2, 0xDA, 0x0B, 0x11, 0xD5, 0x1F, 0xCD, 0xE2, 0x0A, 0xCD, 0x80,
-        0x0A, 0xCD, 0x9E, 0x18, 0x21, 0xEC, 0x20, 0x7E, 0x3C, 0xE6, 0x01, 0x77, 0xCD, 0xD6, 0x09,
-        0xC3, 0xDF, 0x18, 0x11, 0xAB, 0x1D, 0xCD, 0x93, 0x0A, 0xC3, 0x0B, 0x0B, 0xCD, 0x0A, 0x19,
-        0xC3, 0x9A, 0x19, 0x13, 0x00, 0x08, 0x13, 0x0E, 0x26, 0x02, 0x0E, 0x0F, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xCD, 0x74, 0x14, 0x00, 0xC5, 0xE5, 0x1A, 0xD3, 0x04,
-        0xDB, 0x03, 0xB6, 0x77, 0x23, 0x13, 0xAF, 0xD3, 0x04, 0xDB, 0x03, 0xB6, 0x77, 0xE1, 0x01,
-        0x20, 0x00, 0x09, 0xC1, 0x05, 0xC2, 0x05, 0x14, 0xC9, 0x00, 0x00, 0xCD, 0x74, 0x14, 0xC5,
-        0xE5, 0xAF, 0x77, 0x23, 0x77, 0x23, 0xE1, 0x01, 0x20, 0x00, 0x09, 0xC1, 0x05, 0xC2, 0x27,
-        0x14, 0xC9, 0xC5, 0x1A, 0x77, 0x13, 0x01, 0x20, 0x00, 0x09, 0xC1, 0x05, 0xC2, 0x39, 0x14,
-        0xC9, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xCD, 0x74, 0x14,
-        0xC5, 0xE5, 0x1A, 0xD3, 0x04, 0xDB, 0x03, 0x2F, 0xA6, 0x77, 0x23, 0x13, 0xAF, 0xD3, 0x04,
-        0xDB, 0x03, 0x2F, 0xA6, 0x77, 0xE1, 0x01, 0x20, 0x00, 0x09, 0xC1, 0x05, 0xC2, 0x55, 0x14,
-        0xC9, 0x7D, 0xE6, 0x07, 0xD3, 0x02, 0xC3, 0x47, 0x1A, 0xC5, 0xE5, 0x7E, 0x12, 0x13, 0x23,
-        0x0D, 0xC2, 0x7E, 0x14, 0xE1, 0x01, 0x20, 0x00, 0x09, 0xC1, 0x05, 0xC2, 0x7C, 0x14, 0xC9,
-        0xCD, 0x74, 0x14, 0xAF, 0x32, 0x61, 0x20, 0xC5, 0xE5, 0x1A, 0xD3, 0x04, 0xDB, 0x03, 0xF5,
-        0xA6, 0xCA, 0xA9, 0x14, 0x3E, 0x01, 0x32, 0x61, 0x20, 0xF1, 0xB6, 0x77, 0x23, 0x13, 0xAF,
-        0xD3, 0x04, 0xDB, 0x03, 0xF5, 0xA6, 0xCA, 0xBD, 0x14, 0x3E, 0x01, 0x32, 0x61, 0x20, 0xF1,
-        0xB6, 0x77, 0xE1, 0x01, 0x20, 0x00, 0x09, 0xC1, 0x05, 0xC2, 0x98, 0x14, 0xC9, 0xAF, 0xC5,
-        0x77, 0x01, 0x20, 0x00, 0x09, 0xC1, 0x05, 0xC2, 0xCC, 0x14, 0xC9, 0x3A, 0x25, 0x20, 0xFE,
-        0x05, 0xC8, 0xFE, 0x02, 0xC0, 0x3A, 0x29, 0x20, 0xFE, 0xD8, 0x47, 0xD2, 0x30, 0x15, 0x3A,
-        0x02, 0x20, 0xA7, 0xC8, 0x78, 0xFE, 0xCE, 0xD2, 0x79, 0x15, 0xC6, 0x06, 0x47, 0x3A, 0x09,
-        0x20, 0xFE, 0x90, 0xD2, 0x04, 0x15, 0xB8, 0xD2, 0x30, 0x15, 0x68, 0xCD, 0x62, 0x15, 0x3A,
-        0x2A, 0x20, 0x67, 0xCD, 0x6F, 0x15, 0x22, 0x64, 0x20, 0x3E, 0x05, 0x32, 0x25, 0x20, 0xCD,
-        0x81, 0x15, 0x7E, 0xA7, 0xCA, 0x30, 0x15, 0x36, 0x00, 0xCD, 0x5F, 0x0A, 0xCD, 0x3B, 0x1A,
-        0xCD, 0xD3, 0x15, 0x3E, 0x10, 0x32, 0x03, 0x20, 0xC9, 0x3E, 0x03, 0x32, 0x25, 0x20, 0xC3,
-        0x4A, 0x15, 0x21, 0x03, 0x20, 0x35, 0xC0, 0x2A, 0x64, 0x20, 0x06, 0x10, 0xCD, 0x24, 0x14,
-        0x3E, 0x04, 0x32, 0x25, 0x20, 0xAF, 0x32, 0x02, 0x20, 0x06, 0xF7, 0xC3, 0xDC, 0x19, 0x00,
-        0x0E, 0x00, 0xBC, 0xD4, 0x90, 0x15, 0xBC, 0xD0, 0xC6, 0x10, 0x0C, 0xC3, 0x5A, 0x15, 0x3A,
-        0x09, 0x20, 0x65, 0xCD, 0x54, 0x15, 0x41, 0x05, 0xDE, 0x10, 0x6F, 0xC9, 0x3A, 0x0A, 0x20,
-        0xCD, 0x54, 0x15, 0xDE, 0x10, 0x67, 0xC9, 0x3E, 0x01, 0x32, 0x85, 0x20, 0xC3, 0x45, 0x15,
-        0x78, 0x07, 0x07, 0x07, 0x80, 0x80, 0x80, 0x81, 0x3D, 0x6F, 0x3A, 0x67, 0x20, 0x67, 0xC9,
-        0x0C, 0xC6, 0x10, 0xFA, 0x90, 0x15, 0xC9, 0x3A, 0x0D, 0x20, 0xA7, 0xC2, 0xB7, 0x15, 0x21,
-        0xA4, 0x3E, 0xCD, 0xC5, 0x15, 0xD0, 0x06, 0xFE, 0x3E, 0x01, 0x32, 0x0D, 0x20, 0x78, 0x32,
-        0x08, 0x20, 0x3A, 0x0E, 0x20, 0x32, 0x07, 0x20, 0xC9, 0x21, 0x24, 0x25, 0xCD, 0xC5, 0x15,
-        0xD0, 0xCD, 0xF1, 0x18, 0xAF, 0xC3, 0xA9, 0x15, 0x06, 0x17, 0x7E, 0xA7, 0xC2, 0x6B, 0x16,
-        0x23, 0x05, 0xC2, 0xC7, 0x15, 0xC9, 0x00, 0xCD, 0x74, 0x14, 0xE5, 0xC5, 0xE5, 0x1A, 0xD3,
-        0x04, 0xDB, 0x03, 0x77, 0x23, 0x13, 0xAF, 0xD3, 0x04, 0xDB, 0x03, 0x77, 0xE1, 0x01, 0x20,
-        0x00, 0x09, 0xC1, 0x05, 0xC2, 0xD7, 0x15, 0xE1, 0xC9, 0xCD, 0x11, 0x16, 0x01, 0x00, 0x37,
-        0x7E, 0xA7, 0xCA, 0xFF, 0x15, 0x0C, 0x23, 0x05, 0xC2, 0xF9, 0x15, 0x79, 0x32, 0x82, 0x20,
-        0xFE, 0x01, 0xC0, 0x21, 0x6B, 0x20, 0x36, 0x01, 0xC9, 0x2E, 0x00, 0x3A, 0x67, 0x20, 0x67,
-        0xC9, 0x3A, 0x15, 0x20, 0xFE, 0xFF, 0xC0, 0x21, 0x10, 0x20, 0x7E, 0x23, 0x46, 0xB0, 0xC0,
-        0x3A, 0x25, 0x20, 0xA7, 0xC0, 0x3A, 0xEF, 0x20, 0xA7, 0xCA, 0x52, 0x16, 0x3A, 0x2D, 0x20,
-        0xA7, 0xC2, 0x48, 0x16, 0xCD, 0xC0, 0x17, 0xE6, 0x10, 0xC8, 0x3E, 0x01, 0x32, 0x25, 0x20,
-        0x32, 0x2D, 0x20, 0xC9, 0xCD, 0xC0, 0x17, 0xE6, 0x10, 0xC0, 0x32, 0x2D, 0x20, 0xC9, 0x21,
-        0x25, 0x20, 0x36, 0x01, 0x2A, 0xED, 0x20, 0x23, 0x7D, 0xFE, 0x7E, 0xDA, 0x63, 0x16, 0x2E,
-        0x74, 0x22, 0xED, 0x20, 0x7E, 0x32, 0x1D, 0x20, 0xC9, 0x37, 0xC9, 0xAF, 0xCD, 0x8B, 0x1A,
-        0xCD, 0x10, 0x19, 0x36, 0x00, 0xCD, 0xCA, 0x09, 0x23, 0x11, 0xF5, 0x20, 0x1A, 0xBE, 0x1B,
-        0x2B, 0x1A, 0xCA, 0x8B, 0x16, 0xD2, 0x98, 0x16, 0xC3, 0x8F, 0x16, 0xBE, 0xD2, 0x98, 0x16,
-        0x7E, 0x12, 0x13, 0x23, 0x7E, 0x12, 0xCD, 0x50, 0x19, 0x3A, 0xCE, 0x20, 0xA7, 0xCA, 0xC9,
-        0x16, 0x21, 0x03, 0x28, 0x11, 0xA6, 0x1A, 0x0E, 0x14, 0xCD, 0x93, 0x0A, 0x25, 0x25, 0x06,
-        0x1B, 0x3A, 0x67, 0x20, 0x0F, 0xDA, 0xB7, 0x16, 0x06, 0x1C, 0x78, 0xCD, 0xFF, 0x08, 0xCD,
-        0xB1, 0x0A, 0xCD, 0xE7, 0x18, 0x7E, 0xA7, 0xCA, 0xC9, 0x16, 0xC3, 0xED, 0x02, 0x21, 0x18,
-        0x2D, 0x11, 0xA6, 0x1A, 0x0E, 0x0A, 0xCD, 0x93, 0x0A, 0xCD, 0xB6, 0x0A, 0xCD, 0xD6, 0x09,
-        0xAF, 0x32, 0xEF, 0x20, 0xD3, 0x05, 0xCD, 0xD1, 0x19, 0xC3, 0x89, 0x0B, 0x31, 0x00, 0x24,
-        0xFB, 0xAF, 0x32, 0x15, 0x20, 0xCD, 0xD8, 0x14, 0x06, 0x04, 0xCD, 0xFA, 0x18, 0xCD, 0x59,
-        0x0A, 0xC2, 0xEE, 0x16, 0xCD, 0xD7, 0x19, 0x21, 0x01, 0x27, 0xCD, 0xFA, 0x19, 0xAF, 0xCD,
-        0x8B, 0x1A, 0x06, 0xFB, 0xC3, 0x6B, 0x19, 0xCD, 0xCA, 0x09, 0x23, 0x7E, 0x11, 0xB8, 0x1C,
-        0x21, 0xA1, 0x1A, 0x0E, 0x04, 0x47, 0x1A, 0xB8, 0xD2, 0x27, 0x17, 0x23, 0x13, 0x0D, 0xC2,
-        0x1C, 0x17, 0x7E, 0x32, 0xCF, 0x20, 0xC9, 0x3A, 0x25, 0x20, 0xFE, 0x00, 0xC2, 0x39, 0x17,
-        0x06, 0xFD, 0xC3, 0xDC, 0x19, 0x06, 0x02, 0xC3, 0xFA, 0x18, 0x00, 0x00, 0x21, 0x9B, 0x20,
-        0x35, 0xCC, 0x6D, 0x17, 0x3A, 0x68, 0x20, 0xA7, 0xCA, 0x6D, 0x17, 0x21, 0x96, 0x20, 0x35,
-        0xC0, 0x21, 0x98, 0x20, 0x7E, 0xD3, 0x05, 0x3A, 0x82, 0x20, 0xA7, 0xCA, 0x6D, 0x17, 0x2B,
-        0x7E, 0x2B, 0x77, 0x2B, 0x36, 0x01, 0x3E, 0x04, 0x32, 0x9B, 0x20, 0xC9, 0x3A, 0x98, 0x20,
-        0xE6, 0x30, 0xD3, 0x05, 0xC9, 0x3A, 0x95, 0x20, 0xA7, 0xCA, 0xAA, 0x17, 0x21, 0x11, 0x1A,
-        0x11, 0x21, 0x1A, 0x3A, 0x82, 0x20, 0xBE, 0xD2, 0x8E, 0x17, 0x23, 0x13, 0xC3, 0x85, 0x17,
-        0x1A, 0x32, 0x97, 0x20, 0x21, 0x98, 0x20, 0x7E, 0xE6, 0x30, 0x47, 0x7E, 0xE6, 0x0F, 0x07,
-        0xFE, 0x10, 0xC2, 0xA4, 0x17, 0x3E, 0x01, 0xB0, 0x77, 0xAF, 0x32, 0x95, 0x20, 0x21, 0x99,
-        0x20, 0x35, 0xC0, 0x06, 0xEF, 0xC3, 0xDC, 0x19, 0x06, 0xEF, 0x21, 0x98, 0x20, 0x7E, 0xA0,
-        0x77, 0xD3, 0x05, 0xC9, 0x00, 0x3A, 0x67, 0x20, 0x0F, 0xD2, 0xCA, 0x17, 0xDB, 0x01, 0xC9,
-        0xDB, 0x02, 0xC9, 0xDB, 0x02, 0xE6, 0x04, 0xC8, 0x3A, 0x9A, 0x20, 0xA7, 0xC0, 0x31, 0x00,
-        0x24, 0x06, 0x04, 0xCD, 0xD6, 0x09, 0x05, 0xC2, 0xDC, 0x17, 0x3E, 0x01, 0x32, 0x9A, 0x20,
-        0xCD, 0xD7, 0x19, 0xFB, 0x11, 0xBC, 0x1C, 0x21, 0x16, 0x30, 0x0E, 0x04, 0xCD, 0x93, 0x0A,
-        0xCD, 0xB1, 0x0A, 0xAF, 0x32, 0x9A, 0x20, 0x32, 0x93, 0x20, 0xC3, 0xC9, 0x16, 0x21, 0x84,
-        0x20, 0x7E, 0xA7, 0xCA, 0x07, 0x07, 0x23, 0x7E, 0xA7, 0xC0, 0x06, 0x01, 0xC3, 0xFA, 0x18,
-        0x21, 0x10, 0x28, 0x11, 0xA3, 0x1C, 0x0E, 0x15, 0xCD, 0xF3, 0x08, 0x3E, 0x0A, 0x32, 0x6C,
-        0x20, 0x01, 0xBE, 0x1D, 0xCD, 0x56, 0x18, 0xDA, 0x37, 0x18, 0xCD, 0x44, 0x18, 0xC3, 0x28,
-        0x18, 0xCD, 0xB1, 0x0A, 0x01, 0xCF, 0x1D, 0xCD, 0x56, 0x18, 0xD8, 0xCD, 0x4C, 0x18, 0xC3,
-        0x3A, 0x18, 0xC5, 0x06, 0x10, 0xCD, 0x39, 0x14, 0xC1, 0xC9, 0xC5, 0x3A, 0x6C, 0x20, 0x4F,
-        0xCD, 0x93, 0x0A, 0xC1, 0xC9, 0x0A, 0xFE, 0xFF, 0x37, 0xC8, 0x6F, 0x03, 0x0A, 0x67, 0x03,
-        0x0A, 0x5F, 0x03, 0x0A, 0x57, 0x03, 0xA7, 0xC9, 0x21, 0xC2, 0x20, 0x34, 0x23, 0x4E, 0xCD,
-        0xD9, 0x01, 0x47, 0x3A, 0xCA, 0x20, 0xB8, 0xCA, 0x98, 0x18, 0x3A, 0xC2, 0x20, 0xE6, 0x04,
-        0x2A, 0xCC, 0x20, 0xC2, 0x88, 0x18, 0x11, 0x30, 0x00, 0x19, 0x22, 0xC7, 0x20, 0x21, 0xC5,
-        0x20, 0xCD, 0x3B, 0x1A, 0xEB, 0xC3, 0xD3, 0x15, 0x00, 0x00, 0x00, 0x3E, 0x01, 0x32, 0xCB,
-        0x20, 0xC9, 0x21, 0x50, 0x20, 0x11, 0xC0, 0x1B, 0x06, 0x10, 0xCD, 0x32, 0x1A, 0x3E, 0x02,
-        0x32, 0x80, 0x20, 0x3E, 0xFF, 0x32, 0x7E, 0x20, 0x3E, 0x04, 0x32, 0xC1, 0x20, 0x3A, 0x55,
-        0x20, 0xE6, 0x01, 0xCA, 0xB8, 0x18, 0x3A, 0x55, 0x20, 0xE6, 0x01, 0xC2, 0xC0, 0x18, 0x21,
-        0x11, 0x33, 0x3E, 0x26, 0x00, 0xCD, 0xFF, 0x08, 0xC3, 0xB6, 0x0A, 0x31, 0x00, 0x24, 0x06,
-        0x00, 0xCD, 0xE6, 0x01, 0xCD, 0x56, 0x19, 0x3E, 0x08, 0x32, 0xCF, 0x20, 0xC3, 0xEA, 0x0A,
-        0x3A, 0x67, 0x20, 0x21, 0xE7, 0x20, 0x0F, 0xD0, 0x23, 0xC9, 0x06, 0x02, 0x3A, 0x82, 0x20,
-        0x3D, 0xC0, 0x04, 0xC9, 0x3A, 0x94, 0x20, 0xB0, 0x32, 0x94, 0x20, 0xD3, 0x03, 0xC9, 0x21,
-        0x00, 0x22, 0xC3, 0xC3, 0x01, 0xCD, 0xD8, 0x14, 0xC3, 0x97, 0x15, 0x21, 0xE7, 0x20, 0x3A,
-        0x67, 0x20, 0x0F, 0xD8, 0x23, 0xC9, 0x0E, 0x1C, 0x21, 0x1E, 0x24, 0x11, 0xE4, 0x1A, 0xC3,
-        0xF3, 0x08, 0x21, 0xF8, 0x20, 0xC3, 0x31, 0x19, 0x21, 0xFC, 0x20, 0xC3, 0x31, 0x19, 0x5E,
-        0x23, 0x56, 0x23, 0x7E, 0x23, 0x66, 0x6F, 0xC3, 0xAD, 0x09, 0x0E, 0x07, 0x21, 0x01, 0x35,
-        0x11, 0xA9, 0x1F, 0xC3, 0xF3, 0x08, 0x3A, 0xEB, 0x20, 0x21, 0x01, 0x3C, 0xC3, 0xB2, 0x09,
-        0x21, 0xF4, 0x20, 0xC3, 0x31, 0x19, 0xCD, 0x5C, 0x1A, 0xCD, 0x1A, 0x19, 0xCD, 0x25, 0x19,
-        0xCD, 0x2B, 0x19, 0xCD, 0x50, 0x19, 0xCD, 0x3C, 0x19, 0xC3, 0x47, 0x19, 0xCD, 0xDC, 0x19,
-        0xC3, 0x71, 0x16, 0x3E, 0x01, 0x32, 0x6D, 0x20, 0xC3, 0xE6, 0x16, 0xCD, 0xD7, 0x19, 0xCD,
-        0x47, 0x19, 0xC3, 0x3C, 0x19, 0x32, 0xC1, 0x20, 0xC9, 0x8B, 0x19, 0xC3, 0xD6, 0x09, 0x21,
-        0x03, 0x28, 0x11, 0xBE, 0x19, 0x0E, 0x13, 0xC3, 0xF3, 0x08, 0x00, 0x00, 0x00, 0x00, 0x3A,
-        0x1E, 0x20, 0xA7, 0xC2, 0xAC, 0x19, 0xDB, 0x01, 0xE6, 0x76, 0xD6, 0x72, 0xC0, 0x3C, 0x32,
-        0x1E, 0x20, 0xDB, 0x01, 0xE6, 0x76, 0xFE, 0x34, 0xC0, 0x21, 0x1B, 0x2E, 0x11, 0xF7, 0x0B,
-        0x0E, 0x09, 0xC3, 0xF3, 0x08, 0x28, 0x13, 0x00, 0x08, 0x13, 0x0E, 0x26, 0x02, 0x0E, 0x11,
-        0x0F, 0x0E, 0x11, 0x00, 0x13, 0x08, 0x0E, 0x0D, 0x28, 0x3E, 0x01, 0x32, 0xE9, 0x20, 0xC9,
-        0xAF, 0xC3, 0xD3, 0x19, 0x00, 0x3A, 0x94, 0x20, 0xA0, 0x32, 0x94, 0x20, 0xD3, 0x03, 0xC9,
-        0x21, 0x01, 0x27, 0xCA, 0xFA, 0x19, 0x11, 0x60, 0x1C, 0x06, 0x10, 0x4F, 0xCD, 0x39, 0x14,
-        0x79, 0x3D, 0xC2, 0xEC, 0x19, 0x06, 0x10, 0xCD, 0xCB, 0x14, 0x7C, 0xFE, 0x35, 0xC2, 0xFA,
-        0x19, 0xC9, 0x21, 0x72, 0x20, 0x46, 0x1A, 0xE6, 0x80, 0xA8, 0xC0, 0x37, 0xC9, 0x32, 0x2B,
-        0x24, 0x1C, 0x16, 0x11, 0x0D, 0x0A, 0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01, 0x34,
-        0x2E, 0x27, 0x22, 0x1C, 0x18, 0x15, 0x13, 0x10, 0x0E, 0x0D, 0x0C, 0x0B, 0x09, 0x07, 0x05,
-        0xFF, 0x1A, 0x77, 0x23, 0x13, 0x05, 0xC2, 0x32, 0x1A, 0xC9, 0x5E, 0x23, 0x56, 0x23, 0x7E,
-        0x23, 0x4E, 0x23, 0x46, 0x61, 0x6F, 0xC9, 0xC5, 0x06, 0x03, 0x7C, 0x1F, 0x67, 0x7D, 0x1F,
-        0x6F, 0x05, 0xC2, 0x4A, 0x1A, 0x7C, 0xE6, 0x3F, 0xF6, 0x20, 0x67, 0xC1, 0xC9, 0x21, 0x00,
-        0x24, 0x36, 0x00, 0x23, 0x7C, 0xFE, 0x40, 0xC2, 0x5F, 0x1A, 0xC9, 0xC5, 0xE5, 0x1A, 0xB6,
-        0x77, 0x13, 0x23, 0x0D, 0xC2, 0x6B, 0x1A, 0xE1, 0x01, 0x20, 0x00, 0x09, 0xC1, 0x05, 0xC2,
-        0x69, 0x1A, 0xC9, 0xCD, 0x2E, 0x09, 0xA7, 0xC8, 0xF5, 0x3D, 0x77, 0xCD, 0xE6, 0x19, 0xF1,
-        0x21, 0x01, 0x25, 0xE6, 0x0F, 0xC3, 0xC5, 0x09, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xB8, 0xFE,
-        0x20, 0x1C, 0x10, 0x9E, 0x00, 0x20, 0x1C, 0x30, 0x10, 0x0B, 0x08, 0x07, 0x06, 0x00, 0x0C,
-        0x04, 0x26, 0x0E, 0x15, 0x04, 0x11, 0x26, 0x26, 0x0F, 0x0B, 0x00, 0x18, 0x04, 0x11, 0x24,
-        0x26, 0x25, 0x1B, 0x26, 0x0E, 0x11, 0x26, 0x1C, 0x0F, 0x0B, 0x00, 0x18, 0x04, 0x11, 0x12,
-        0x26, 0x01, 0x14, 0x13, 0x13, 0x0E, 0x0D, 0x26, 0x0E, 0x0D, 0x0B, 0x18, 0x26, 0x1B, 0x0F,
-        0x0B, 0x00, 0x18, 0x04, 0x11, 0x26, 0x26, 0x01, 0x14, 0x13, 0x13, 0x0E, 0x0D, 0x26, 0x26,
-        0x12, 0x02, 0x0E, 0x11, 0x04, 0x24, 0x1B, 0x25, 0x26, 0x07, 0x08, 0x3F, 0x12, 0x02, 0x0E,
-        0x11, 0x04, 0x26, 0x12, 0x02, 0x0E, 0x11, 0x04, 0x24, 0x1C, 0x25, 0x26, 0x01, 0x00, 0x00,
-        0x10, 0x00, 0x00, 0x00, 0x00, 0x02, 0x78, 0x38, 0x78, 0x38, 0x00, 0xF8, 0x00, 0x00, 0x80,
-        0x00, 0x8E, 0x02, 0xFF, 0x05, 0x0C, 0x60, 0x1C, 0x20, 0x30, 0x10, 0x01, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0xBB, 0x03, 0x00, 0x10, 0x90, 0x1C, 0x28, 0x30, 0x01, 0x04, 0x00, 0xFF, 0xFF,
-        0x00, 0x00, 0x02, 0x76, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0xEE, 0x1C, 0x00, 0x00,
-        0x03, 0x00, 0x00, 0x00, 0xB6, 0x04, 0x00, 0x00, 0x01, 0x00, 0x1D, 0x04, 0xE2, 0x1C, 0x00,
-        0x00, 0x03, 0x00, 0x00, 0x00, 0x82, 0x06, 0x00, 0x00, 0x01, 0x06, 0x1D, 0x04, 0xD0, 0x1C,
-        0x00, 0x00, 0x03, 0xFF, 0x00, 0xC0, 0x1C, 0x00, 0x00, 0x10, 0x21, 0x01, 0x00, 0x30, 0x00,
-        0x12, 0x00, 0x00, 0x00, 0x0F, 0x0B, 0x00, 0x18, 0x26, 0x0F, 0x0B, 0x00, 0x18, 0x04, 0x11,
-        0x24, 0x1B, 0x25, 0xFC, 0x00, 0x01, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x20, 0x64, 0x1D, 0xD0,
-        0x29, 0x18, 0x02, 0x54, 0x1D, 0x00, 0x08, 0x00, 0x06, 0x00, 0x00, 0x01, 0x40, 0x00, 0x01,
-        0x00, 0x00, 0x10, 0x9E, 0x00, 0x20, 0x1C, 0x00, 0x03, 0x04, 0x78, 0x14, 0x13, 0x08, 0x1A,
-        0x3D, 0x68, 0xFC, 0xFC, 0x68, 0x3D, 0x1A, 0x00, 0x00, 0x00, 0x01, 0xB8, 0x98, 0xA0, 0x1B,
-        0x10, 0xFF, 0x00, 0xA0, 0x1B, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x0E, 0x05, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x07, 0xD0, 0x1C, 0xC8, 0x9B, 0x03, 0x00, 0x00, 0x03, 0x04, 0x78,
-        0x14, 0x0B, 0x19, 0x3A, 0x6D, 0xFA, 0xFA, 0x6D, 0x3A, 0x19, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x01, 0x74, 0x1F, 0x00, 0x80, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x1C, 0x2F, 0x00, 0x00, 0x1C, 0x27, 0x00, 0x00, 0x1C, 0x39, 0x00, 0x00,
-        0x39, 0x79, 0x7A, 0x6E, 0xEC, 0xFA, 0xFA, 0xEC, 0x6E, 0x7A, 0x79, 0x39, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x78, 0x1D, 0xBE, 0x6C, 0x3C, 0x3C, 0x3C, 0x6C, 0xBE, 0x1D, 0x78, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x19, 0x3A, 0x6D, 0xFA, 0xFA, 0x6D, 0x3A, 0x19, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x38, 0x7A, 0x7F, 0x6D, 0xEC, 0xFA, 0xFA, 0xEC, 0x6D, 0x7F, 0x7A, 0x38,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x0E, 0x18, 0xBE, 0x6D, 0x3D, 0x3C, 0x3D, 0x6D, 0xBE, 0x18,
-        0x0E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1A, 0x3D, 0x68, 0xFC, 0xFC, 0x68, 0x3D, 0x1A,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0F, 0x1F, 0x1F, 0x1F, 0x1F, 0x7F, 0xFF, 0x7F, 0x1F,
-        0x1F, 0x1F, 0x1F, 0x0F, 0x00, 0x00, 0x04, 0x01, 0x13, 0x03, 0x07, 0xB3, 0x0F, 0x2F, 0x03,
-        0x2F, 0x49, 0x04, 0x03, 0x00, 0x01, 0x40, 0x08, 0x05, 0xA3, 0x0A, 0x03, 0x5B, 0x0F, 0x27,
-        0x27, 0x0B, 0x4B, 0x40, 0x84, 0x11, 0x48, 0x0F, 0x99, 0x3C, 0x7E, 0x3D, 0xBC, 0x3E, 0x7C,
-        0x99, 0x27, 0x1B, 0x1A, 0x26, 0x0F, 0x0E, 0x08, 0x0D, 0x13, 0x12, 0x28, 0x12, 0x02, 0x0E,
-        0x11, 0x04, 0x26, 0x00, 0x03, 0x15, 0x00, 0x0D, 0x02, 0x04, 0x26, 0x13, 0x00, 0x01, 0x0B,
-        0x04, 0x28, 0x02, 0x10, 0x20, 0x30, 0x13, 0x08, 0x0B, 0x13, 0x00, 0x08, 0x49, 0x22, 0x14,
-        0x81, 0x42, 0x00, 0x42, 0x81, 0x14, 0x22, 0x49, 0x08, 0x00, 0x00, 0x44, 0xAA, 0x10, 0x88,
-        0x54, 0x22, 0x10, 0xAA, 0x44, 0x22, 0x54, 0x88, 0x4A, 0x15, 0xBE, 0x3F, 0x5E, 0x25, 0x04,
-        0xFC, 0x04, 0x10, 0xFC, 0x10, 0x20, 0xFC, 0x20, 0x80, 0xFC, 0x80, 0x00, 0xFE, 0x00, 0x24,
-        0xFE, 0x12, 0x00, 0xFE, 0x00, 0x48, 0xFE, 0x90, 0x0F, 0x0B, 0x00, 0x29, 0x00, 0x00, 0x01,
-        0x07, 0x01, 0x01, 0x01, 0x04, 0x0B, 0x01, 0x06, 0x03, 0x01, 0x01, 0x0B, 0x09, 0x02, 0x08,
-        0x02, 0x0B, 0x04, 0x07, 0x0A, 0x05, 0x02, 0x05, 0x04, 0x06, 0x07, 0x08, 0x0A, 0x06, 0x0A,
-        0x03, 0xFF, 0x0F, 0xFF, 0x1F, 0xFF, 0x3F, 0xFF, 0x7F, 0xFF, 0xFF, 0xFC, 0xFF, 0xF8, 0xFF,
-        0xF0, 0xFF, 0xF0, 0xFF, 0xF0, 0xFF, 0xF0, 0xFF, 0xF0, 0xFF, 0xF0, 0xFF, 0xF0, 0xFF, 0xF8,
-        0xFF, 0xFC, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F, 0xFF, 0x3F, 0xFF, 0x1F, 0xFF, 0x0F,
-        0x05, 0x10, 0x15, 0x30, 0x94, 0x97, 0x9A, 0x9D, 0x10, 0x05, 0x05, 0x10, 0x15, 0x10, 0x10,
-        0x05, 0x30, 0x10, 0x10, 0x10, 0x05, 0x15, 0x10, 0x05, 0x00, 0x00, 0x00, 0x00, 0x04, 0x0C,
-        0x1E, 0x37, 0x3E, 0x7C, 0x74, 0x7E, 0x7E, 0x74, 0x7C, 0x3E, 0x37, 0x1E, 0x0C, 0x04, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x22, 0x00, 0xA5, 0x40, 0x08, 0x98, 0x3D, 0xB6, 0x3C, 0x36, 0x1D,
-        0x10, 0x48, 0x62, 0xB6, 0x1D, 0x98, 0x08, 0x42, 0x90, 0x08, 0x00, 0x00, 0x26, 0x1F, 0x1A,
-        0x1B, 0x1A, 0x1A, 0x1B, 0x1F, 0x1A, 0x1D, 0x1A, 0x1A, 0x10, 0x20, 0x30, 0x60, 0x50, 0x48,
-        0x48, 0x48, 0x40, 0x40, 0x40, 0x0F, 0x0B, 0x00, 0x18, 0x12, 0x0F, 0x00, 0x02, 0x04, 0x26,
-        0x26, 0x08, 0x0D, 0x15, 0x00, 0x03, 0x04, 0x11, 0x12, 0x0E, 0x2C, 0x68, 0x1D, 0x0C, 0x2C,
-        0x20, 0x1C, 0x0A, 0x2C, 0x40, 0x1C, 0x08, 0x2C, 0x00, 0x1C, 0xFF, 0x0E, 0x2E, 0xE0, 0x1D,
-        0x0C, 0x2E, 0xEA, 0x1D, 0x0A, 0x2E, 0xF4, 0x1D, 0x08, 0x2E, 0x99, 0x1C, 0xFF, 0x27, 0x38,
-        0x26, 0x0C, 0x18, 0x12, 0x13, 0x04, 0x11, 0x18, 0x27, 0x1D, 0x1A, 0x26, 0x0F, 0x0E, 0x08,
-        0x0D, 0x13, 0x12, 0x27, 0x1C, 0x1A, 0x26, 0x0F, 0x0E, 0x08, 0x0D, 0x13, 0x12, 0x00, 0x00,
-        0x00, 0x1F, 0x24, 0x44, 0x24, 0x1F, 0x00, 0x00, 0x00, 0x7F, 0x49, 0x49, 0x49, 0x36, 0x00,
-        0x00, 0x00, 0x3E, 0x41, 0x41, 0x41, 0x22, 0x00, 0x00, 0x00, 0x7F, 0x41, 0x41, 0x41, 0x3E,
-        0x00, 0x00, 0x00, 0x7F, 0x49, 0x49, 0x49, 0x41, 0x00, 0x00, 0x00, 0x7F, 0x48, 0x48, 0x48,
-        0x40, 0x00, 0x00, 0x00, 0x3E, 0x41, 0x41, 0x45, 0x47, 0x00, 0x00, 0x00, 0x7F, 0x08, 0x08,
-        0x08, 0x7F, 0x00, 0x00, 0x00, 0x00, 0x41, 0x7F, 0x41, 0x00, 0x00, 0x00, 0x00, 0x02, 0x01,
-        0x01, 0x01, 0x7E, 0x00, 0x00, 0x00, 0x7F, 0x08, 0x14, 0x22, 0x41, 0x00, 0x00, 0x00, 0x7F,
-        0x01, 0x01, 0x01, 0x01, 0x00, 0x00, 0x00, 0x7F, 0x20, 0x18, 0x20, 0x7F, 0x00, 0x00, 0x00,
-        0x7F, 0x10, 0x08, 0x04, 0x7F, 0x00, 0x00, 0x00, 0x3E, 0x41, 0x41, 0x41, 0x3E, 0x00, 0x00,
-        0x00, 0x7F, 0x48, 0x48, 0x48, 0x30, 0x00, 0x00, 0x00, 0x3E, 0x41, 0x45, 0x42, 0x3D, 0x00,
-        0x00, 0x00, 0x7F, 0x48, 0x4C, 0x4A, 0x31, 0x00, 0x00, 0x00, 0x32, 0x49, 0x49, 0x49, 0x26,
-        0x00, 0x00, 0x00, 0x40, 0x40, 0x7F, 0x40, 0x40, 0x00, 0x00, 0x00, 0x7E, 0x01, 0x01, 0x01,
-        0x7E, 0x00, 0x00, 0x00, 0x7C, 0x02, 0x01, 0x02, 0x7C, 0x00, 0x00, 0x00, 0x7F, 0x02, 0x0C,
-        0x02, 0x7F, 0x00, 0x00, 0x00, 0x63, 0x14, 0x08, 0x14, 0x63, 0x00, 0x00, 0x00, 0x60, 0x10,
-        0x0F, 0x10, 0x60, 0x00, 0x00, 0x00, 0x43, 0x45, 0x49, 0x51, 0x61, 0x00, 0x00, 0x00, 0x3E,
-        0x45, 0x49, 0x51, 0x3E, 0x00, 0x00, 0x00, 0x00, 0x21, 0x7F, 0x01, 0x00, 0x00, 0x00, 0x00,
-        0x23, 0x45, 0x49, 0x49, 0x31, 0x00, 0x00, 0x00, 0x42, 0x41, 0x49, 0x59, 0x66, 0x00, 0x00,
-        0x00, 0x0C, 0x14, 0x24, 0x7F, 0x04, 0x00, 0x00, 0x00, 0x72, 0x51, 0x51, 0x51, 0x4E, 0x00,
-        0x00, 0x00, 0x1E, 0x29, 0x49, 0x49, 0x46, 0x00, 0x00, 0x00, 0x40, 0x47, 0x48, 0x50, 0x60,
-        0x00, 0x00, 0x00, 0x36, 0x49, 0x49, 0x49, 0x36, 0x00, 0x00, 0x00, 0x31, 0x49, 0x49, 0x4A,
-        0x3C, 0x00, 0x00, 0x00, 0x08, 0x14, 0x22, 0x41, 0x00, 0x00, 0x00, 0x00, 0x00, 0x41, 0x22,
-        0x14, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x14, 0x14,
-        0x14, 0x14, 0x14, 0x00, 0x00, 0x00, 0x22, 0x14, 0x7F, 0x14, 0x22, 0x00, 0x00, 0x00, 0x03,
-        0x04, 0x78, 0x04, 0x03, 0x00, 0x00, 0x24, 0x1B, 0x26, 0x0E, 0x11, 0x26, 0x1C, 0x26, 0x0F,
-        0x0B, 0x00, 0x18, 0x04, 0x11, 0x12, 0x25, 0x26, 0x26, 0x28, 0x1B, 0x26, 0x0F, 0x0B, 0x00,
-        0x18, 0x04, 0x11, 0x26, 0x26, 0x1B, 0x26, 0x02, 0x0E, 0x08, 0x0D, 0x26, 0x01, 0x01, 0x00,
-        0x00, 0x01, 0x00, 0x02, 0x01, 0x00, 0x02, 0x01, 0x00, 0x60, 0x10, 0x0F, 0x10, 0x60, 0x30,
-        0x18, 0x1A, 0x3D, 0x68, 0xFC, 0xFC, 0x68, 0x3D, 0x1A, 0x00, 0x08, 0x0D, 0x12, 0x04, 0x11,
-        0x13, 0x26, 0x26, 0x02, 0x0E, 0x08, 0x0D, 0x0D, 0x2A, 0x50, 0x1F, 0x0A, 0x2A, 0x62, 0x1F,
-        0x07, 0x2A, 0xE1, 0x1F, 0xFF, 0x02, 0x11, 0x04, 0x03, 0x08, 0x13, 0x26, 0x00, 0x60, 0x10,
-        0x0F, 0x10, 0x60, 0x38, 0x19, 0x3A, 0x6D, 0xFA, 0xFA, 0x6D, 0x3A, 0x19, 0x00, 0x00, 0x20,
-        0x40, 0x4D, 0x50, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xB8, 0xFF, 0x80, 0x1F, 0x10,
-        0x97, 0x00, 0x80, 0x1F, 0x00, 0x00, 0x01, 0xD0, 0x22, 0x20, 0x1C, 0x10, 0x94, 0x00, 0x20,
-        0x1C, 0x28, 0x1C, 0x26, 0x0F, 0x0B, 0x00, 0x18, 0x04, 0x11, 0x12, 0x26, 0x1C, 0x26, 0x02,
-        0x0E, 0x08, 0x0D, 0x12, 0x0F, 0x14, 0x12, 0x07, 0x26, 0x00, 0x08, 0x08, 0x08, 0x08, 0x08,
-        0x00, 0x00,
-    ]
-}
@@ -0,0 +1,320 @@
+//! The MITS Altair 8800.
+//!
+//! The machine has 64K of RAM, a terminal wired to both serial cards MITS
+//! sold, and the front panel's sense switches:
+//!
+//! * the 88-SIO at ports 0x00 (status) and 0x01 (data), whose status bits are
+//!   active low: bit 0 clear when a character has arrived and bit 7 clear
+//!   when the card can send;
+//! * the 88-2SIO's first port, a 6850 ACIA, at ports 0x10 (status and
+//!   control) and 0x11 (data), with bit 0 set when a character has arrived and
+//!   bit 1 set when the card can send;
+//! * the 88-DCDD floppy disk controller at ports 0x08 to 0x0a, see `dcdd`;
+//! * the sense switches, A8 to A15 of the front panel, read at port 0xff.
+//!
+//! Disk BASIC and CP/M boot from the disk boot loader PROM at 0xff00, whose
+//! image has to be loaded along with the disks.
+//!
+//! Ports without a card read 0xff, as the Altair's bus floats high.
+
+pub mod dcdd;
+
+use self::dcdd::Dcdd;
+use crate::{
+    interconnect::Interconnect,
+    io::{Console, IO},
+    loader::{self, Loaded},
+    mmu::Ram,
+    pic::InterruptController,
+    Emulator,
+};
+
+use failure::Error;
+use std::cell::{Cell, Ref, RefCell, RefMut};
+
+pub const SIO_STATUS: u8 = 0x00;
+pub const SIO_DATA: u8 = 0x01;
+pub const SIO2_STATUS: u8 = 0x10;
+pub const SIO2_DATA: u8 = 0x11;
+pub const SENSE_SWITCHES: u8 = 0xff;
+
+/// 88-SIO status: no character waiting.
+const SIO_INPUT_EMPTY: u8 = 0x01;
+/// 6850 status: receive data register full.
+const ACIA_RDRF: u8 = 0x01;
+/// 6850 status: transmit data register empty.
+const ACIA_TDRE: u8 = 0x02;
+/// 6850 control: master reset.
+const ACIA_RESET: u8 = 0x03;
+
+/// Most cycles between two status reads of a loop waiting for a character.
+/// Programs that check for Control-C while they run read the status far
+/// less often.
+const WAIT_LOOP_CYCLES: u64 = 64;
+/// Status reads in a row, after the input has ended, taken as waiting for
+/// more.
+const WAIT_POLLS: u32 = 8;
+
+/// Why the machine stopped.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Exit {
+    /// The CPU executed a HLT. Nothing on the machine raises interrupts to
+    /// wake it.
+    Halted,
+    /// The program sat polling a serial card for input after the input
+    /// ended.
+    EndOfInput,
+}
+
+/// Port hardware of the Altair: the serial cards, the disk controller and
+/// the sense switches.
+pub struct AltairIo<C: Console> {
+    console: RefCell<C>,
+    dcdd: RefCell<Dcdd>,
+    /// Last character read, which the data registers keep returning until a
+    /// new one arrives.
+    last: RefCell<u8>,
+    sense_switches: u8,
+    acia_control: u8,
+    /// Cycles since the last status read.
+    since_poll: Cell<u64>,
+    /// Status reads in a row, each soon after the last, with the input
+    /// ended.
+    waiting: Cell<u32>,
+}
+
+impl<C: Console> AltairIo<C> {
+    pub fn new(console: C) -> AltairIo<C> {
+        AltairIo {
+            console: RefCell::new(console),
+            dcdd: RefCell::default(),
+            last: RefCell::new(0),
+            sense_switches: 0,
+            acia_control: 0,
+            since_poll: Cell::new(0),
+            waiting: Cell::new(0),
+        }
+    }
+
+    /// Reads the console status for a status port, noting whether the
+    /// program is looping on it for input that will never come.
+    fn input_ready(&self) -> bool {
+        let mut console = self.console.borrow_mut();
+        let ready = console.status();
+        let waiting = match !ready && console.ended() {
+            true if self.since_poll.get() <= WAIT_LOOP_CYCLES => self.waiting.get() + 1,
+            _ => 0,
+        };
+        self.waiting.set(waiting);
+        self.since_poll.set(0);
+        ready
+    }
+
+    /// Whether the program is waiting for input after the input has ended.
+    pub fn end_of_input(&self) -> bool {
+        self.waiting.get() >= WAIT_POLLS
+    }
+
+    fn read_data(&self) -> u8 {
+        let mut console = self.console.borrow_mut();
+        if console.status() {
+            if let Some(c) = console.read() {
+                *self.last.borrow_mut() = c;
+            }
+        }
+        *self.last.borrow()
+    }
+}
+
+impl<C: Console> IO for AltairIo<C> {
+    fn read_port(&self, port: u8) -> u8 {
+        match port {
+            SIO_STATUS => match self.input_ready() {
+                true => 0x00,
+                false => SIO_INPUT_EMPTY,
+            },
+            SIO2_STATUS => match (self.acia_control & ACIA_RESET, self.input_ready()) {
+                (ACIA_RESET, _) => 0x00,
+                (_, true) => ACIA_TDRE | ACIA_RDRF,
+                (_, false) => ACIA_TDRE,
+            },
+            SIO_DATA | SIO2_DATA => self.read_data(),
+            dcdd::SELECT..=dcdd::DATA => self.dcdd.borrow_mut().read_port(port),
+            SENSE_SWITCHES => self.sense_switches,
+            _ => 0xff,
+        }
+    }
+
+    fn write_port(&mut self, port: u8, value: u8) {
+        match port {
+            // Terminals of the day ignored the parity bit some programs set.
+            SIO_DATA | SIO2_DATA => self.console.get_mut().write(value & 0x7f),
+            SIO2_STATUS => self.acia_control = value,
+            dcdd::SELECT..=dcdd::DATA => self.dcdd.get_mut().write_port(port, value),
+            _ => {}
+        }
+    }
+
+    fn clock(&mut self, cycles: u64, _interrupts: &mut InterruptController) {
+        let since_poll = self.since_poll.get_mut();
+        *since_poll = since_poll.saturating_add(cycles);
+    }
+}
+
+pub struct Altair<C: Console> {
+    emulator: Emulator<Ram, AltairIo<C>>,
+}
+
+impl<C: Console> Altair<C> {
+    pub fn new(console: C) -> Altair<C> {
+        let interconnect = Interconnect::from_parts(Ram::new(), AltairIo::new(console));
+        Altair {
+            emulator: Emulator::with_interconnect(interconnect),
+        }
+    }
+
+    pub fn emulator(&self) -> &Emulator<Ram, AltairIo<C>> {
+        &self.emulator
+    }
+
+    pub fn emulator_mut(&mut self) -> &mut Emulator<Ram, AltairIo<C>> {
+        &mut self.emulator
+    }
+
+    pub fn console(&self) -> Ref<'_, C> {
+        self.emulator.interconnect.io.console.borrow()
+    }
+
+    pub fn console_mut(&mut self) -> RefMut<'_, C> {
+        self.emulator.interconnect.io.console.borrow_mut()
+    }
+
+    pub fn sense_switches(&self) -> u8 {
+        self.emulator.interconnect.io.sense_switches
+    }
+
+    /// Sets the sense switches, with switch A15 in bit 7.
+    pub fn set_sense_switches(&mut self, switches: u8) {
+        self.emulator.interconnect.io.sense_switches = switches;
+    }
+
+    /// Puts a `.dsk` image in disk drive `drive`, 0 to 15.
+    ///
+    /// #Errors
+    /// Fails if the drive number is out of range or the image is too long.
+    pub fn insert_disk(&mut self, drive: usize, image: Vec<u8>) -> Result<(), Error> {
+        self.emulator
+            .interconnect
+            .io
+            .dcdd
+            .get_mut()
+            .insert(drive, image)
+    }
+
+    /// Takes the image out of disk drive `drive`, with whatever was written
+    /// to it.
+    pub fn eject_disk(&mut self, drive: usize) -> Option<Vec<u8>> {
+        self.emulator.interconnect.io.dcdd.get_mut().eject(drive)
+    }
+
+    /// Loads a memory image at `addr` and sets the program counter to
+    /// `start`, as toggling in a jump on the front panel would.
+    ///
+    /// #Errors
+    /// Fails if the image runs past 0xffff.
+    pub fn load_binary(&mut self, image: &[u8], addr: u16, start: u16) -> Result<Loaded, Error> {
+        let loaded = loader::load_binary(&mut self.emulator.interconnect.mmu, image, addr, start)?;
+        self.emulator.cpu_mut().set_pc(start);
+        Ok(loaded)
+    }
+
+    /// Loads an Intel HEX file, starting at its start address or else at
+    /// its lowest address.
+    ///
+    /// #Errors
+    /// Fails if the file is malformed.
+    pub fn load_hex(&mut self, text: &str) -> Result<Loaded, Error> {
+        let loaded = loader::load_hex(&mut self.emulator.interconnect.mmu, text)?;
+        let start = loaded.start.or_else(|| loaded.range.map(|(low, _)| low));
+        self.emulator.cpu_mut().set_pc(start.unwrap_or(0));
+        Ok(loaded)
+    }
+
+    /// Executes one instruction, unless the machine has stopped.
+    pub fn step(&mut self) -> Result<Option<Exit>, Error> {
+        if self.emulator.interconnect.io.end_of_input() {
+            return Ok(Some(Exit::EndOfInput));
+        }
+        // Nothing raises interrupts, so a HLT is final.
+        if self.emulator.cpu().halted() {
+            return Ok(Some(Exit::Halted));
+        }
+        self.emulator.try_step()?;
+        Ok(None)
+    }
+
+    /// Runs until the CPU halts or waits for input after the console's
+    /// input has ended.
+    pub fn run(&mut self) -> Result<Exit, Error> {
+        loop {
+            if let Some(exit) = self.step()? {
+                return Ok(exit);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Altair, Exit};
+    use crate::io::BufferConsole;
+
+    #[test]
+    fn echoes_through_both_serial_cards() {
+        let program = [
+            0xdb, 0x00, //       IN 0x00
+            0x0f, //             RRC
+            0xda, 0x00, 0x01, // JC 0x0100
+            0xdb, 0x01, //       IN 0x01
+            0xd3, 0x11, //       OUT 0x11
+            0xfe, b'.', //       CPI '.'
+            0xc2, 0x00, 0x01, // JNZ 0x0100
+            0xdb, 0xff, //       IN 0xff
+            0xd3, 0x01, //       OUT 0x01
+            0x76, //             HLT
+        ];
+        let mut altair = Altair::new(BufferConsole::new("Hi."));
+        altair.set_sense_switches(b'!' | 0x80);
+        altair.load_binary(&program, 0x0100, 0x0100).unwrap();
+        assert_eq!(altair.run().unwrap(), Exit::Halted);
+        assert_eq!(altair.console().text(), "Hi.!");
+    }
+
+    #[test]
+    fn stops_waiting_for_input_that_has_ended() {
+        let program = [
+            0xdb, 0x10, //       IN 0x10
+            0x0f, //             RRC
+            0xd2, 0x00, 0x01, // JNC 0x0100
+            0xdb, 0x11, //       IN 0x11
+            0xd3, 0x11, //       OUT 0x11
+            0x4f, //             MOV C, A
+            // Checks for Control-C every 200 cycles or so without waiting.
+            0x06, 0x08, //       MVI B, 8
+            0xdb, 0x10, //       IN 0x10
+            0x1e, 0x0c, //       MVI E, 12
+            0x1d, //             DCR E
+            0xc2, 0x11, 0x01, // JNZ 0x0111
+            0x05, //             DCR B
+            0xc2, 0x0d, 0x01, // JNZ 0x010d
+            0x79, //             MOV A, C
+            0xd3, 0x11, //       OUT 0x11
+            0xc3, 0x00, 0x01, // JMP 0x0100
+        ];
+        let mut altair = Altair::new(BufferConsole::new("ab"));
+        altair.load_binary(&program, 0x0100, 0x0100).unwrap();
+        assert_eq!(altair.run().unwrap(), Exit::EndOfInput);
+        assert_eq!(altair.console().text(), "aabb");
+        assert!(altair.emulator().cpu().pc() < 0x0106);
+    }
+}
@@ -0,0 +1,287 @@
+//! The MITS 88-DCDD 8" floppy disk controller.
+//!
+//! The controller takes three ports:
+//!
+//! * 0x08: OUT selects a drive in bits 0 to 3, or deselects with bit 7 set.
+//!   IN returns the status of the selected drive, active low: bit 0 ready
+//!   for a write byte, bit 1 head can move, bit 2 head loaded, bit 5
+//!   interrupts enabled, bit 6 on track 0 and bit 7 read data available.
+//!   Bits 3 and 4 read 0, and everything reads 1 with no drive selected.
+//! * 0x09: OUT issues commands: bit 0 step in, bit 1 step out, bit 2 load
+//!   head, bit 3 unload head, bits 4 and 5 enable and disable interrupts and
+//!   bit 7 write enable. IN returns the sector position once the head is
+//!   loaded: bit 0 clear at the start of a sector and the sector number in
+//!   bits 1 to 5.
+//! * 0x0a: reads and writes the data bytes of the current sector.
+//!
+//! Disks have 77 tracks of 32 sectors of 137 bytes, and `.dsk` images hold
+//! them in that order. There is no rotation timing: the sector position
+//! moves on each time it is read, so every poll finds the next sector.
+
+use failure::{bail, Error};
+
+pub const TRACKS: usize = 77;
+pub const SECTORS: usize = 32;
+pub const SECTOR_LEN: usize = 137;
+pub const IMAGE_LEN: usize = TRACKS * SECTORS * SECTOR_LEN;
+const DRIVES: usize = 16;
+
+pub const SELECT: u8 = 0x08;
+pub const CONTROL: u8 = 0x09;
+pub const DATA: u8 = 0x0a;
+
+// Status bits, which are active low.
+const ENWD: u8 = 0x01;
+const MOVE_HEAD: u8 = 0x02;
+const HEAD_STATUS: u8 = 0x04;
+const INTERRUPTS: u8 = 0x20;
+const TRACK_ZERO: u8 = 0x40;
+const NRDA: u8 = 0x80;
+
+// Control bits.
+const STEP_IN: u8 = 0x01;
+const STEP_OUT: u8 = 0x02;
+const HEAD_LOAD: u8 = 0x04;
+const HEAD_UNLOAD: u8 = 0x08;
+const INTERRUPT_ENABLE: u8 = 0x10;
+const INTERRUPT_DISABLE: u8 = 0x20;
+const WRITE_ENABLE: u8 = 0x80;
+
+#[derive(Clone, Debug, Default)]
+struct Drive {
+    image: Option<Vec<u8>>,
+    track: usize,
+    sector: usize,
+    /// Whether the sector position is at the start of `sector`.
+    sector_true: bool,
+    head_loaded: bool,
+}
+
+/// The controller and its drives.
+#[derive(Clone, Debug, Default)]
+pub struct Dcdd {
+    drives: [Drive; DRIVES],
+    selected: Option<usize>,
+    /// Next byte of the sector to read or write.
+    byte: usize,
+    writing: bool,
+    interrupts: bool,
+}
+
+impl Dcdd {
+    /// Puts a `.dsk` image in drive `drive`, 0 to 15. Short images are
+    /// padded with formatted (0xe5) bytes.
+    ///
+    /// #Errors
+    /// Fails if the drive number is out of range or the image is longer than
+    /// a disk.
+    pub fn insert(&mut self, drive: usize, mut image: Vec<u8>) -> Result<(), Error> {
+        if drive >= DRIVES {
+            bail!("drive {} is not one of 0 to {}", drive, DRIVES - 1);
+        }
+        if image.len() > IMAGE_LEN {
+            bail!(
+                "image of {} bytes is longer than the {} bytes of a disk",
+                image.len(),
+                IMAGE_LEN
+            );
+        }
+        image.resize(IMAGE_LEN, 0xe5);
+        self.drives[drive].image = Some(image);
+        Ok(())
+    }
+
+    /// Takes the image out of `drive`, with whatever was written to it.
+    pub fn eject(&mut self, drive: usize) -> Option<Vec<u8>> {
+        self.drives.get_mut(drive).and_then(|d| d.image.take())
+    }
+
+    pub fn image(&self, drive: usize) -> Option<&[u8]> {
+        self.drives.get(drive).and_then(|d| d.image.as_deref())
+    }
+
+    pub fn read_port(&mut self, port: u8) -> u8 {
+        match port {
+            SELECT => self.status(),
+            CONTROL => self.sector_position(),
+            DATA => self.read_data(),
+            _ => 0xff,
+        }
+    }
+
+    pub fn write_port(&mut self, port: u8, value: u8) {
+        match port {
+            SELECT => self.select(value),
+            CONTROL => self.control(value),
+            DATA => self.write_data(value),
+            _ => {}
+        }
+    }
+
+    fn drive(&mut self) -> Option<&mut Drive> {
+        let drive = &mut self.drives[self.selected?];
+        drive.image.as_ref()?;
+        Some(drive)
+    }
+
+    fn status(&mut self) -> u8 {
+        let (writing, interrupts) = (self.writing, self.interrupts);
+        let drive = match self.drive() {
+            Some(drive) => drive,
+            None => return 0xff,
+        };
+        let mut status = ENWD | HEAD_STATUS | INTERRUPTS | TRACK_ZERO | NRDA;
+        if writing {
+            status &= !ENWD;
+        }
+        if drive.head_loaded {
+            status &= !(HEAD_STATUS | NRDA);
+        }
+        if interrupts {
+            status &= !INTERRUPTS;
+        }
+        if drive.track == 0 {
+            status &= !TRACK_ZERO;
+        }
+        // The head can always move, as stepping takes no time here.
+        status & !MOVE_HEAD
+    }
+
+    fn select(&mut self, value: u8) {
+        self.writing = false;
+        self.selected = match value & 0x80 {
+            0 => Some(usize::from(value & 0x0f)),
+            _ => None,
+        };
+    }
+
+    fn control(&mut self, value: u8) {
+        if value & INTERRUPT_ENABLE != 0 {
+            self.interrupts = true;
+        }
+        if value & INTERRUPT_DISABLE != 0 {
+            self.interrupts = false;
+        }
+        let drive = match self.drive() {
+            Some(drive) => drive,
+            None => return,
+        };
+        if value & STEP_IN != 0 && drive.track < TRACKS - 1 {
+            drive.track += 1;
+        }
+        if value & STEP_OUT != 0 && drive.track > 0 {
+            drive.track -= 1;
+        }
+        if value & HEAD_LOAD != 0 {
+            drive.head_loaded = true;
+        }
+        if value & HEAD_UNLOAD != 0 {
+            drive.head_loaded = false;
+        }
+        let stepped = value & (STEP_IN | STEP_OUT) != 0;
+        self.byte = 0;
+        if value & WRITE_ENABLE != 0 {
+            self.writing = true;
+        } else if stepped {
+            self.writing = false;
+        }
+    }
+
+    fn sector_position(&mut self) -> u8 {
+        let drive = match self.drive() {
+            Some(drive) if drive.head_loaded => drive,
+            _ => return 0xff,
+        };
+        drive.sector_true = !drive.sector_true;
+        if drive.sector_true {
+            drive.sector = (drive.sector + 1) % SECTORS;
+        }
+        let sector = drive.sector;
+        let sector_true = drive.sector_true;
+        if sector_true {
+            self.byte = 0;
+        }
+        0xc0 | (sector as u8) << 1 | u8::from(!sector_true)
+    }
+
+    /// Offset in the image of the current byte.
+    fn offset(&mut self) -> Option<usize> {
+        let byte = self.byte;
+        let drive = self.drive()?;
+        Some((drive.track * SECTORS + drive.sector) * SECTOR_LEN + byte)
+    }
+
+    fn read_data(&mut self) -> u8 {
+        if self.byte >= SECTOR_LEN {
+            return 0x00;
+        }
+        let value = match self.offset() {
+            Some(offset) => self.drive().and_then(|d| d.image.as_ref()).unwrap()[offset],
+            None => 0xff,
+        };
+        self.byte += 1;
+        value
+    }
+
+    fn write_data(&mut self, value: u8) {
+        if !self.writing || self.byte >= SECTOR_LEN {
+            return;
+        }
+        if let Some(offset) = self.offset() {
+            self.drive().and_then(|d| d.image.as_mut()).unwrap()[offset] = value;
+        }
+        self.byte += 1;
+        if self.byte == SECTOR_LEN {
+            self.writing = false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Dcdd, CONTROL, DATA, IMAGE_LEN, SECTOR_LEN, SELECT};
+
+    /// Waits for the start of `sector`.
+    fn find_sector(dcdd: &mut Dcdd, sector: u8) {
+        for _ in 0..100 {
+            if dcdd.read_port(CONTROL) == 0xc0 | sector << 1 {
+                return;
+            }
+        }
+        panic!("sector {} never came round", sector);
+    }
+
+    #[test]
+    fn reads_and_writes_sectors() {
+        let mut image = vec![0; IMAGE_LEN];
+        let offset = (32 + 5) * SECTOR_LEN;
+        image[offset..offset + 3].copy_from_slice(&[0x81, 0x02, 0x03]);
+        let mut dcdd = Dcdd::default();
+        dcdd.insert(1, image).unwrap();
+        assert!(dcdd.insert(1, vec![0; IMAGE_LEN + 1]).is_err());
+
+        assert_eq!(dcdd.read_port(SELECT), 0xff);
+        dcdd.write_port(SELECT, 0x01);
+        assert_eq!(dcdd.read_port(SELECT), 0xa5);
+        assert_eq!(dcdd.read_port(CONTROL), 0xff);
+
+        dcdd.write_port(CONTROL, 0x04);
+        dcdd.write_port(CONTROL, 0x01);
+        assert_eq!(dcdd.read_port(SELECT), 0x61);
+        find_sector(&mut dcdd, 5);
+        assert_eq!(dcdd.read_port(DATA), 0x81);
+        assert_eq!(dcdd.read_port(DATA), 0x02);
+
+        find_sector(&mut dcdd, 5);
+        dcdd.write_port(CONTROL, 0x80);
+        assert_eq!(dcdd.read_port(SELECT) & 0x01, 0);
+        for _ in 0..SECTOR_LEN {
+            dcdd.write_port(DATA, 0x55);
+        }
+        assert_eq!(dcdd.read_port(SELECT) & 0x01, 0x01);
+        dcdd.write_port(SELECT, 0x80);
+        let image = dcdd.eject(1).unwrap();
+        assert_eq!(image[offset..offset + SECTOR_LEN], [0x55; SECTOR_LEN][..]);
+        assert_eq!(image[offset + SECTOR_LEN], 0);
+    }
+}
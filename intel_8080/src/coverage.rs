@@ -0,0 +1,212 @@
+//! Code/data logging (CDL) for ROM coverage.
+//!
+//! `CodeDataLogger` is a `MemoryObserver` that marks every ROM byte the CPU
+//! touches as an executed opcode, an executed operand or data that was read.
+//! The result can be saved as a coverage map with one flag byte per ROM byte,
+//! loaded back to merge several runs, and summarised as percentages.
+
+use crate::mmu::{MemoryAccess, MemoryAccessKind, MemoryObserver};
+
+use failure::{bail, Error};
+use std::{
+    fmt::{self, Display},
+    io::{Read, Write},
+};
+
+/// Flag bits stored per ROM byte in a coverage map.
+pub const OPCODE: u8 = 0x01;
+pub const OPERAND: u8 = 0x02;
+pub const DATA: u8 = 0x04;
+
+pub struct CodeDataLogger {
+    flags: Vec<u8>,
+}
+
+impl CodeDataLogger {
+    /// Creates an empty log for a ROM of `rom_len` bytes mapped at address 0.
+    pub fn new(rom_len: usize) -> CodeDataLogger {
+        CodeDataLogger {
+            flags: vec![0; rom_len],
+        }
+    }
+
+    /// Loads a coverage map previously written by `write_map`.
+    pub fn read_map<R: Read>(mut input: R) -> Result<CodeDataLogger, Error> {
+        let mut flags = Vec::new();
+        input.read_to_end(&mut flags)?;
+        if let Some(bad) = flags.iter().find(|&&f| f & !(OPCODE | OPERAND | DATA) != 0) {
+            bail!("invalid coverage flag byte 0x{:02x}", bad);
+        }
+        Ok(CodeDataLogger { flags })
+    }
+
+    /// Writes the coverage map: one byte per ROM byte, a combination of the
+    /// `OPCODE`, `OPERAND` and `DATA` bits, or zero if never touched.
+    pub fn write_map<W: Write>(&self, mut out: W) -> Result<(), Error> {
+        out.write_all(&self.flags)?;
+        Ok(())
+    }
+
+    /// Combines the coverage of another run of the same ROM into this one.
+    pub fn merge(&mut self, other: &CodeDataLogger) -> Result<(), Error> {
+        if other.flags.len() != self.flags.len() {
+            bail!(
+                "coverage maps differ in size: {} vs {} bytes",
+                self.flags.len(),
+                other.flags.len()
+            );
+        }
+        for (mine, theirs) in self.flags.iter_mut().zip(other.flags.iter()) {
+            *mine |= theirs;
+        }
+        Ok(())
+    }
+
+    pub fn flags(&self, addr: u16) -> u8 {
+        self.flags.get(addr as usize).cloned().unwrap_or(0)
+    }
+
+    pub fn summary(&self) -> CoverageSummary {
+        let count = |mask| self.flags.iter().filter(|&&f| f & mask != 0).count();
+        CoverageSummary {
+            total: self.flags.len(),
+            opcode: count(OPCODE),
+            operand: count(OPERAND),
+            data: count(DATA),
+            untouched: self.flags.iter().filter(|&&f| f == 0).count(),
+        }
+    }
+
+    fn mark(&mut self, addr: u16, flag: u8) {
+        if let Some(f) = self.flags.get_mut(addr as usize) {
+            *f |= flag;
+        }
+    }
+}
+
+impl MemoryObserver for CodeDataLogger {
+    fn observe(&mut self, access: MemoryAccess) {
+        match access.kind {
+            MemoryAccessKind::Fetch => self.mark(access.addr, OPCODE),
+            MemoryAccessKind::Operand => self.mark(access.addr, OPERAND),
+            MemoryAccessKind::Read => self.mark(access.addr, DATA),
+            MemoryAccessKind::Write => {}
+        }
+    }
+}
+
+/// Byte counts for each kind of ROM usage. A byte can count towards more
+/// than one kind, e.g. code that is also read as a table.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CoverageSummary {
+    pub total: usize,
+    pub opcode: usize,
+    pub operand: usize,
+    pub data: usize,
+    pub untouched: usize,
+}
+
+impl CoverageSummary {
+    /// Percentage of ROM bytes touched in any way.
+    pub fn coverage(&self) -> f64 {
+        match self.total {
+            0 => 0.0,
+            total => (total - self.untouched) as f64 * 100.0 / total as f64,
+        }
+    }
+}
+
+impl Display for CoverageSummary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let percent = |n: usize| match self.total {
+            0 => 0.0,
+            total => n as f64 * 100.0 / total as f64,
+        };
+        writeln!(
+            f,
+            "ROM coverage: {:.2}% of {} bytes",
+            self.coverage(),
+            self.total
+        )?;
+        writeln!(
+            f,
+            "  opcode:    {:>6} ({:.2}%)",
+            self.opcode,
+            percent(self.opcode)
+        )?;
+        writeln!(
+            f,
+            "  operand:   {:>6} ({:.2}%)",
+            self.operand,
+            percent(self.operand)
+        )?;
+        writeln!(
+            f,
+            "  data:      {:>6} ({:.2}%)",
+            self.data,
+            percent(self.data)
+        )?;
+        write!(
+            f,
+            "  untouched: {:>6} ({:.2}%)",
+            self.untouched,
+            percent(self.untouched)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CodeDataLogger, DATA, OPCODE, OPERAND};
+    use crate::Emulator;
+    use std::{cell::RefCell, rc::Rc};
+
+    #[test]
+    fn logs_code_and_data() {
+        let bytecode = [
+            0x3a, 0x07, 0x00, // LDA 0x0007
+            0xc3, 0x08, 0x00, // JMP 0x0008
+            0xff, //             never touched
+            0x42, //             data
+            0x00, //             NOP
+        ];
+        let cdl = Rc::new(RefCell::new(CodeDataLogger::new(bytecode.len())));
+        let mut system = Emulator::new(bytecode).unwrap();
+        system.add_observer(cdl.clone());
+        system.run();
+
+        let cdl = cdl.borrow();
+        assert_eq!(cdl.flags(0x0000), OPCODE);
+        assert_eq!(cdl.flags(0x0001), OPERAND);
+        assert_eq!(cdl.flags(0x0002), OPERAND);
+        assert_eq!(cdl.flags(0x0006), 0);
+        assert_eq!(cdl.flags(0x0007), DATA);
+        assert_eq!(cdl.flags(0x0008), OPCODE);
+
+        let summary = cdl.summary();
+        assert_eq!(summary.total, 9);
+        assert_eq!(summary.opcode, 3);
+        assert_eq!(summary.operand, 4);
+        assert_eq!(summary.data, 1);
+        assert_eq!(summary.untouched, 1);
+    }
+
+    #[test]
+    fn map_round_trips_and_merges() {
+        let mut a = CodeDataLogger::new(3);
+        a.mark(0, OPCODE);
+        let mut map = Vec::new();
+        a.write_map(&mut map).unwrap();
+        assert_eq!(map, vec![OPCODE, 0, 0]);
+
+        let mut b = CodeDataLogger::read_map(&map[..]).unwrap();
+        let mut c = CodeDataLogger::new(3);
+        c.mark(0, DATA);
+        c.mark(2, OPERAND);
+        b.merge(&c).unwrap();
+        assert_eq!(b.flags, vec![OPCODE | DATA, 0, OPERAND]);
+
+        assert!(b.merge(&CodeDataLogger::new(4)).is_err());
+        assert!(CodeDataLogger::read_map(&[0x80][..]).is_err());
+    }
+}
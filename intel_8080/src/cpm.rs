@@ -0,0 +1,511 @@
+//! A CP/M 2.2 machine.
+//!
+//! The machine has 64K of RAM and no port hardware. CP/M itself runs on the
+//! host: the BDOS entry point and the BIOS jump table lead to RET
+//! instructions, and when the CPU reaches one of them the call is carried out
+//! in Rust before the RET returns to the program. Programs see a standard
+//! 64K system with the BDOS at 0xec00 and the BIOS at 0xfa00, and anything
+//! that follows the jumps at 0x0000 and 0x0005 works as it would on real
+//! hardware.
+//!
+//! The CCP's part is played by `Cpm::load_com`, which loads a .COM file at
+//! 0x0100 and sets up its command tail and default FCBs.
+//!
+//! Drives A: to P: can be backed by host directories with `Cpm::mount`, which
+//! the BDOS file functions work on directly. Disk images put in a drive with
+//! `Cpm::insert_disk` are reached through the BIOS disk entries instead, by
+//! programs that read and write sectors themselves.
+
+mod bdos;
+mod bios;
+pub mod disk;
+mod drives;
+
+pub use self::disk::{DiskImage, Geometry};
+pub use crate::io::console::{BufferConsole, Console, StdConsole};
+
+use crate::{
+    i8080::Registers,
+    interconnect::Interconnect,
+    io::IO,
+    loader,
+    mmu::{Mmu, Ram},
+    Emulator,
+};
+
+use failure::{bail, Error};
+use std::{
+    collections::VecDeque,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Start of the transient program area, where .COM files are loaded.
+pub const TPA: u16 = 0x0100;
+pub const BDOS_BASE: u16 = 0xec00;
+/// Address programs call for BDOS functions, through the jump at 0x0005.
+pub const BDOS_ENTRY: u16 = BDOS_BASE + 6;
+pub const BIOS_BASE: u16 = 0xfa00;
+/// Number of entries in the CP/M 2.2 BIOS jump table.
+const BIOS_FUNCTIONS: u16 = 17;
+/// Each BIOS jump table entry leads to its own RET here.
+const BIOS_TRAPS: u16 = BIOS_BASE + 0x80;
+
+const WARM_BOOT: u16 = 0x0000;
+const IOBYTE: u16 = 0x0003;
+/// Current drive in the low nibble and user number in the high nibble.
+const DRIVE_USER: u16 = 0x0004;
+const BDOS_JUMP: u16 = 0x0005;
+const FCB1: u16 = 0x005c;
+const FCB2: u16 = 0x006c;
+/// Default DMA buffer, which also holds the command tail.
+const DEFAULT_DMA: u16 = 0x0080;
+
+/// CP/M reads and writes disks and files in records of this many bytes.
+const RECORD_LEN: usize = 128;
+/// Records in each 16K logical extent.
+const EXTENT_RECORDS: usize = 128;
+/// Length of a directory entry, which is an FCB up to the allocation map.
+const ENTRY_LEN: usize = 32;
+/// End of text: pads a text file's last record, and is what the reader
+/// returns when there is no paper tape.
+const EOF: u8 = 0x1a;
+
+const JMP: u8 = 0xc3;
+const RET: u8 = 0xc9;
+
+/// Why the machine stopped.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Exit {
+    /// The program returned to CP/M by a warm boot or BDOS function 0.
+    WarmBoot,
+    /// The program waited for console input after the input ended.
+    EndOfInput,
+    /// The CPU executed a HLT. Nothing on the machine raises interrupts to
+    /// wake it.
+    Halted,
+}
+
+/// Port hardware of the CP/M machine: there is none, since the BIOS runs on
+/// the host. Reads return 0xff.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct NoPorts;
+
+impl IO for NoPorts {
+    fn read_port(&self, _port: u8) -> u8 {
+        0xff
+    }
+
+    fn write_port(&mut self, _port: u8, _value: u8) {}
+}
+
+pub struct Cpm<C: Console> {
+    emulator: Emulator<Ram, NoPorts>,
+    console: C,
+    dma: u16,
+    drives: [Option<PathBuf>; drives::DRIVES],
+    disks: [Option<DiskImage>; drives::DRIVES],
+    /// Drive, track and record set through the BIOS.
+    disk: usize,
+    track: u16,
+    record: u16,
+    /// Directory entries left for BDOS search next.
+    search: VecDeque<[u8; 32]>,
+}
+
+impl<C: Console> Cpm<C> {
+    pub fn new(console: C) -> Cpm<C> {
+        let interconnect = Interconnect::from_parts(Ram::new(), NoPorts);
+        let mut cpm = Cpm {
+            emulator: Emulator::with_interconnect(interconnect),
+            console,
+            dma: DEFAULT_DMA,
+            drives: Default::default(),
+            disks: Default::default(),
+            disk: 0,
+            track: 0,
+            record: 0,
+            search: VecDeque::new(),
+        };
+        cpm.write_jump(WARM_BOOT, BIOS_BASE + 3);
+        cpm.write_jump(BDOS_JUMP, BDOS_ENTRY);
+        cpm.write(BDOS_ENTRY, RET);
+        for i in 0..BIOS_FUNCTIONS {
+            cpm.write_jump(BIOS_BASE + i * 3, BIOS_TRAPS + i);
+            cpm.write(BIOS_TRAPS + i, RET);
+        }
+        cpm
+    }
+
+    pub fn emulator(&self) -> &Emulator<Ram, NoPorts> {
+        &self.emulator
+    }
+
+    pub fn emulator_mut(&mut self) -> &mut Emulator<Ram, NoPorts> {
+        &mut self.emulator
+    }
+
+    pub fn console(&self) -> &C {
+        &self.console
+    }
+
+    pub fn console_mut(&mut self) -> &mut C {
+        &mut self.console
+    }
+
+    /// Backs `drive`, a letter from A to P, with the host directory `dir`.
+    ///
+    /// #Errors
+    /// Fails if the drive letter is out of range or `dir` is not a
+    /// directory.
+    pub fn mount<P: Into<PathBuf>>(&mut self, drive: char, dir: P) -> Result<(), Error> {
+        let index = drive_index(drive)?;
+        let dir = dir.into();
+        if !dir.is_dir() {
+            bail!("{} is not a directory", dir.display());
+        }
+        self.drives[index] = Some(dir);
+        Ok(())
+    }
+
+    /// Puts a disk image in `drive`, a letter from A to P, for the BIOS disk
+    /// entries.
+    ///
+    /// #Errors
+    /// Fails if the drive letter is out of range.
+    pub fn insert_disk(&mut self, drive: char, image: DiskImage) -> Result<(), Error> {
+        self.disks[drive_index(drive)?] = Some(image);
+        Ok(())
+    }
+
+    /// Takes the disk image out of `drive`, with whatever the program wrote
+    /// to it.
+    pub fn eject_disk(&mut self, drive: char) -> Option<DiskImage> {
+        drive_index(drive).ok().and_then(|i| self.disks[i].take())
+    }
+
+    pub fn disk(&self, drive: char) -> Option<&DiskImage> {
+        drive_index(drive).ok().and_then(|i| self.disks[i].as_ref())
+    }
+
+    /// Loads a .COM program at 0x0100 and prepares to run it as the CCP
+    /// would for the command line `NAME tail`: the upper cased tail goes in
+    /// the buffer at 0x0080, the first two arguments are parsed into the FCBs
+    /// at 0x005c and 0x006c, and returning from the program warm boots.
+    ///
+    /// #Errors
+    /// Fails if the program does not fit below the BDOS.
+    pub fn load_com(&mut self, program: &[u8], tail: &str) -> Result<(), Error> {
+        if TPA as usize + program.len() > BDOS_BASE as usize {
+            bail!(
+                "program of {} bytes does not fit in the {} byte TPA",
+                program.len(),
+                BDOS_BASE - TPA
+            );
+        }
+        loader::load_binary(&mut self.emulator.interconnect.mmu, program, TPA, TPA)?;
+
+        let tail = tail.trim().to_ascii_uppercase();
+        let tail = match tail.is_empty() {
+            true => String::new(),
+            false => format!(" {}", tail),
+        };
+        // The tail and its 0 terminator must fit between 0x0081 and the
+        // program at 0x0100.
+        let tail = &tail.as_bytes()[..tail.len().min(0x7e)];
+        for addr in FCB1..DEFAULT_DMA {
+            self.write(addr, 0);
+        }
+        let mut args = std::str::from_utf8(tail).unwrap_or("").split_whitespace();
+        self.write_fcb(FCB1, args.next().unwrap_or(""));
+        self.write_fcb(FCB2, args.next().unwrap_or(""));
+        self.write(DEFAULT_DMA, tail.len() as u8);
+        for (i, &b) in tail.iter().enumerate() {
+            self.write(DEFAULT_DMA + 1 + i as u16, b);
+        }
+        self.write(DEFAULT_DMA + 1 + tail.len() as u16, 0);
+        self.dma = DEFAULT_DMA;
+
+        // The CCP's stack sits just below the BDOS, holding the return
+        // address of the program.
+        let sp = BDOS_BASE - 2;
+        self.write(sp, 0x00);
+        self.write(sp + 1, 0x00);
+        let registers = Registers {
+            sp,
+            pc: TPA,
+            ..self.registers()
+        };
+        self.set_registers(registers);
+        Ok(())
+    }
+
+    pub fn load_com_file<P: AsRef<Path>>(&mut self, path: P, tail: &str) -> Result<(), Error> {
+        let path = path.as_ref();
+        match fs::read(path) {
+            Ok(program) => self.load_com(&program, tail),
+            Err(e) => bail!("unable to read {}: {}", path.display(), e),
+        }
+    }
+
+    /// Executes one instruction, first carrying out the BDOS or BIOS call if
+    /// the CPU has reached one. Returns why the machine stopped, if it did.
+    pub fn step(&mut self) -> Result<Option<Exit>, Error> {
+        let pc = self.emulator.cpu().pc();
+        let exit = if pc == BDOS_ENTRY {
+            self.bdos()
+        } else if (BIOS_TRAPS..BIOS_TRAPS + BIOS_FUNCTIONS).contains(&pc) {
+            self.bios(pc - BIOS_TRAPS)
+        } else {
+            None
+        };
+        if exit.is_some() {
+            return Ok(exit);
+        }
+        // Nothing raises interrupts, so a HLT is final.
+        if self.emulator.cpu().halted() {
+            return Ok(Some(Exit::Halted));
+        }
+        self.emulator.try_step()?;
+        Ok(None)
+    }
+
+    /// Runs until the program exits.
+    pub fn run(&mut self) -> Result<Exit, Error> {
+        loop {
+            if let Some(exit) = self.step()? {
+                return Ok(exit);
+            }
+        }
+    }
+
+    fn registers(&self) -> Registers {
+        self.emulator.cpu().registers()
+    }
+
+    fn set_registers(&mut self, registers: Registers) {
+        self.emulator.cpu_mut().set_registers(registers)
+    }
+
+    fn read(&self, addr: u16) -> u8 {
+        self.emulator.interconnect.mmu.read_byte(addr)
+    }
+
+    fn write(&mut self, addr: u16, value: u8) {
+        self.emulator.interconnect.mmu.write_byte(addr, value)
+    }
+
+    fn write_jump(&mut self, addr: u16, target: u16) {
+        self.write(addr, JMP);
+        self.write(addr + 1, target as u8);
+        self.write(addr + 2, (target >> 8) as u8);
+    }
+
+    /// Fills in the drive and name of the FCB at `addr` from a file name such
+    /// as `B:FOO.ASM`, leaving the rest of the FCB alone.
+    fn write_fcb(&mut self, addr: u16, name: &str) {
+        let (drive, name) = fcb_name(name);
+        self.write(addr, drive);
+        for (i, &b) in name.iter().enumerate() {
+            self.write(addr + 1 + i as u16, b);
+        }
+    }
+}
+
+fn drive_index(drive: char) -> Result<usize, Error> {
+    match drive.to_ascii_uppercase() {
+        letter @ 'A'..='P' => Ok(letter as usize - 'A' as usize),
+        _ => bail!("drive {}: is not one of A: to P:", drive),
+    }
+}
+
+/// Splits a file name into its FCB drive code (0 for the current drive, 1
+/// for A:) and its blank padded 8.3 name, upper cased, with `*` wildcards
+/// expanded to `?`.
+pub(crate) fn fcb_name(name: &str) -> (u8, [u8; 11]) {
+    let bytes = name.as_bytes();
+    let (drive, name) = match bytes {
+        [d, b':', ..] if d.is_ascii_alphabetic() => (d.to_ascii_uppercase() - b'A' + 1, &name[2..]),
+        _ => (0, name),
+    };
+    let mut fcb = [b' '; 11];
+    let (base, ext) = match name.find('.') {
+        Some(i) => (&name[..i], &name[i + 1..]),
+        None => (name, ""),
+    };
+    for (field, part) in [(0..8, base), (8..11, ext)] {
+        let mut chars = part.bytes();
+        for i in field.clone() {
+            match chars.next() {
+                Some(b'*') => {
+                    for b in &mut fcb[i..field.end] {
+                        *b = b'?';
+                    }
+                    break;
+                }
+                Some(c) => fcb[i] = c.to_ascii_uppercase(),
+                None => break,
+            }
+        }
+    }
+    (drive, fcb)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        disk::IBM_3740, fcb_name, BufferConsole, Cpm, DiskImage, Exit, DEFAULT_DMA, FCB1, FCB2,
+    };
+    use crate::mmu::Mmu;
+    use std::fs;
+
+    #[test]
+    fn parses_fcb_names() {
+        assert_eq!(fcb_name("b:foo.asm"), (2, *b"FOO     ASM"));
+        assert_eq!(fcb_name("*.C*"), (0, *b"????????C??"));
+        assert_eq!(fcb_name("LONGFILENAME.TEXT"), (0, *b"LONGFILETEX"));
+        assert_eq!(fcb_name(""), (0, *b"           "));
+    }
+
+    #[test]
+    fn runs_com_files() {
+        let mut program = vec![
+            0x0e, 0x09, //       MVI C, 9
+            0x11, 0x0c, 0x01, // LXI D, 0x010c
+            0xcd, 0x05, 0x00, // CALL 5
+            0x3a, 0x5d, 0x00, // LDA 0x005d
+            0xc9, //             RET
+        ];
+        program.extend_from_slice(b"Hi!\r\n$");
+        let mut cpm = Cpm::new(BufferConsole::new(""));
+        cpm.load_com(&program, "b:one.txt  two").unwrap();
+
+        let mmu = &cpm.emulator().interconnect.mmu;
+        assert_eq!(mmu.read_byte(0x0080), 15);
+        assert_eq!(mmu.read_byte(0x0081), b' ');
+        assert_eq!(mmu.read_byte(0x005c), 2);
+        assert_eq!(mmu.read_byte(0x006d), b'T');
+
+        assert_eq!(cpm.run().unwrap(), Exit::WarmBoot);
+        assert_eq!(cpm.console().text(), "Hi!\n");
+        assert_eq!(cpm.emulator().cpu().registers().a, b'O');
+    }
+
+    #[test]
+    fn truncates_long_command_tails() {
+        let mut cpm = Cpm::new(BufferConsole::new(""));
+        cpm.load_com(&[0xc9], &"x".repeat(200)).unwrap();
+
+        let mmu = &cpm.emulator().interconnect.mmu;
+        assert_eq!(mmu.read_byte(0x0080), 0x7e);
+        assert_eq!(mmu.read_byte(0x00fe), b'X');
+        assert_eq!(mmu.read_byte(0x00ff), 0x00);
+        assert_eq!(mmu.read_byte(0x0100), 0xc9);
+    }
+
+    /// Calls BDOS function `c` with `de` and returns A.
+    fn bdos(cpm: &mut Cpm<BufferConsole>, c: u8, de: u16) -> u8 {
+        let mut registers = cpm.registers();
+        registers.c = c;
+        registers.d = (de >> 8) as u8;
+        registers.e = de as u8;
+        cpm.set_registers(registers);
+        assert_eq!(cpm.bdos(), None);
+        cpm.registers().a
+    }
+
+    #[test]
+    fn works_on_host_files() {
+        let dir = std::env::temp_dir().join(format!("i8080_cpm_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("hello.txt"), vec![b'h'; 200]).unwrap();
+        fs::write(dir.join("not a cpm name.txt"), b"").unwrap();
+
+        let mut cpm = Cpm::new(BufferConsole::new(""));
+        cpm.mount('a', &dir).unwrap();
+        assert!(cpm.mount('Q', &dir).is_err());
+        assert_eq!(bdos(&mut cpm, 14, 1), 0xff);
+
+        cpm.write_fcb(FCB1, "*.*");
+        assert_eq!(bdos(&mut cpm, 17, FCB1), 0);
+        assert_eq!(cpm.read(DEFAULT_DMA + 1), b'H');
+        assert_eq!(cpm.read(DEFAULT_DMA + 15), 2);
+        assert_eq!(bdos(&mut cpm, 18, FCB1), 0xff);
+
+        cpm.write_fcb(FCB1, "Hello.Txt");
+        assert_eq!(bdos(&mut cpm, 15, FCB1), 0);
+        assert_eq!(bdos(&mut cpm, 20, FCB1), 0);
+        assert_eq!(bdos(&mut cpm, 20, FCB1), 0);
+        assert_eq!(cpm.read(DEFAULT_DMA + 71), b'h');
+        assert_eq!(cpm.read(DEFAULT_DMA + 72), 0x1a);
+        assert_eq!(bdos(&mut cpm, 20, FCB1), 1);
+
+        cpm.write_fcb(FCB1, "new.dat");
+        assert_eq!(bdos(&mut cpm, 22, FCB1), 0);
+        assert_eq!(bdos(&mut cpm, 21, FCB1), 0);
+        cpm.write(FCB1 + 33, 5);
+        assert_eq!(bdos(&mut cpm, 34, FCB1), 0);
+        assert_eq!(bdos(&mut cpm, 35, FCB1), 0);
+        assert_eq!(cpm.read(FCB1 + 33), 6);
+        assert_eq!(fs::metadata(dir.join("NEW.DAT")).unwrap().len(), 6 * 128);
+
+        cpm.write_fcb(FCB2, "old.dat");
+        assert_eq!(bdos(&mut cpm, 23, FCB1), 0);
+        assert!(dir.join("OLD.DAT").is_file());
+        cpm.write_fcb(FCB1, "hello.txt");
+        cpm.write_fcb(FCB2, "Old.Dat");
+        assert_eq!(bdos(&mut cpm, 23, FCB1), 0xff);
+        assert!(dir.join("hello.txt").is_file());
+        assert_eq!(fs::metadata(dir.join("OLD.DAT")).unwrap().len(), 6 * 128);
+        cpm.write_fcb(FCB1, "*.DAT");
+        assert_eq!(bdos(&mut cpm, 19, FCB1), 0);
+        assert_eq!(bdos(&mut cpm, 15, FCB1), 0xff);
+
+        assert_eq!(bdos(&mut cpm, 32, 3), 0);
+        cpm.write_fcb(FCB1, "user.txt");
+        assert_eq!(bdos(&mut cpm, 22, FCB1), 0);
+        assert!(dir.join("3").join("USER.TXT").is_file());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn reads_disk_images_through_the_bios() {
+        let mut disk = DiskImage::new(IBM_3740);
+        disk.insert(0, "test.txt", b"data").unwrap();
+        let mut cpm = Cpm::new(BufferConsole::new(""));
+        cpm.insert_disk('B', disk).unwrap();
+
+        let bios = |cpm: &mut Cpm<BufferConsole>, function, bc: u16| {
+            let mut registers = cpm.registers();
+            registers.b = (bc >> 8) as u8;
+            registers.c = bc as u8;
+            cpm.set_registers(registers);
+            assert_eq!(cpm.bios(function), None);
+            cpm.registers()
+        };
+        assert_eq!(bios(&mut cpm, 9, 0).l, 0);
+        let dph = bios(&mut cpm, 9, 1);
+        let dph = u16::from(dph.h) << 8 | u16::from(dph.l);
+        let dpb = u16::from(cpm.read(dph + 11)) << 8 | u16::from(cpm.read(dph + 10));
+        assert_eq!(cpm.read(dpb), 26);
+        assert_eq!(cpm.read(dpb + 5), 242);
+
+        // The directory entry is at the start of the first data track, and
+        // the file's data in the first block after the directory.
+        bios(&mut cpm, 10, 2);
+        bios(&mut cpm, 11, 0);
+        assert_eq!(bios(&mut cpm, 13, 0).a, 0);
+        assert_eq!(cpm.read(DEFAULT_DMA + 1), b'T');
+        bios(&mut cpm, 11, 16);
+        assert_eq!(bios(&mut cpm, 13, 0).a, 0);
+        assert_eq!(cpm.read(DEFAULT_DMA), b'd');
+
+        cpm.write(DEFAULT_DMA, b'D');
+        assert_eq!(bios(&mut cpm, 14, 0).a, 0);
+        bios(&mut cpm, 11, 26);
+        assert_eq!(bios(&mut cpm, 13, 0).a, 1);
+        let disk = cpm.eject_disk('B').unwrap();
+        assert_eq!(&disk.extract(0, "TEST.TXT").unwrap()[..4], b"Data");
+    }
+}
@@ -0,0 +1,187 @@
+use super::{bios, Console, Cpm, Exit, DEFAULT_DMA, DRIVE_USER, EOF, IOBYTE};
+
+use log::debug;
+
+const CR: u8 = 0x0d;
+const LF: u8 = 0x0a;
+const BACKSPACE: u8 = 0x08;
+const DELETE: u8 = 0x7f;
+const CTRL_C: u8 = 0x03;
+/// CP/M version 2.2.
+const VERSION: u16 = 0x0022;
+/// Result of calls that failed, such as opening a missing file.
+const ERROR: u8 = 0xff;
+
+impl<C: Console> Cpm<C> {
+    /// Carries out the BDOS function in C with the parameter in E or DE.
+    /// Results go in A and L for bytes, and in HL with a copy in BA for
+    /// words, as the real BDOS leaves them.
+    pub(super) fn bdos(&mut self) -> Option<Exit> {
+        match self.bdos_call() {
+            Ok(result) => {
+                let mut registers = self.registers();
+                registers.l = result as u8;
+                registers.h = (result >> 8) as u8;
+                registers.a = registers.l;
+                registers.b = registers.h;
+                self.set_registers(registers);
+                None
+            }
+            Err(exit) => Some(exit),
+        }
+    }
+
+    fn bdos_call(&mut self) -> Result<u16, Exit> {
+        let registers = self.registers();
+        let e = registers.e;
+        let de = u16::from(registers.d) << 8 | u16::from(e);
+        let result: u16 = match registers.c {
+            0 => return Err(Exit::WarmBoot),
+            // Console input
+            1 => {
+                let c = self.key()?;
+                self.echo(c);
+                c.into()
+            }
+            // Console output
+            2 => {
+                self.console.write(e);
+                0
+            }
+            // Reader input
+            3 => EOF.into(),
+            // Punch and list output have nowhere to go.
+            4 | 5 => 0,
+            // Direct console I/O
+            6 => match e {
+                0xff if self.console.status() => self.key()?.into(),
+                0xff => 0,
+                0xfe => self.console_status(),
+                0xfd => self.key()?.into(),
+                _ => {
+                    self.console.write(e);
+                    0
+                }
+            },
+            7 => self.read(IOBYTE).into(),
+            8 => {
+                self.write(IOBYTE, e);
+                0
+            }
+            // Print string
+            9 => {
+                let mut addr = de;
+                loop {
+                    let c = self.read(addr);
+                    if c == b'$' {
+                        break;
+                    }
+                    self.console.write(c);
+                    addr = addr.wrapping_add(1);
+                }
+                0
+            }
+            10 => self.read_buffer(de)?,
+            11 => self.console_status(),
+            12 => VERSION,
+            // Reset disk system
+            13 => {
+                self.dma = DEFAULT_DMA;
+                self.search.clear();
+                let user = self.read(DRIVE_USER) & 0xf0;
+                self.write(DRIVE_USER, user);
+                0
+            }
+            // Select disk, which fails for drives with no directory behind
+            // them as it does in CP/M 3.
+            14 => {
+                if self.mounted() & 1 << (e & 0x0f) == 0 {
+                    return Ok(ERROR.into());
+                }
+                let user = self.read(DRIVE_USER) & 0xf0;
+                self.write(DRIVE_USER, user | (e & 0x0f));
+                0
+            }
+            15..=23 | 30 | 33..=36 | 40 => self.file_call(registers.c, de),
+            24 => self.mounted(),
+            25 => (self.read(DRIVE_USER) & 0x0f).into(),
+            26 => {
+                self.dma = de;
+                0
+            }
+            // Get or set user code
+            32 => {
+                let drive_user = self.read(DRIVE_USER);
+                if e == 0xff {
+                    (drive_user >> 4).into()
+                } else {
+                    self.write(DRIVE_USER, (e & 0x0f) << 4 | drive_user & 0x0f);
+                    0
+                }
+            }
+            // Write protect disk and reset drive succeed as there is nothing
+            // to protect or reset.
+            28 | 37 => 0,
+            // Read-only vector
+            29 => 0,
+            // Get disk parameters, which only disk images have.
+            31 => match self.select_disk(usize::from(self.read(DRIVE_USER) & 0x0f)) {
+                0 => ERROR.into(),
+                _ => bios::DPB,
+            },
+            function => {
+                debug!("Unsupported BDOS function {}", function);
+                ERROR.into()
+            }
+        };
+        Ok(result)
+    }
+
+    /// Waits for a key, stopping the machine if input has ended.
+    pub(super) fn key(&mut self) -> Result<u8, Exit> {
+        self.console.read().ok_or(Exit::EndOfInput)
+    }
+
+    fn console_status(&mut self) -> u16 {
+        match self.console.status() {
+            true => 0xff,
+            false => 0x00,
+        }
+    }
+
+    fn echo(&mut self, c: u8) {
+        if c >= b' ' || c == CR || c == LF || c == b'\t' || c == BACKSPACE {
+            self.console.write(c);
+        }
+    }
+
+    /// BDOS function 10: reads an edited line into the buffer at `addr`,
+    /// which holds its size in the first byte and gets the line's length in
+    /// the second. Control-C on an empty line warm boots.
+    fn read_buffer(&mut self, addr: u16) -> Result<u16, Exit> {
+        let size = self.read(addr);
+        let mut len = 0u8;
+        while len < size {
+            match self.key()? {
+                CR | LF => break,
+                CTRL_C if len == 0 => return Err(Exit::WarmBoot),
+                BACKSPACE | DELETE => {
+                    if len > 0 {
+                        len -= 1;
+                        for &c in b"\x08 \x08" {
+                            self.console.write(c);
+                        }
+                    }
+                }
+                c => {
+                    self.write(addr.wrapping_add(2 + u16::from(len)), c);
+                    len += 1;
+                    self.echo(c);
+                }
+            }
+        }
+        self.console.write(CR);
+        self.write(addr.wrapping_add(1), len);
+        Ok(0)
+    }
+}
@@ -0,0 +1,120 @@
+use super::{Console, Cpm, Exit, BIOS_BASE, EOF, RECORD_LEN};
+
+// Disk parameter header of the selected drive and the tables it points at.
+// There is one set shared by all drives, refilled by SELDSK. The checksum
+// and allocation vectors are only there for programs that look: the host
+// BDOS keeps no allocation state.
+pub(super) const DPH: u16 = BIOS_BASE + 0x100;
+pub(super) const DPB: u16 = DPH + 0x10;
+const DIRBUF: u16 = DPB + 0x10;
+const CSV: u16 = DIRBUF + 0x80;
+const ALV: u16 = CSV + 0x100;
+
+impl<C: Console> Cpm<C> {
+    /// Carries out the BIOS jump table entry numbered `function`, with its
+    /// parameter in C or BC and its result in A or HL.
+    ///
+    /// The disk entries work on the disk images put in the drives. SELDSK
+    /// reports drives without one as missing, and sectors are numbered from
+    /// 0 in logical order, with the skew applied by READ and WRITE.
+    pub(super) fn bios(&mut self, function: u16) -> Option<Exit> {
+        let mut registers = self.registers();
+        let bc = u16::from(registers.b) << 8 | u16::from(registers.c);
+        match function {
+            // BOOT and WBOOT
+            0 | 1 => return Some(Exit::WarmBoot),
+            // CONST
+            2 => {
+                registers.a = match self.console.status() {
+                    true => 0xff,
+                    false => 0x00,
+                }
+            }
+            // CONIN
+            3 => match self.key() {
+                Ok(c) => registers.a = c,
+                Err(exit) => return Some(exit),
+            },
+            // CONOUT
+            4 => self.console.write(registers.c),
+            // LIST and PUNCH
+            5 | 6 => {}
+            // READER
+            7 => registers.a = EOF,
+            8 => self.track = 0,
+            // SELDSK
+            9 => {
+                let dph = self.select_disk(usize::from(registers.c));
+                registers.h = (dph >> 8) as u8;
+                registers.l = dph as u8;
+            }
+            10 => self.track = bc,
+            11 => self.record = bc,
+            // SETDMA
+            12 => self.dma = bc,
+            13 => registers.a = self.read_sector(),
+            14 => registers.a = self.write_sector(),
+            // LISTST: the printer is always ready to drop characters.
+            15 => registers.a = 0xff,
+            // SECTRAN: READ and WRITE apply the skew themselves.
+            16 => {
+                registers.h = registers.b;
+                registers.l = registers.c;
+            }
+            _ => unreachable!("BIOS function {} out of range", function),
+        }
+        self.set_registers(registers);
+        None
+    }
+
+    /// Selects `drive` for the disk entries and fills in its DPH and DPB.
+    /// Returns the address of the DPH, or 0 if the drive has no disk.
+    pub(super) fn select_disk(&mut self, drive: usize) -> u16 {
+        let dpb = match self.disks.get(drive).and_then(Option::as_ref) {
+            Some(disk) => disk.geometry().dpb(),
+            None => return 0,
+        };
+        self.disk = drive;
+        // No translation table, then the BDOS scratch words.
+        let words = [0, 0, 0, 0, DIRBUF, DPB, CSV, ALV];
+        for (i, &word) in words.iter().enumerate() {
+            let addr = DPH + 2 * i as u16;
+            self.write(addr, word as u8);
+            self.write(addr + 1, (word >> 8) as u8);
+        }
+        for (i, &b) in dpb.to_bytes().iter().enumerate() {
+            self.write(DPB + i as u16, b);
+        }
+        DPH
+    }
+
+    fn read_sector(&mut self) -> u8 {
+        let record = self.disks[self.disk]
+            .as_ref()
+            .and_then(|disk| disk.read_record(self.track, self.record))
+            .map(<[u8]>::to_vec);
+        match record {
+            Some(record) => {
+                for (i, &b) in record.iter().enumerate() {
+                    self.write(self.dma.wrapping_add(i as u16), b);
+                }
+                0
+            }
+            None => 1,
+        }
+    }
+
+    fn write_sector(&mut self) -> u8 {
+        let record: Vec<u8> = (0..RECORD_LEN as u16)
+            .map(|i| self.read(self.dma.wrapping_add(i)))
+            .collect();
+        let (track, sector) = (self.track, self.record);
+        match &mut self.disks[self.disk] {
+            Some(disk) => match disk.write_record(track, sector, &record) {
+                true => 0,
+                false => 1,
+            },
+            None => 1,
+        }
+    }
+}
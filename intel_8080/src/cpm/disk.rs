@@ -0,0 +1,705 @@
+//! CP/M disk images.
+//!
+//! An image is a raw dump of a disk's tracks in order, each track holding its
+//! physical sectors in order. Its layout is described by a `Geometry`, from
+//! which the disk parameter block (DPB) that CP/M uses to find its way around
+//! the disk is worked out.
+//!
+//! CP/M addresses a disk in 128 byte records. The BIOS side of an image takes
+//! a track and a logical record number on that track and applies the sector
+//! skew itself, so the translation table in the disk parameter header is not
+//! needed. The same translation is used by `list`, `extract` and `insert`,
+//! which work on the CP/M file system in the image from Rust.
+
+use super::{ENTRY_LEN, EOF, EXTENT_RECORDS, RECORD_LEN};
+
+use failure::{bail, format_err, Error};
+use std::{fmt, fs, path::Path, str::FromStr};
+
+/// User byte of an unused directory entry, and what formatted disks hold.
+const EMPTY: u8 = 0xe5;
+
+/// Physical layout of a disk and how CP/M divides it up.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Geometry {
+    pub tracks: u16,
+    /// Physical sectors per track.
+    pub sectors: u16,
+    /// Bytes per physical sector, a multiple of 128.
+    pub sector_len: usize,
+    /// Physical sectors between consecutive logical sectors, or 1 for none.
+    pub skew: u16,
+    /// Tracks set aside for the system before the directory.
+    pub reserved_tracks: u16,
+    /// Allocation block size: 1024, 2048, 4096, 8192 or 16384 bytes.
+    pub block_size: usize,
+    pub dir_entries: u16,
+}
+
+/// The IBM 3740 single sided, single density 8" format that CP/M was
+/// distributed on: 77 tracks of 26 sectors of 128 bytes, skewed by 6.
+pub const IBM_3740: Geometry = Geometry {
+    tracks: 77,
+    sectors: 26,
+    sector_len: 128,
+    skew: 6,
+    reserved_tracks: 2,
+    block_size: 1024,
+    dir_entries: 64,
+};
+
+impl Geometry {
+    /// Records per track.
+    pub fn records(&self) -> usize {
+        usize::from(self.sectors) * self.sector_len / RECORD_LEN
+    }
+
+    pub fn track_len(&self) -> usize {
+        usize::from(self.sectors) * self.sector_len
+    }
+
+    pub fn image_len(&self) -> usize {
+        usize::from(self.tracks) * self.track_len()
+    }
+
+    /// Number of allocation blocks after the reserved tracks.
+    pub fn blocks(&self) -> usize {
+        usize::from(self.tracks - self.reserved_tracks) * self.track_len() / self.block_size
+    }
+
+    fn dir_blocks(&self) -> usize {
+        (usize::from(self.dir_entries) * ENTRY_LEN).div_ceil(self.block_size)
+    }
+
+    /// Whether block numbers in directory entries take two bytes.
+    fn wide_blocks(&self) -> bool {
+        self.blocks() > 256
+    }
+
+    fn blocks_per_entry(&self) -> usize {
+        match self.wide_blocks() {
+            true => 8,
+            false => 16,
+        }
+    }
+
+    /// The DPB CP/M needs for a disk of this shape.
+    pub fn dpb(&self) -> Dpb {
+        let block_records = self.block_size / RECORD_LEN;
+        let dir_mask = !0xffffu16.checked_shr(self.dir_blocks() as u32).unwrap_or(0);
+        Dpb {
+            spt: self.records() as u16,
+            bsh: block_records.trailing_zeros() as u8,
+            blm: (block_records - 1) as u8,
+            exm: (self.blocks_per_entry() * self.block_size / (EXTENT_RECORDS * RECORD_LEN) - 1)
+                as u8,
+            dsm: (self.blocks() - 1) as u16,
+            drm: self.dir_entries - 1,
+            al0: (dir_mask >> 8) as u8,
+            al1: dir_mask as u8,
+            cks: self.dir_entries / 4,
+            off: self.reserved_tracks,
+        }
+    }
+
+    /// Byte offset in the image of `record` on `track`, or `None` if there is
+    /// no such record.
+    pub fn offset(&self, track: u16, record: u16) -> Option<usize> {
+        let records_per_sector = self.sector_len / RECORD_LEN;
+        let record = usize::from(record);
+        if track >= self.tracks || record >= self.records() {
+            return None;
+        }
+        let sector = self.physical_sector(record / records_per_sector);
+        let record = sector * records_per_sector + record % records_per_sector;
+        Some(usize::from(track) * self.track_len() + record * RECORD_LEN)
+    }
+
+    /// Physical sector holding logical sector `sector`. Each step of the skew
+    /// moves on `skew` sectors; when that comes back round to a sector
+    /// already used, the next free one is taken, as in Digital Research's
+    /// skew tables.
+    fn physical_sector(&self, sector: usize) -> usize {
+        let sectors = usize::from(self.sectors);
+        let skew = usize::from(self.skew.max(1));
+        let mut used = vec![false; sectors];
+        let mut physical = 0;
+        for i in 0..=sector {
+            if i > 0 {
+                physical = (physical + skew) % sectors;
+            }
+            while used[physical] {
+                physical = (physical + 1) % sectors;
+            }
+            used[physical] = true;
+        }
+        physical
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        if self.tracks == 0 || self.sectors == 0 {
+            bail!("a disk needs at least one track and one sector");
+        }
+        if self.sector_len == 0 || !self.sector_len.is_multiple_of(RECORD_LEN) {
+            bail!("sector length {} is not a multiple of 128", self.sector_len);
+        }
+        if ![1024, 2048, 4096, 8192, 16384].contains(&self.block_size) {
+            bail!("block size {} is not one CP/M supports", self.block_size);
+        }
+        if self.reserved_tracks >= self.tracks {
+            bail!("all {} tracks are reserved", self.tracks);
+        }
+        let blocks = self.blocks();
+        if blocks <= self.dir_blocks() || blocks > 0x10000 {
+            bail!("{} blocks is not a usable disk", blocks);
+        }
+        if self.block_size == 1024 && self.wide_blocks() {
+            bail!("1024 byte blocks cannot address {} blocks", blocks);
+        }
+        if self.dir_entries == 0 || self.dir_blocks() > 16 {
+            bail!("{} directory entries do not fit", self.dir_entries);
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Geometry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "tracks={},sectors={},sector-len={},skew={},reserved={},block={},dir={}",
+            self.tracks,
+            self.sectors,
+            self.sector_len,
+            self.skew,
+            self.reserved_tracks,
+            self.block_size,
+            self.dir_entries
+        )
+    }
+}
+
+/// Parses a descriptor such as
+/// `tracks=77,sectors=26,sector-len=128,skew=6,reserved=2,block=1024,dir=64`,
+/// as printed by `Display`, or the name `ibm-3740`. Fields left out are
+/// taken from the IBM 3740 format.
+impl FromStr for Geometry {
+    type Err = Error;
+
+    fn from_str(descriptor: &str) -> Result<Geometry, Error> {
+        let mut geometry = IBM_3740;
+        if descriptor.eq_ignore_ascii_case("ibm-3740") {
+            return Ok(geometry);
+        }
+        for field in descriptor.split(',').map(str::trim) {
+            let (key, value) = match field.find('=') {
+                Some(i) => (&field[..i], &field[i + 1..]),
+                None => bail!("expected key=value, found {:?}", field),
+            };
+            let number = || {
+                value
+                    .parse::<u16>()
+                    .map_err(|_| format_err!("{} must be a number, not {:?}", key, value))
+            };
+            match key {
+                "tracks" => geometry.tracks = number()?,
+                "sectors" => geometry.sectors = number()?,
+                "sector-len" => geometry.sector_len = number()?.into(),
+                "skew" => geometry.skew = number()?,
+                "reserved" => geometry.reserved_tracks = number()?,
+                "block" => geometry.block_size = number()?.into(),
+                "dir" => geometry.dir_entries = number()?,
+                _ => bail!("unknown disk geometry field {:?}", key),
+            }
+        }
+        geometry.validate()?;
+        Ok(geometry)
+    }
+}
+
+/// A CP/M 2.2 disk parameter block.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Dpb {
+    /// Records per track.
+    pub spt: u16,
+    /// Block shift and mask: a block holds `blm + 1 == 1 << bsh` records.
+    pub bsh: u8,
+    pub blm: u8,
+    /// Extent mask: each directory entry covers `exm + 1` 16K extents.
+    pub exm: u8,
+    /// Highest block number.
+    pub dsm: u16,
+    /// Highest directory entry number.
+    pub drm: u16,
+    /// Blocks taken by the directory, as a bit map from the top bit of AL0.
+    pub al0: u8,
+    pub al1: u8,
+    /// Size of the directory check vector, which assumes the disk can be
+    /// changed.
+    pub cks: u16,
+    /// Reserved tracks.
+    pub off: u16,
+}
+
+impl Dpb {
+    /// The DPB as it is laid out in memory.
+    pub fn to_bytes(&self) -> [u8; 15] {
+        let [spt_low, spt_high] = self.spt.to_le_bytes();
+        let [dsm_low, dsm_high] = self.dsm.to_le_bytes();
+        let [drm_low, drm_high] = self.drm.to_le_bytes();
+        let [cks_low, cks_high] = self.cks.to_le_bytes();
+        let [off_low, off_high] = self.off.to_le_bytes();
+        [
+            spt_low, spt_high, self.bsh, self.blm, self.exm, dsm_low, dsm_high, drm_low, drm_high,
+            self.al0, self.al1, cks_low, cks_high, off_low, off_high,
+        ]
+    }
+}
+
+/// A file in the directory of a disk image.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DirEntry {
+    pub user: u8,
+    /// Name such as `FOO.ASM`.
+    pub name: String,
+    /// Length rounded up to whole records, as CP/M keeps it.
+    pub len: usize,
+}
+
+/// A disk image held in memory.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiskImage {
+    geometry: Geometry,
+    data: Vec<u8>,
+}
+
+impl DiskImage {
+    /// Creates a freshly formatted disk.
+    ///
+    /// #Panics
+    /// Panics if the geometry does not describe a usable CP/M disk.
+    pub fn new(geometry: Geometry) -> DiskImage {
+        geometry.validate().expect("invalid disk geometry");
+        DiskImage {
+            geometry,
+            data: vec![EMPTY; geometry.image_len()],
+        }
+    }
+
+    /// Wraps the bytes of an image file. Images shorter than the geometry
+    /// calls for, as some tools write, are padded as if formatted.
+    ///
+    /// #Errors
+    /// Fails if the geometry is not usable or the image is too long for it.
+    pub fn from_bytes(geometry: Geometry, mut data: Vec<u8>) -> Result<DiskImage, Error> {
+        geometry.validate()?;
+        if data.len() > geometry.image_len() {
+            bail!(
+                "image of {} bytes is longer than the {} bytes of a {} disk",
+                data.len(),
+                geometry.image_len(),
+                geometry
+            );
+        }
+        data.resize(geometry.image_len(), EMPTY);
+        Ok(DiskImage { geometry, data })
+    }
+
+    pub fn open<P: AsRef<Path>>(geometry: Geometry, path: P) -> Result<DiskImage, Error> {
+        let path = path.as_ref();
+        match fs::read(path) {
+            Ok(data) => DiskImage::from_bytes(geometry, data),
+            Err(e) => bail!("unable to read {}: {}", path.display(), e),
+        }
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        let path = path.as_ref();
+        match fs::write(path, &self.data) {
+            Ok(()) => Ok(()),
+            Err(e) => bail!("unable to write {}: {}", path.display(), e),
+        }
+    }
+
+    pub fn geometry(&self) -> &Geometry {
+        &self.geometry
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// The 128 byte `record` on `track`, if there is one.
+    pub fn read_record(&self, track: u16, record: u16) -> Option<&[u8]> {
+        let offset = self.geometry.offset(track, record)?;
+        Some(&self.data[offset..offset + RECORD_LEN])
+    }
+
+    /// Writes `data`, up to 128 bytes, to `record` on `track`. Returns whether
+    /// there is such a record.
+    pub fn write_record(&mut self, track: u16, record: u16, data: &[u8]) -> bool {
+        match self.geometry.offset(track, record) {
+            Some(offset) => {
+                let len = data.len().min(RECORD_LEN);
+                self.data[offset..offset + len].copy_from_slice(&data[..len]);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Files in the directory, in directory order.
+    pub fn list(&self) -> Vec<DirEntry> {
+        let mut files: Vec<DirEntry> = Vec::new();
+        for entry in self.entries() {
+            let len = entry.records() * RECORD_LEN;
+            let name = entry.name();
+            match files
+                .iter_mut()
+                .find(|file| file.user == entry.user() && file.name == name)
+            {
+                Some(file) => file.len = file.len.max(len),
+                None => files.push(DirEntry {
+                    user: entry.user(),
+                    name,
+                    len,
+                }),
+            }
+        }
+        files
+    }
+
+    /// Reads the file `name` of `user`. The last record is returned whole,
+    /// usually padded with Control-Z.
+    ///
+    /// #Errors
+    /// Fails if there is no such file, or its directory entries point at
+    /// blocks past the end of the disk.
+    pub fn extract(&self, user: u8, name: &str) -> Result<Vec<u8>, Error> {
+        let name = file_name(name)?;
+        let mut entries: Vec<Entry> = self
+            .entries()
+            .filter(|entry| entry.user() == user && entry.raw_name() == name)
+            .collect();
+        if entries.is_empty() {
+            bail!("no file {} in user {}", display_name(&name), user);
+        }
+        entries.sort_by_key(Entry::extent);
+        let mut data = Vec::new();
+        let records_per_entry = self.records_per_entry();
+        let dsm = self.geometry.dpb().dsm;
+        for entry in &entries {
+            let first = entry.extent() / self.extents_per_entry() * records_per_entry;
+            let records = entry.records().saturating_sub(first).min(records_per_entry);
+            for i in 0..records {
+                let block = self.entry_block(entry, i / self.block_records());
+                if block > usize::from(dsm) {
+                    bail!(
+                        "{} uses block {} past the end of the disk at {}",
+                        display_name(&name),
+                        block,
+                        dsm
+                    );
+                }
+                let record = block * self.block_records() + i % self.block_records();
+                let offset = self
+                    .block_offset(record)
+                    .ok_or_else(|| format_err!("block {} is outside the image", block))?;
+                data.extend_from_slice(&self.data[offset..offset + RECORD_LEN]);
+            }
+        }
+        Ok(data)
+    }
+
+    /// Writes `data` to a new file `name` of `user`, replacing any file of
+    /// that name. The last record is padded with Control-Z.
+    ///
+    /// #Errors
+    /// Fails if the name is not a valid 8.3 name or the disk or its
+    /// directory is full, in which case the disk is left unchanged.
+    pub fn insert(&mut self, user: u8, name: &str, data: &[u8]) -> Result<(), Error> {
+        let name = file_name(name)?;
+        if user > 15 {
+            bail!("user {} is not one of 0 to 15", user);
+        }
+        let mut disk = self.clone();
+        disk.remove(user, &name);
+
+        let records = data.len().div_ceil(RECORD_LEN);
+        let blocks_needed = (records * RECORD_LEN).div_ceil(self.geometry.block_size);
+        let blocks: Vec<usize> = disk.free_blocks().into_iter().take(blocks_needed).collect();
+        if blocks.len() < blocks_needed {
+            bail!(
+                "disk full: {} needs {} blocks",
+                display_name(&name),
+                blocks_needed
+            );
+        }
+        let entries_needed = records.div_ceil(disk.records_per_entry()).max(1);
+        let slots = disk.free_slots();
+        if slots.len() < entries_needed {
+            bail!("directory full");
+        }
+
+        for (i, chunk) in data.chunks(RECORD_LEN).enumerate() {
+            let mut record = [EOF; RECORD_LEN];
+            record[..chunk.len()].copy_from_slice(chunk);
+            let block = blocks[i / disk.block_records()];
+            let offset = disk
+                .block_offset(block * disk.block_records() + i % disk.block_records())
+                .expect("free blocks are on the disk");
+            disk.data[offset..offset + RECORD_LEN].copy_from_slice(&record);
+        }
+        for (i, &slot) in slots.iter().take(entries_needed).enumerate() {
+            let first = i * disk.records_per_entry();
+            let records = (records - first.min(records)).min(disk.records_per_entry());
+            let extent = i * disk.extents_per_entry() + records.saturating_sub(1) / EXTENT_RECORDS;
+            let mut entry = [0; ENTRY_LEN];
+            entry[0] = user;
+            entry[1..12].copy_from_slice(&name);
+            entry[12] = (extent & 0x1f) as u8;
+            entry[14] = (extent >> 5) as u8;
+            entry[15] =
+                (records - (records.saturating_sub(1) / EXTENT_RECORDS) * EXTENT_RECORDS) as u8;
+            let entry_blocks =
+                &blocks[first / disk.block_records()..][..records.div_ceil(disk.block_records())];
+            for (j, &block) in entry_blocks.iter().enumerate() {
+                match disk.geometry.wide_blocks() {
+                    true => {
+                        entry[16 + 2 * j..18 + 2 * j].copy_from_slice(&(block as u16).to_le_bytes())
+                    }
+                    false => entry[16 + j] = block as u8,
+                }
+            }
+            let offset = disk.entry_offset(slot);
+            disk.data[offset..offset + ENTRY_LEN].copy_from_slice(&entry);
+        }
+        *self = disk;
+        Ok(())
+    }
+
+    /// Marks the directory entries of a file unused.
+    fn remove(&mut self, user: u8, name: &[u8; 11]) {
+        for slot in 0..usize::from(self.geometry.dir_entries) {
+            let entry = self.entry(slot);
+            if entry.user() == user && entry.raw_name() == *name {
+                let offset = self.entry_offset(slot);
+                self.data[offset] = EMPTY;
+            }
+        }
+    }
+
+    fn free_slots(&self) -> Vec<usize> {
+        (0..usize::from(self.geometry.dir_entries))
+            .filter(|&slot| self.entry(slot).user() == EMPTY)
+            .collect()
+    }
+
+    fn free_blocks(&self) -> Vec<usize> {
+        let mut used = vec![false; self.geometry.blocks()];
+        for u in &mut used[..self.geometry.dir_blocks()] {
+            *u = true;
+        }
+        for entry in self.entries() {
+            for i in 0..self.geometry.blocks_per_entry() {
+                let block = self.entry_block(&entry, i);
+                if block != 0 && block < used.len() {
+                    used[block] = true;
+                }
+            }
+        }
+        (0..used.len()).filter(|&block| !used[block]).collect()
+    }
+
+    /// Directory entries in use. Entries with user numbers above 15 are
+    /// labels, timestamps or garbage and are skipped.
+    fn entries(&self) -> impl Iterator<Item = Entry> + '_ {
+        (0..usize::from(self.geometry.dir_entries))
+            .map(move |slot| self.entry(slot))
+            .filter(|entry| entry.user() <= 15)
+    }
+
+    fn entry(&self, slot: usize) -> Entry {
+        let offset = self.entry_offset(slot);
+        let mut bytes = [0; ENTRY_LEN];
+        bytes.copy_from_slice(&self.data[offset..offset + ENTRY_LEN]);
+        Entry(bytes)
+    }
+
+    fn entry_offset(&self, slot: usize) -> usize {
+        let record = slot * ENTRY_LEN / RECORD_LEN;
+        // Validation keeps the directory blocks on the disk.
+        let offset = self
+            .block_offset(record)
+            .expect("directory outside the disk");
+        offset + slot * ENTRY_LEN % RECORD_LEN
+    }
+
+    /// Block number `i` of an entry.
+    fn entry_block(&self, entry: &Entry, i: usize) -> usize {
+        let map = &entry.0[16..];
+        match self.geometry.wide_blocks() {
+            true => usize::from(u16::from_le_bytes([map[2 * i], map[2 * i + 1]])),
+            false => usize::from(map[i]),
+        }
+    }
+
+    fn block_records(&self) -> usize {
+        self.geometry.block_size / RECORD_LEN
+    }
+
+    fn extents_per_entry(&self) -> usize {
+        usize::from(self.geometry.dpb().exm) + 1
+    }
+
+    fn records_per_entry(&self) -> usize {
+        self.extents_per_entry() * EXTENT_RECORDS
+    }
+
+    /// Offset in the image of record `record` counted from the start of the
+    /// directory, or `None` if the disk is not that big.
+    fn block_offset(&self, record: usize) -> Option<usize> {
+        let records = self.geometry.records();
+        let track = usize::from(self.geometry.reserved_tracks) + record / records;
+        if track >= usize::from(self.geometry.tracks) {
+            return None;
+        }
+        self.geometry
+            .offset(track as u16, (record % records) as u16)
+    }
+}
+
+/// A raw directory entry.
+struct Entry([u8; ENTRY_LEN]);
+
+impl Entry {
+    fn user(&self) -> u8 {
+        self.0[0]
+    }
+
+    /// Name with the attribute bits cleared.
+    fn raw_name(&self) -> [u8; 11] {
+        let mut name = [0; 11];
+        for (n, &b) in name.iter_mut().zip(&self.0[1..12]) {
+            *n = b & 0x7f;
+        }
+        name
+    }
+
+    fn name(&self) -> String {
+        display_name(&self.raw_name())
+    }
+
+    /// Number of the last 16K logical extent the entry covers.
+    fn extent(&self) -> usize {
+        usize::from(self.0[14] & 0x3f) << 5 | usize::from(self.0[12] & 0x1f)
+    }
+
+    /// Records in the file up to the end of this entry.
+    fn records(&self) -> usize {
+        self.extent() * EXTENT_RECORDS + usize::from(self.0[15].min(0x80))
+    }
+}
+
+fn file_name(name: &str) -> Result<[u8; 11], Error> {
+    super::drives::cpm_name(name).ok_or_else(|| format_err!("{:?} is not a CP/M file name", name))
+}
+
+fn display_name(name: &[u8; 11]) -> String {
+    super::drives::host_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DirEntry, DiskImage, Dpb, Geometry, IBM_3740};
+
+    #[test]
+    fn works_out_dpbs() {
+        assert_eq!(
+            IBM_3740.dpb(),
+            Dpb {
+                spt: 26,
+                bsh: 3,
+                blm: 7,
+                exm: 0,
+                dsm: 242,
+                drm: 63,
+                al0: 0xc0,
+                al1: 0x00,
+                cks: 16,
+                off: 2,
+            }
+        );
+        let skewed: Vec<usize> = (0..26).map(|s| IBM_3740.physical_sector(s) + 1).collect();
+        assert_eq!(
+            skewed,
+            [
+                1, 7, 13, 19, 25, 5, 11, 17, 23, 3, 9, 15, 21, 2, 8, 14, 20, 26, 6, 12, 18, 24, 4,
+                10, 16, 22
+            ]
+        );
+
+        let hd: Geometry = "tracks=255,sectors=128,skew=1,reserved=0,block=2048,dir=1024"
+            .parse()
+            .unwrap();
+        assert_eq!(hd.dpb().dsm, 2039);
+        assert_eq!(hd.dpb().exm, 0);
+        assert_eq!(hd.dpb().al0, 0xff);
+        assert_eq!(hd.to_string().parse::<Geometry>().unwrap(), hd);
+        assert!("tracks=77,block=1000".parse::<Geometry>().is_err());
+        assert!("heads=2".parse::<Geometry>().is_err());
+    }
+
+    #[test]
+    fn inserts_and_extracts_files() {
+        let mut disk = DiskImage::new(IBM_3740);
+        let big: Vec<u8> = (0..20000).map(|i| i as u8).collect();
+        disk.insert(0, "big.dat", &big).unwrap();
+        disk.insert(3, "HELLO.TXT", b"hello").unwrap();
+        disk.insert(0, "empty", b"").unwrap();
+        assert_eq!(
+            disk.list(),
+            [
+                DirEntry {
+                    user: 0,
+                    name: "BIG.DAT".to_string(),
+                    len: 20096,
+                },
+                DirEntry {
+                    user: 3,
+                    name: "HELLO.TXT".to_string(),
+                    len: 128,
+                },
+                DirEntry {
+                    user: 0,
+                    name: "EMPTY".to_string(),
+                    len: 0,
+                },
+            ]
+        );
+        assert_eq!(disk.extract(0, "BIG.DAT").unwrap()[..20000], big[..]);
+        assert_eq!(&disk.extract(3, "hello.txt").unwrap()[..6], b"hello\x1a");
+        assert!(disk.extract(0, "hello.txt").is_err());
+
+        // The first directory entry sits at the start of track 2.
+        assert_eq!(disk.read_record(2, 0).unwrap()[1..8], *b"BIG    ");
+
+        // A corrupt entry pointing HELLO.TXT past block 242 is an error.
+        let mut directory = disk.read_record(2, 0).unwrap().to_vec();
+        assert_eq!(directory[65..70], *b"HELLO");
+        directory[80] = 250;
+        let mut corrupt = disk.clone();
+        corrupt.write_record(2, 0, &directory);
+        assert!(corrupt.extract(3, "hello.txt").is_err());
+        assert_eq!(
+            corrupt.extract(0, "big.dat").unwrap(),
+            disk.extract(0, "big.dat").unwrap()
+        );
+
+        let copy = disk.clone();
+        assert!(disk.insert(0, "huge.dat", &[0; 250_000]).is_err());
+        assert_eq!(disk, copy);
+        disk.insert(0, "big.dat", b"small").unwrap();
+        assert_eq!(disk.list()[0].len, 128);
+    }
+}
@@ -0,0 +1,485 @@
+//! BDOS file functions on drives backed by host directories.
+//!
+//! Files of user 0 live in the drive's directory itself and files of users
+//! 1 to 15 in subdirectories named `1` to `15`. Only host files whose names
+//! fit the 8.3 pattern and use characters CP/M allows are visible; they are
+//! matched without regard to case, and new files are created with upper case
+//! names.
+//!
+//! No state is kept between calls: every read or write opens the host file
+//! and seeks to the record the FCB points at, so a program that never closes
+//! its files loses nothing.
+
+use super::{Console, Cpm, DRIVE_USER, ENTRY_LEN, EOF, EXTENT_RECORDS, RECORD_LEN};
+
+use log::{debug, warn};
+use std::{
+    collections::VecDeque,
+    fs::{self, File, OpenOptions},
+    io::{Read, Seek, SeekFrom, Write},
+    path::PathBuf,
+};
+
+pub(super) const DRIVES: usize = 16;
+
+// Offsets of the FCB fields.
+const DR: usize = 0;
+const NAME: usize = 1;
+const EX: usize = 12;
+const S1: usize = 13;
+const S2: usize = 14;
+const RC: usize = 15;
+const AL: usize = 16;
+const CR: usize = 32;
+const R0: usize = 33;
+const FCB_LEN: usize = 36;
+
+const OK: u16 = 0x00;
+const END_OF_FILE: u16 = 0x01;
+const RANDOM_OUT_OF_RANGE: u16 = 0x06;
+const ERROR: u16 = 0xff;
+
+type Fcb = [u8; FCB_LEN];
+
+/// A host file found for an FCB.
+struct HostFile {
+    path: PathBuf,
+    name: [u8; 11],
+    user: u8,
+    len: u64,
+}
+
+impl<C: Console> Cpm<C> {
+    /// Carries out file function `function` on the FCB at `addr`.
+    pub(super) fn file_call(&mut self, function: u8, addr: u16) -> u16 {
+        let mut fcb = self.read_fcb(addr);
+        let result = match function {
+            15 => self.open(&mut fcb),
+            16 | 30 => match self.find(&fcb) {
+                Some(_) => OK,
+                None => ERROR,
+            },
+            17 => self.search_first(&fcb),
+            18 => self.search_next(),
+            19 => self.delete(&fcb),
+            20 => self.read_record(&mut fcb, None),
+            21 => self.write_record(&mut fcb, None),
+            22 => self.make(&mut fcb),
+            23 => {
+                let mut new_name = [0; 11];
+                for (i, b) in new_name.iter_mut().enumerate() {
+                    *b = self.read(addr.wrapping_add((16 + NAME + i) as u16));
+                }
+                self.rename(&fcb, &new_name)
+            }
+            33 | 34 | 40 => match random_record(&fcb) {
+                Some(record) if function == 33 => self.read_record(&mut fcb, Some(record)),
+                Some(record) => self.write_record(&mut fcb, Some(record)),
+                None => RANDOM_OUT_OF_RANGE,
+            },
+            35 => match self.find(&fcb) {
+                Some(file) => {
+                    set_random_record(&mut fcb, records(file.len));
+                    OK
+                }
+                None => ERROR,
+            },
+            36 => {
+                let record = position(&fcb);
+                set_random_record(&mut fcb, record);
+                OK
+            }
+            _ => unreachable!("BDOS function {} is not a file function", function),
+        };
+        self.write_fcb_bytes(addr, &fcb);
+        result
+    }
+
+    /// Login vector: a bit for each drive with a directory behind it.
+    pub(super) fn mounted(&self) -> u16 {
+        self.drives
+            .iter()
+            .enumerate()
+            .filter(|(_, dir)| dir.is_some())
+            .fold(0, |vector, (drive, _)| vector | 1 << drive)
+    }
+
+    fn open(&mut self, fcb: &mut Fcb) -> u16 {
+        match self.find(fcb) {
+            Some(file) => {
+                fcb[NAME..NAME + 11].copy_from_slice(&file.name);
+                fcb[S1] = 0;
+                let record = position(fcb);
+                set_position(fcb, record, file.len);
+                OK
+            }
+            None => ERROR,
+        }
+    }
+
+    fn make(&mut self, fcb: &mut Fcb) -> u16 {
+        let dir = match self.directory(fcb) {
+            Some(dir) => dir,
+            None => return ERROR,
+        };
+        let name = fcb_name(fcb);
+        let path = match self.find(fcb) {
+            Some(file) => file.path,
+            None => dir.join(host_name(&name)),
+        };
+        let created = fs::create_dir_all(&dir).and_then(|_| File::create(&path));
+        if let Err(e) = created {
+            warn!("unable to create {}: {}", path.display(), e);
+            return ERROR;
+        }
+        fcb[NAME..NAME + 11].copy_from_slice(&name);
+        fcb[S1] = 0;
+        fcb[RC] = 0;
+        OK
+    }
+
+    fn delete(&mut self, fcb: &Fcb) -> u16 {
+        let mut result = ERROR;
+        for file in self.matches(fcb) {
+            match fs::remove_file(&file.path) {
+                Ok(()) => result = OK,
+                Err(e) => warn!("unable to delete {}: {}", file.path.display(), e),
+            }
+        }
+        result
+    }
+
+    fn rename(&mut self, fcb: &Fcb, new_name: &[u8; 11]) -> u16 {
+        let file = match self.find(fcb) {
+            Some(file) => file,
+            None => return ERROR,
+        };
+        let new_name = strip_attributes(new_name);
+        // The host would silently replace a file already using the name.
+        let mut target = *fcb;
+        target[NAME..NAME + 11].copy_from_slice(&new_name);
+        if let Some(existing) = self.find(&target) {
+            warn!(
+                "unable to rename {}: {} exists",
+                file.path.display(),
+                existing.path.display()
+            );
+            return ERROR;
+        }
+        let path = file.path.with_file_name(host_name(&new_name));
+        match fs::rename(&file.path, &path) {
+            Ok(()) => OK,
+            Err(e) => {
+                warn!("unable to rename {}: {}", file.path.display(), e);
+                ERROR
+            }
+        }
+    }
+
+    /// Starts a directory search, leaving the first entry in the DMA buffer.
+    /// A drive code of `?` lists every extent of every user's files.
+    fn search_first(&mut self, fcb: &Fcb) -> u16 {
+        let all = fcb[DR] == b'?';
+        let mut entries = VecDeque::new();
+        for file in self.matches(fcb) {
+            let extents = records(file.len).saturating_sub(1) / EXTENT_RECORDS as u32 + 1;
+            for extent in 0..extents {
+                if !all && fcb[EX] != b'?' && u32::from(fcb[EX] & 0x1f) != extent & 0x1f {
+                    continue;
+                }
+                let mut entry = [0; ENTRY_LEN];
+                entry[0] = file.user;
+                entry[NAME..NAME + 11].copy_from_slice(&file.name);
+                set_position(&mut entry, extent * EXTENT_RECORDS as u32, file.len);
+                // The allocation map only has to show which 1K blocks are in
+                // use, for programs that count them.
+                let blocks = usize::from(entry[RC]).div_ceil(8);
+                for b in &mut entry[AL..AL + blocks] {
+                    *b = 1;
+                }
+                entries.push_back(entry);
+            }
+        }
+        self.search = entries;
+        self.search_next()
+    }
+
+    fn search_next(&mut self) -> u16 {
+        match self.search.pop_front() {
+            Some(entry) => {
+                for (i, &b) in entry.iter().enumerate() {
+                    self.write(self.dma.wrapping_add(i as u16), b);
+                }
+                OK
+            }
+            None => ERROR,
+        }
+    }
+
+    /// Reads the record at the FCB's sequential position, or at `random`,
+    /// into the DMA buffer.
+    fn read_record(&mut self, fcb: &mut Fcb, random: Option<u32>) -> u16 {
+        let file = match self.find(fcb) {
+            Some(file) => file,
+            None => return ERROR,
+        };
+        let record = random.unwrap_or_else(|| position(fcb));
+        if u64::from(record) >= records(file.len).into() {
+            return END_OF_FILE;
+        }
+        let mut buffer = [EOF; RECORD_LEN];
+        let read = File::open(&file.path).and_then(|mut f| {
+            f.seek(SeekFrom::Start(u64::from(record) * RECORD_LEN as u64))?;
+            let mut len = 0;
+            loop {
+                match f.read(&mut buffer[len..])? {
+                    0 => return Ok(()),
+                    n => len += n,
+                }
+            }
+        });
+        if let Err(e) = read {
+            warn!("unable to read {}: {}", file.path.display(), e);
+            return ERROR;
+        }
+        for (i, &b) in buffer.iter().enumerate() {
+            self.write(self.dma.wrapping_add(i as u16), b);
+        }
+        match random {
+            Some(record) => set_position(fcb, record, file.len),
+            None => set_position(fcb, record + 1, file.len),
+        }
+        OK
+    }
+
+    /// Writes the DMA buffer to the record at the FCB's sequential position,
+    /// or at `random`. Host files are sparse, so skipped records read back
+    /// as zeros.
+    fn write_record(&mut self, fcb: &mut Fcb, random: Option<u32>) -> u16 {
+        let file = match self.find(fcb) {
+            Some(file) => file,
+            None => return ERROR,
+        };
+        let record = random.unwrap_or_else(|| position(fcb));
+        let buffer: Vec<u8> = (0..RECORD_LEN)
+            .map(|i| self.read(self.dma.wrapping_add(i as u16)))
+            .collect();
+        let written = OpenOptions::new()
+            .write(true)
+            .open(&file.path)
+            .and_then(|mut f| {
+                f.seek(SeekFrom::Start(u64::from(record) * RECORD_LEN as u64))?;
+                f.write_all(&buffer)
+            });
+        if let Err(e) = written {
+            warn!("unable to write {}: {}", file.path.display(), e);
+            return ERROR;
+        }
+        let len = file.len.max(u64::from(record + 1) * RECORD_LEN as u64);
+        match random {
+            Some(record) => set_position(fcb, record, len),
+            None => set_position(fcb, record + 1, len),
+        }
+        OK
+    }
+
+    /// Host directory for the FCB's drive and the current user.
+    fn directory(&self, fcb: &Fcb) -> Option<PathBuf> {
+        let user = self.read(DRIVE_USER) >> 4;
+        self.drive(fcb)
+            .map(|root| user_directory(root.clone(), user))
+    }
+
+    fn drive(&self, fcb: &Fcb) -> Option<&PathBuf> {
+        let drive = match fcb[DR] {
+            0 | b'?' => self.read(DRIVE_USER) & 0x0f,
+            dr => (dr - 1) & 0x0f,
+        };
+        let dir = self.drives[usize::from(drive)].as_ref();
+        if dir.is_none() {
+            debug!("No directory behind drive {}:", (b'A' + drive) as char);
+        }
+        dir
+    }
+
+    fn find(&self, fcb: &Fcb) -> Option<HostFile> {
+        self.matches(fcb).into_iter().next()
+    }
+
+    /// Host files matching the FCB's name, which may contain `?` wildcards,
+    /// in name order.
+    fn matches(&self, fcb: &Fcb) -> Vec<HostFile> {
+        let root = match self.drive(fcb) {
+            Some(root) => root,
+            None => return Vec::new(),
+        };
+        let users = match fcb[DR] {
+            b'?' => 0..16,
+            _ => {
+                let user = self.read(DRIVE_USER) >> 4;
+                user..user + 1
+            }
+        };
+        let pattern = fcb_name(fcb);
+        let mut files = Vec::new();
+        for user in users {
+            let entries = match fs::read_dir(user_directory(root.clone(), user)) {
+                Ok(entries) => entries,
+                Err(_) => continue,
+            };
+            for entry in entries.flatten() {
+                let metadata = match entry.metadata() {
+                    Ok(metadata) if metadata.is_file() => metadata,
+                    _ => continue,
+                };
+                let name = match entry.file_name().to_str().and_then(cpm_name) {
+                    Some(name) => name,
+                    None => continue,
+                };
+                if matches(&pattern, &name) {
+                    files.push(HostFile {
+                        path: entry.path(),
+                        name,
+                        user,
+                        len: metadata.len(),
+                    });
+                }
+            }
+        }
+        files.sort_by_key(|file| (file.user, file.name));
+        files
+    }
+
+    fn read_fcb(&self, addr: u16) -> Fcb {
+        let mut fcb = [0; FCB_LEN];
+        for (i, b) in fcb.iter_mut().enumerate() {
+            *b = self.read(addr.wrapping_add(i as u16));
+        }
+        fcb
+    }
+
+    fn write_fcb_bytes(&mut self, addr: u16, fcb: &Fcb) {
+        for (i, &b) in fcb.iter().enumerate() {
+            self.write(addr.wrapping_add(i as u16), b);
+        }
+    }
+}
+
+fn user_directory(root: PathBuf, user: u8) -> PathBuf {
+    match user {
+        0 => root,
+        user => root.join(user.to_string()),
+    }
+}
+
+/// The FCB's name with the attribute bits cleared and letters upper cased.
+fn fcb_name(fcb: &[u8]) -> [u8; 11] {
+    let mut name = [0; 11];
+    name.copy_from_slice(&fcb[NAME..NAME + 11]);
+    strip_attributes(&name)
+}
+
+fn strip_attributes(name: &[u8; 11]) -> [u8; 11] {
+    let mut name = *name;
+    for b in &mut name {
+        *b = (*b & 0x7f).to_ascii_uppercase();
+    }
+    name
+}
+
+fn matches(pattern: &[u8; 11], name: &[u8; 11]) -> bool {
+    pattern.iter().zip(name).all(|(&p, &n)| p == b'?' || p == n)
+}
+
+/// Translates a host file name into a blank padded FCB name, if it is a
+/// valid 8.3 CP/M name.
+pub(crate) fn cpm_name(host: &str) -> Option<[u8; 11]> {
+    let (base, ext) = match host.find('.') {
+        Some(i) => (&host[..i], &host[i + 1..]),
+        None => (host, ""),
+    };
+    let valid = |part: &str, max| {
+        part.len() <= max
+            && part
+                .bytes()
+                .all(|b| b.is_ascii_graphic() && !b"<>.,;:=?*[]_".contains(&b))
+    };
+    if base.is_empty() || !valid(base, 8) || !valid(ext, 3) {
+        return None;
+    }
+    let mut name = [b' '; 11];
+    for (i, b) in base.bytes().enumerate() {
+        name[i] = b.to_ascii_uppercase();
+    }
+    for (i, b) in ext.bytes().enumerate() {
+        name[8 + i] = b.to_ascii_uppercase();
+    }
+    Some(name)
+}
+
+/// Translates a blank padded FCB name into a host file name such as
+/// `FOO.ASM`.
+pub(crate) fn host_name(name: &[u8; 11]) -> String {
+    let base = String::from_utf8_lossy(&name[..8]);
+    let ext = String::from_utf8_lossy(&name[8..]);
+    match ext.trim_end() {
+        "" => base.trim_end().to_string(),
+        ext => format!("{}.{}", base.trim_end(), ext),
+    }
+}
+
+/// Number of records needed to hold `len` bytes.
+fn records(len: u64) -> u32 {
+    len.div_ceil(RECORD_LEN as u64) as u32
+}
+
+/// Sequential position of an FCB, as a record number in the file.
+fn position(fcb: &[u8]) -> u32 {
+    u32::from(fcb[S2] & 0x3f) << 12 | u32::from(fcb[EX] & 0x1f) << 7 | u32::from(fcb[CR] & 0x7f)
+}
+
+/// Moves an FCB or directory entry to `record` of a file of `len` bytes,
+/// updating the extent fields and the record count of the extent.
+fn set_position(fcb: &mut [u8], record: u32, len: u64) {
+    let extent_start = record & !(EXTENT_RECORDS as u32 - 1);
+    if fcb.len() > CR {
+        fcb[CR] = (record & 0x7f) as u8;
+    }
+    fcb[EX] = (record >> 7 & 0x1f) as u8;
+    fcb[S2] = (record >> 12 & 0x3f) as u8;
+    fcb[RC] = records(len)
+        .saturating_sub(extent_start)
+        .min(EXTENT_RECORDS as u32) as u8;
+}
+
+/// Record number in R0 and R1, if R2 does not put it past the 8MB limit.
+fn random_record(fcb: &Fcb) -> Option<u32> {
+    match fcb[R0 + 2] {
+        0 => Some(u32::from(fcb[R0]) | u32::from(fcb[R0 + 1]) << 8),
+        _ => None,
+    }
+}
+
+fn set_random_record(fcb: &mut Fcb, record: u32) {
+    fcb[R0] = record as u8;
+    fcb[R0 + 1] = (record >> 8) as u8;
+    fcb[R0 + 2] = (record >> 16) as u8;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cpm_name, host_name};
+
+    #[test]
+    fn translates_names() {
+        assert_eq!(cpm_name("hello.asm"), Some(*b"HELLO   ASM"));
+        assert_eq!(cpm_name("README"), Some(*b"README     "));
+        assert_eq!(cpm_name("toolongname.txt"), None);
+        assert_eq!(cpm_name("file.text"), None);
+        assert_eq!(cpm_name("a.b.c"), None);
+        assert_eq!(cpm_name(".hidden"), None);
+        assert_eq!(cpm_name("my file"), None);
+        assert_eq!(host_name(b"HELLO   ASM"), "HELLO.ASM");
+        assert_eq!(host_name(b"README     "), "README");
+    }
+}
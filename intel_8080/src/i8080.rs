@@ -1,5 +1,5 @@
 use crate::{
-    instruction::{Instruction, Opcode},
+    instruction::{Instruction, Opcode, Syntax},
     interconnect::Interconnect,
     io::IO,
     mmu::Mmu,
@@ -12,8 +12,10 @@ mod flags;
 pub use self::flags::ConditionalFlags;
 
 mod register;
-pub use self::register::Register;
+pub use self::register::{Register, Registers};
 
+// `failure_derive` puts its impls inside an anonymous const.
+#[allow(non_local_definitions)]
 mod error;
 use self::error::EmulateError;
 
@@ -35,9 +37,13 @@ pub struct I8080 {
     flags: ConditionalFlags,
     rc: [bool; 8],
     interrupts_enabled: bool,
+    halted: bool,
+    syntax: Syntax,
+    cycles: u64,
 }
 
 impl I8080 {
+    #[allow(clippy::new_without_default)]
     pub fn new() -> I8080 {
         I8080 {
             a: 0,
@@ -52,6 +58,9 @@ impl I8080 {
             flags: ConditionalFlags::new(),
             rc: [false; 8],
             interrupts_enabled: true,
+            halted: false,
+            syntax: Syntax::Intel,
+            cycles: 0,
         }
     }
 
@@ -61,28 +70,46 @@ impl I8080 {
         interconnect: &mut Interconnect<T, U>,
         is_interrupt: bool,
     ) -> Result<()> {
-        use self::Opcode::*;
-
         let mmu = &mut interconnect.mmu;
         let io = &mut interconnect.io;
+        self.execute(instruction, mmu, io, is_interrupt)
+    }
+
+    pub(crate) fn execute<T: Mmu, U: IO>(
+        &mut self,
+        instruction: Instruction,
+        mmu: &mut T,
+        io: &mut U,
+        is_interrupt: bool,
+    ) -> Result<()> {
+        use self::Opcode::*;
 
         let old_pc = self.pc;
         match is_interrupt {
-            true => self.interrupts_enabled = false,
-            false => self.pc += instruction.len(),
+            true => {
+                self.interrupts_enabled = false;
+                self.halted = false;
+            }
+            false => self.pc = self.pc.wrapping_add(instruction.len()),
         }
 
         self.reset_rc();
+        let condition_met = self.condition_met(instruction.opcode());
         let r = match instruction.opcode() {
             NOP => Ok(()),
             // Data transfer Instructions
             LXI(r) => self.lxi(r, instruction.data()),
             LDAX(r) => self.ldax(r, mmu),
+            STAX(r) => self.stax(r, mmu),
             LDA => self.lda(instruction.data(), mmu),
             STA => self.sta(instruction.data(), mmu),
+            LHLD => self.lhld(instruction.data(), mmu),
+            SHLD => self.shld(instruction.data(), mmu),
             MOV(d, s) => self.mov(d, s, mmu),
             MVI(r) => self.mvi(r, instruction.data(), mmu),
             XCHG => self.xchg(),
+            XTHL => self.xthl(mmu),
+            SPHL => self.sphl(),
             PUSH(r) => self.push(r, mmu),
             POP(r) => self.pop(r, mmu),
             // Arithmetic Instructions
@@ -91,37 +118,78 @@ impl I8080 {
             INR(r) => self.inr(r, mmu),
             DCR(r) => self.dcr(r, mmu),
             ADD(r) => self.add(r, mmu),
+            ADC(r) => self.adc(r, mmu),
             ADI => self.adi(instruction.data()),
+            ACI => self.aci(instruction.data()),
             DAD(r) => self.dad(r),
             SUB(r) => self.sub(r, mmu),
+            SBB(r) => self.sbb(r, mmu),
             SUI => self.sui(instruction.data()),
+            SBI => self.sbi(instruction.data()),
+            DAA => self.daa(),
+            RLC => self.rlc(),
             RRC => self.rrc(),
+            RAL => self.ral(),
+            RAR => self.rar(),
             // Logical Instructions
             CPI => self.cpi(instruction.data()),
+            CMP(r) => self.cmp(r, mmu),
             ANI => self.ani(instruction.data()),
             ANA(r) => self.ana(r, mmu),
+            XRI => self.xri(instruction.data()),
             XRA(r) => self.xra(r, mmu),
+            ORI => self.ori(instruction.data()),
+            ORA(r) => self.ora(r, mmu),
+            CMA => self.cma(),
+            CMC => self.cmc(),
+            STC => self.stc(),
             // IO Instructions
-            OUT => self.out(instruction.data(), io),
+            OUT => self.out(instruction.data(), mmu, io),
             IN => self.input(instruction.data(), io),
             // Branch Instructions
             JMP => self.jmp(instruction.data()),
             JZ => self.jz(instruction.data()),
             JNZ => self.jnz(instruction.data()),
             JNC => self.jnc(instruction.data()),
+            JC | JPO | JPE | JP | JM => self.jump_if(condition_met, instruction.data()),
             CALL => self.call(instruction.data(), mmu),
+            CNZ | CZ | CNC | CC | CPO | CPE | CP | CM => {
+                self.call_if(condition_met, instruction.data(), mmu)
+            }
             RET => self.ret(mmu),
+            RNZ | RZ | RNC | RC | RPO | RPE | RP | RM => self.ret_if(condition_met, mmu),
+            RST(n) => self.rst(n, mmu),
+            PCHL => self.pchl(),
             // Special Instructions
             EI => self.ei(),
+            DI => self.di(),
+            HLT => self.hlt(),
             _op => return Err(EmulateError::UnimplementedInstruction { instruction }),
         };
 
         if let Ok(()) = r {
-            info!("{}: {}; {}", old_pc, instruction, self);
+            self.cycles += u64::from(instruction.opcode().cycles(condition_met));
+            info!("{}: {}; {}", old_pc, instruction.display(self.syntax), self);
         }
         r
     }
 
+    /// Whether the condition of a conditional jump, call or return holds.
+    fn condition_met(&self, opcode: Opcode) -> bool {
+        use self::Opcode::*;
+        match opcode {
+            JNZ | CNZ | RNZ => !self.flags.z,
+            JZ | CZ | RZ => self.flags.z,
+            JNC | CNC | RNC => !self.flags.cy,
+            JC | CC | RC => self.flags.cy,
+            JPO | CPO | RPO => !self.flags.p,
+            JPE | CPE | RPE => self.flags.p,
+            JP | CP | RP => !self.flags.s,
+            JM | CM | RM => self.flags.s,
+            _ => true,
+        }
+    }
+
     fn set_8bit_register(&mut self, register: Register, value: u8) {
         self.register_changed(register);
         match register {
@@ -140,6 +208,7 @@ impl I8080 {
         };
     }
 
+    #[allow(clippy::needless_return)]
     pub fn get_8bit_register(&self, register: Register) -> Result<u8> {
         match register {
             Register::A => Ok(self.a),
@@ -159,6 +228,42 @@ impl I8080 {
         high << 8 | low
     }
 
+    /// Reads the source operand of an 8 bit instruction, from memory at HL
+    /// for M.
+    ///
+    /// #Errors
+    /// Fails if given register SP.
+    fn operand<T: Mmu>(&self, opcode: Opcode, register: Register, mmu: &T) -> Result<u8> {
+        match register {
+            Register::SP => Err(EmulateError::UnsupportedRegister { opcode, register }),
+            Register::M => Ok(mmu.read_byte(self.m())),
+            r => self.get_8bit_register(r),
+        }
+    }
+
+    /// Adds `value` and a carry in to the accumulator, setting every flag.
+    fn add_to_a(&mut self, value: u8, carry: bool) {
+        let a = self.a;
+        let result = a.wrapping_add(value).wrapping_add(carry as u8);
+        self.flags.set_non_carry_flags(result);
+        self.flags.cy = u16::from(a) + u16::from(value) + carry as u16 > 0xff;
+        self.flags.ac = (a & 0x0f) + (value & 0x0f) + carry as u8 > 0x0f;
+        self.set_8bit_register(Register::A, result);
+    }
+
+    /// Subtracts `value` and a borrow from the accumulator, setting every
+    /// flag, and returns the difference without storing it.
+    fn sub_from_a(&mut self, value: u8, borrow: bool) -> u8 {
+        let a = self.a;
+        let result = a.wrapping_sub(value).wrapping_sub(borrow as u8);
+        self.flags.set_non_carry_flags(result);
+        self.flags.cy = u16::from(a) < u16::from(value) + borrow as u16;
+        // The ALU adds the complement, so AC is the carry out of bit 3 of
+        // that sum rather than a borrow.
+        self.flags.ac = (a & 0x0f) + (!value & 0x0f) + !borrow as u8 > 0x0f;
+        result
+    }
+
     fn set_m(&mut self, addr: u16) {
         let (high, low) = split_bytes(addr);
         self.set_8bit_register(Register::H, high);
@@ -178,14 +283,64 @@ impl I8080 {
         self.pc
     }
 
+    /// Moves execution to `pc`, e.g. to the entry point of a loaded program.
+    pub fn set_pc(&mut self, pc: u16) {
+        self.pc = pc;
+    }
+
     pub fn flags(&self) -> ConditionalFlags {
         self.flags
     }
 
+    pub fn registers(&self) -> Registers {
+        Registers {
+            a: self.a,
+            b: self.b,
+            c: self.c,
+            d: self.d,
+            e: self.e,
+            h: self.h,
+            l: self.l,
+            sp: self.sp,
+            pc: self.pc,
+            flags: self.flags,
+        }
+    }
+
+    /// Total clock cycles executed since the CPU was created.
+    pub fn cycles(&self) -> u64 {
+        self.cycles
+    }
+
     pub fn interrupts_enabled(&self) -> bool {
         self.interrupts_enabled
     }
 
+    /// Whether the CPU is stopped at a HLT, waiting for an interrupt.
+    pub fn halted(&self) -> bool {
+        self.halted
+    }
+
+    /// Overwrites the programmer visible state, e.g. when a host routine
+    /// stands in for code running on the CPU.
+    pub fn set_registers(&mut self, registers: Registers) {
+        self.a = registers.a;
+        self.b = registers.b;
+        self.c = registers.c;
+        self.d = registers.d;
+        self.e = registers.e;
+        self.h = registers.h;
+        self.l = registers.l;
+        self.sp = registers.sp;
+        self.pc = registers.pc;
+        self.flags = registers.flags;
+    }
+
+    /// Sets the mnemonic syntax used for instructions in the execution log.
+    pub fn set_syntax(&mut self, syntax: Syntax) {
+        self.syntax = syntax;
+    }
+
     fn push_u16<T: Mmu>(&mut self, value: u16, mmu: &mut T) -> Result<()> {
         let (high, low) = split_bytes(value);
         self.push_u8(high, mmu)?;
@@ -194,19 +349,19 @@ impl I8080 {
     }
 
     fn push_u8<T: Mmu>(&mut self, value: u8, mmu: &mut T) -> Result<()> {
-        let loc = self.sp - 1;
+        let loc = self.sp.wrapping_sub(1);
         //if loc < 0x2000 {
         //    return Err(EmulateError::StackOverflow);
         //};
         mmu.write_byte(loc, value);
-        self.sp -= 1;
+        self.sp = loc;
         self.register_changed(Register::SP);
         Ok(())
     }
 
     fn pop_u8<T: Mmu>(&mut self, mmu: &T) -> Result<u8> {
         let value = mmu.read_byte(self.sp);
-        self.sp += 1;
+        self.sp = self.sp.wrapping_add(1);
         self.register_changed(Register::SP);
         Ok(value)
     }
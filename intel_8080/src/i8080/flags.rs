@@ -8,6 +8,7 @@ pub struct ConditionalFlags {
 }
 
 impl ConditionalFlags {
+    #[allow(clippy::new_without_default)]
     pub fn new() -> ConditionalFlags {
         ConditionalFlags {
             z: false,
@@ -79,6 +80,7 @@ impl From<u8> for ConditionalFlags {
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::ConditionalFlags;
     #[test]
@@ -61,6 +61,7 @@ impl I8080 {
             }
         };
         self.flags.set_non_carry_flags(value);
+        self.flags.ac = value & 0x0f == 0x00;
         Ok(())
     }
 
@@ -84,37 +85,26 @@ impl I8080 {
             }
         };
         self.flags.set_non_carry_flags(value);
+        self.flags.ac = value & 0x0f != 0x0f;
         Ok(())
     }
 
     pub(crate) fn add<T: Mmu>(&mut self, register: Register, interconnect: &T) -> Result<()> {
-        let (result, cy) = match register {
-            Register::SP => {
-                return Err(EmulateError::UnsupportedRegister {
-                    opcode: Opcode::ADD(register),
-                    register,
-                })
-            }
-            Register::M => self
-                .get_8bit_register(Register::A)?
-                .overflowing_add(interconnect.read_byte(self.m())),
-            _r => self
-                .get_8bit_register(Register::A)?
-                .overflowing_add(self.get_8bit_register(_r)?),
-        };
+        let value = self.operand(Opcode::ADD(register), register, interconnect)?;
+        self.add_to_a(value, false);
+        Ok(())
+    }
 
-        self.flags.set_non_carry_flags(result);
-        self.flags.cy = cy;
-        self.set_8bit_register(Register::A, result);
+    /// #ADC - Add Register or Memory to Accumulator With Carry
+    pub(crate) fn adc<T: Mmu>(&mut self, register: Register, interconnect: &T) -> Result<()> {
+        let value = self.operand(Opcode::ADC(register), register, interconnect)?;
+        self.add_to_a(value, self.flags.cy);
         Ok(())
     }
 
     pub(crate) fn adi(&mut self, data: InstructionData) -> Result<()> {
         if let Some(value) = data.first() {
-            let (result, cy) = self.get_8bit_register(Register::A)?.overflowing_add(value);
-            self.flags.set_non_carry_flags(result);
-            self.flags.cy = cy;
-            self.set_8bit_register(Register::A, result);
+            self.add_to_a(value, false);
         } else {
             return Err(EmulateError::InvalidInstructionData {
                 opcode: Opcode::ADI,
@@ -124,6 +114,19 @@ impl I8080 {
         Ok(())
     }
 
+    /// #ACI - Add Immediate to Accumulator With Carry
+    pub(crate) fn aci(&mut self, data: InstructionData) -> Result<()> {
+        if let Some(value) = data.first() {
+            self.add_to_a(value, self.flags.cy);
+        } else {
+            return Err(EmulateError::InvalidInstructionData {
+                opcode: Opcode::ACI,
+                data,
+            });
+        }
+        Ok(())
+    }
+
     pub(crate) fn dad(&mut self, reg: Register) -> Result<()> {
         let addend1 = self.m();
         let addend2 = match (reg, reg.get_pair()) {
@@ -145,32 +148,23 @@ impl I8080 {
     }
 
     pub(crate) fn sub<T: Mmu>(&mut self, register: Register, interconnect: &T) -> Result<()> {
-        let (result, cy) = match register {
-            Register::SP => {
-                return Err(EmulateError::UnsupportedRegister {
-                    opcode: Opcode::SUB(register),
-                    register,
-                })
-            }
-            Register::M => self
-                .get_8bit_register(Register::A)?
-                .complement_sub(interconnect.read_byte(self.m())),
-            _r => self
-                .get_8bit_register(Register::A)?
-                .complement_sub(self.get_8bit_register(_r)?),
-        };
+        let value = self.operand(Opcode::SUB(register), register, interconnect)?;
+        let result = self.sub_from_a(value, false);
+        self.set_8bit_register(Register::A, result);
+        Ok(())
+    }
 
-        self.flags.set_non_carry_flags(result);
-        self.flags.cy = cy;
+    /// #SBB - Subtract Register or Memory From Accumulator With Borrow
+    pub(crate) fn sbb<T: Mmu>(&mut self, register: Register, interconnect: &T) -> Result<()> {
+        let value = self.operand(Opcode::SBB(register), register, interconnect)?;
+        let result = self.sub_from_a(value, self.flags.cy);
         self.set_8bit_register(Register::A, result);
         Ok(())
     }
 
     pub(crate) fn sui(&mut self, data: InstructionData) -> Result<()> {
         if let Some(value) = data.first() {
-            let (result, cy) = self.get_8bit_register(Register::A)?.complement_sub(value);
-            self.flags.set_non_carry_flags(result);
-            self.flags.cy = cy;
+            let result = self.sub_from_a(value, false);
             self.set_8bit_register(Register::A, result);
         } else {
             return Err(EmulateError::InvalidInstructionData {
@@ -181,14 +175,74 @@ impl I8080 {
         Ok(())
     }
 
+    /// #SBI - Subtract Immediate From Accumulator With Borrow
+    pub(crate) fn sbi(&mut self, data: InstructionData) -> Result<()> {
+        if let Some(value) = data.first() {
+            let result = self.sub_from_a(value, self.flags.cy);
+            self.set_8bit_register(Register::A, result);
+        } else {
+            return Err(EmulateError::InvalidInstructionData {
+                opcode: Opcode::SBI,
+                data,
+            });
+        }
+        Ok(())
+    }
+
+    /// #DAA - Decimal Adjust Accumulator
+    ///
+    /// Turns the result of adding two packed BCD numbers back into packed
+    /// BCD, using the carry and auxiliary carry of the addition.
+    pub(crate) fn daa(&mut self) -> Result<()> {
+        let mut correction = 0;
+        let mut cy = self.flags.cy;
+        if self.a & 0x0f > 0x09 || self.flags.ac {
+            correction |= 0x06;
+        }
+        if self.a > 0x99 || self.flags.cy {
+            correction |= 0x60;
+            cy = true;
+        }
+        self.add_to_a(correction, false);
+        self.flags.cy = cy;
+        Ok(())
+    }
+
+    pub(crate) fn rlc(&mut self) -> Result<()> {
+        self.set_8bit_register(Register::A, self.a.rotate_left(1));
+        self.flags.cy = self.a & 0x01 != 0;
+        Ok(())
+    }
+
     pub(crate) fn rrc(&mut self) -> Result<()> {
         self.set_8bit_register(Register::A, self.a.rotate_right(1));
         self.flags.cy = self.a & 0x80 != 0;
         Ok(())
     }
+
+    /// #RAL - Rotate Accumulator Left Through Carry
+    pub(crate) fn ral(&mut self) -> Result<()> {
+        let cy = self.a & 0x80 != 0;
+        self.set_8bit_register(Register::A, self.a << 1 | self.flags.cy as u8);
+        self.flags.cy = cy;
+        Ok(())
+    }
+
+    /// #RAR - Rotate Accumulator Right Through Carry
+    pub(crate) fn rar(&mut self) -> Result<()> {
+        let cy = self.a & 0x01 != 0;
+        self.set_8bit_register(Register::A, self.a >> 1 | (self.flags.cy as u8) << 7);
+        self.flags.cy = cy;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
+#[allow(
+    clippy::bool_assert_comparison,
+    clippy::legacy_numeric_constants,
+    clippy::needless_borrows_for_generic_args
+)]
 mod tests {
     use crate::i8080::*;
     use crate::Emulator;
@@ -213,7 +267,7 @@ mod tests {
             0x34, // INR M
             0x3c, // INR A
         ];
-        let mut system = Emulator::new(&bytecode);
+        let mut system = Emulator::new(&bytecode).unwrap();
         system.mmu_mut().write_byte(0x2bff, 0x15);
         system.cpu.a = 0x00;
         system.cpu.b = 0xff;
@@ -231,7 +285,7 @@ mod tests {
             0x35, // DCR M
             0x3d, // DCR A
         ];
-        let mut system = Emulator::new(&bytecode);
+        let mut system = Emulator::new(&bytecode).unwrap();
         system.mmu_mut().write_byte(0x2000, 0x15);
         system.cpu.a = 0x00;
         system.cpu.b = 0xff;
@@ -250,7 +304,7 @@ mod tests {
             0x23, // INX H
             0x33, // INX SP
         ];
-        let mut system = Emulator::new(&bytecode);
+        let mut system = Emulator::new(&bytecode).unwrap();
         system.cpu.b = 0x20;
         system.cpu.c = 0x00;
         system.cpu.d = 0xff;
@@ -276,7 +330,7 @@ mod tests {
             0x2b, // DCX H
             0x3b, // DCX SP
         ];
-        let mut system = Emulator::new(&bytecode);
+        let mut system = Emulator::new(&bytecode).unwrap();
         system.cpu.b = 0x20;
         system.cpu.c = 0x00;
         system.cpu.d = 0x00;
@@ -300,7 +354,7 @@ mod tests {
             0x80, // ADD B
             0x87, // ADD A
         ];
-        let mut system = Emulator::new(&bytecode);
+        let mut system = Emulator::new(&bytecode).unwrap();
         system.cpu.a = 0x2e;
         system.cpu.b = 0x6c;
         system.step();
@@ -324,7 +378,7 @@ mod tests {
             0xc6, 0x6c, // ADI 0x6c
             0xc6, 0x9a, // ADI 0x9a
         ];
-        let mut system = Emulator::new(&bytecode);
+        let mut system = Emulator::new(&bytecode).unwrap();
         system.cpu.a = 0x2e;
         system.step();
         assert_eq!(system.cpu.a, 0x9a);
@@ -347,7 +401,7 @@ mod tests {
             0x90, // SUB B
             0x97, // SUB A
         ];
-        let mut system = Emulator::new(&bytecode); // SUB B
+        let mut system = Emulator::new(&bytecode).unwrap(); // SUB B
         system.cpu.a = 0x49;
         system.cpu.b = 0x3a;
         system.step();
@@ -372,7 +426,7 @@ mod tests {
             0xd6, 0x3a, // SUI 0x3a
             0xd6, 0x0f, // SUI 0x0f
         ];
-        let mut system = Emulator::new(&bytecode);
+        let mut system = Emulator::new(&bytecode).unwrap();
         system.cpu.a = 0x49;
         system.step();
         assert_eq!(system.cpu.a, 0x0f);
@@ -395,7 +449,7 @@ mod tests {
             0x0f, // RRC
             0x0f, // RRC
         ];
-        let mut system = Emulator::new(&bytecode);
+        let mut system = Emulator::new(&bytecode).unwrap();
         system.cpu.a = 0xf2;
         system.step();
         assert_eq!(system.cpu.a, 0x79);
@@ -405,4 +459,151 @@ mod tests {
         assert_eq!(system.cpu.a, 0x88);
         assert_eq!(system.cpu.flags.cy, true);
     }
+
+    #[test]
+    fn adc_and_aci() {
+        let bytecode = [
+            0x88, //       ADC B
+            0x88, //       ADC B
+            0xce, 0x80, // ACI 0x80
+        ];
+        let mut system = Emulator::new(&bytecode).unwrap();
+        system.cpu.a = 0x3d;
+        system.cpu.b = 0x42;
+        system.cpu.flags.cy = true;
+        system.step();
+        assert_eq!(system.cpu.a, 0x80);
+        assert_eq!(system.cpu.flags.cy, false);
+        assert_eq!(system.cpu.flags.ac, true);
+        assert_eq!(system.cpu.flags.s, true);
+
+        // The carry in alone carries out of both nibbles.
+        system.cpu.a = 0xff;
+        system.cpu.b = 0x00;
+        system.cpu.flags.cy = true;
+        system.step();
+        assert_eq!(system.cpu.a, 0x00);
+        assert_eq!(system.cpu.flags.cy, true);
+        assert_eq!(system.cpu.flags.ac, true);
+        assert_eq!(system.cpu.flags.z, true);
+
+        system.step();
+        assert_eq!(system.cpu.a, 0x81);
+        assert_eq!(system.cpu.flags.cy, false);
+        assert_eq!(system.cpu.flags.z, false);
+    }
+
+    #[test]
+    fn sbb_and_sbi() {
+        let bytecode = [
+            0x98, //       SBB B
+            0x98, //       SBB B
+            0xde, 0x01, // SBI 0x01
+            0xde, 0x01, // SBI 0x01
+        ];
+        let mut system = Emulator::new(&bytecode).unwrap();
+        system.cpu.a = 0x04;
+        system.cpu.b = 0x02;
+        system.cpu.flags.cy = true;
+        system.step();
+        assert_eq!(system.cpu.a, 0x01);
+        assert_eq!(system.cpu.flags.cy, false);
+
+        system.cpu.b = 0x00;
+        system.cpu.flags.cy = true;
+        system.step();
+        assert_eq!(system.cpu.a, 0x00);
+        assert_eq!(system.cpu.flags.cy, false);
+        assert_eq!(system.cpu.flags.z, true);
+
+        system.step();
+        assert_eq!(system.cpu.a, 0xff);
+        assert_eq!(system.cpu.flags.cy, true);
+        assert_eq!(system.cpu.flags.s, true);
+
+        system.step();
+        assert_eq!(system.cpu.a, 0xfd);
+        assert_eq!(system.cpu.flags.cy, false);
+    }
+
+    #[test]
+    fn daa() {
+        let bytecode = [
+            0x27, //       DAA
+            0x3e, 0x38, // MVI A, 0x38
+            0xc6, 0x45, // ADI 0x45
+            0x27, //       DAA
+            0x3e, 0x29, // MVI A, 0x29
+            0xc6, 0x18, // ADI 0x18
+            0x27, //       DAA
+        ];
+        let mut system = Emulator::new(&bytecode).unwrap();
+        system.cpu.a = 0x9b;
+        system.step();
+        assert_eq!(system.cpu.a, 0x01);
+        assert_eq!(system.cpu.flags.cy, true);
+        assert_eq!(system.cpu.flags.ac, true);
+
+        // 38 + 45 = 83
+        system.step();
+        system.step();
+        system.step();
+        assert_eq!(system.cpu.a, 0x83);
+        assert_eq!(system.cpu.flags.cy, false);
+
+        // 29 + 18 = 47, where only the auxiliary carry shows the low digit
+        // overflowed.
+        system.step();
+        system.step();
+        assert_eq!(system.cpu.a, 0x41);
+        assert_eq!(system.cpu.flags.ac, true);
+        system.step();
+        assert_eq!(system.cpu.a, 0x47);
+        assert_eq!(system.cpu.flags.cy, false);
+    }
+
+    #[test]
+    fn rotate_through_carry() {
+        let bytecode = [
+            0x07, // RLC
+            0x17, // RAL
+            0x1f, // RAR
+            0x17, // RAL
+        ];
+        let mut system = Emulator::new(&bytecode).unwrap();
+        system.cpu.a = 0xf2;
+        system.step();
+        assert_eq!(system.cpu.a, 0xe5);
+        assert_eq!(system.cpu.flags.cy, true);
+        system.step();
+        assert_eq!(system.cpu.a, 0xcb);
+        assert_eq!(system.cpu.flags.cy, true);
+        system.step();
+        assert_eq!(system.cpu.a, 0xe5);
+        assert_eq!(system.cpu.flags.cy, true);
+        system.cpu.flags.cy = false;
+        system.step();
+        assert_eq!(system.cpu.a, 0xca);
+        assert_eq!(system.cpu.flags.cy, true);
+    }
+
+    #[test]
+    fn inr_dcr_auxiliary_carry() {
+        let bytecode = [
+            0x3c, // INR A
+            0x3d, // DCR A
+            0x3d, // DCR A
+        ];
+        let mut system = Emulator::new(&bytecode).unwrap();
+        system.cpu.a = 0x0f;
+        system.step();
+        assert_eq!(system.cpu.a, 0x10);
+        assert_eq!(system.cpu.flags.ac, true);
+        system.step();
+        assert_eq!(system.cpu.a, 0x0f);
+        assert_eq!(system.cpu.flags.ac, false);
+        system.step();
+        assert_eq!(system.cpu.a, 0x0e);
+        assert_eq!(system.cpu.flags.ac, true);
+    }
 }
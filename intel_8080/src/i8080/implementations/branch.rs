@@ -33,7 +33,16 @@ impl I8080 {
     }
 
     pub(crate) fn jnc(&mut self, data: InstructionData) -> Result<()> {
-        if self.flags.cy {
+        if !self.flags.cy {
+            self.jmp(data)?;
+        }
+        Ok(())
+    }
+
+    /// Conditional jumps other than JZ, JNZ and JNC, whose condition
+    /// `execute` has already evaluated.
+    pub(crate) fn jump_if(&mut self, condition_met: bool, data: InstructionData) -> Result<()> {
+        if condition_met {
             self.jmp(data)?;
         }
         Ok(())
@@ -57,9 +66,163 @@ impl I8080 {
         Ok(())
     }
 
+    pub(crate) fn call_if<T: Mmu>(
+        &mut self,
+        condition_met: bool,
+        data: InstructionData,
+        interconnect: &mut T,
+    ) -> Result<()> {
+        if condition_met {
+            self.call(data, interconnect)?;
+        }
+        Ok(())
+    }
+
     pub(crate) fn ret<T: Mmu>(&mut self, interconnect: &mut T) -> Result<()> {
         let addr = self.pop_u16(interconnect)?;
         self.pc = addr;
         Ok(())
     }
+
+    pub(crate) fn ret_if<T: Mmu>(
+        &mut self,
+        condition_met: bool,
+        interconnect: &mut T,
+    ) -> Result<()> {
+        if condition_met {
+            self.ret(interconnect)?;
+        }
+        Ok(())
+    }
+
+    /// #RST - Restart
+    ///
+    /// Opcodes: 0xc7, 0xcf, 0xd7, 0xdf, 0xe7, 0xef, 0xf7, 0xff
+    ///
+    /// Calls the subroutine at 8 times `n`. Interrupt controllers place RST
+    /// instructions on the bus, so this is also how interrupts are taken.
+    pub(crate) fn rst<T: Mmu>(&mut self, n: u8, interconnect: &mut T) -> Result<()> {
+        self.push_u16(self.pc, interconnect)?;
+        self.pc = u16::from(n) * 8;
+        Ok(())
+    }
+
+    /// #PCHL - Jump to the address in H and L
+    pub(crate) fn pchl(&mut self) -> Result<()> {
+        self.pc = self.m();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::bool_assert_comparison,
+    clippy::needless_borrows_for_generic_args
+)]
+mod tests {
+    use crate::interconnect::Interconnect;
+    use crate::io::basic_io::BasicIO;
+    use crate::mmu::{Mmu, Ram};
+    use crate::Emulator;
+
+    #[test]
+    fn jnc() {
+        let bytecode = [
+            0xd2, 0x10, 0x00, // JNC 0x0010
+            0xd2, 0x10, 0x00, // JNC 0x0010
+        ];
+        let mut system = Emulator::new(&bytecode).unwrap();
+        system.cpu.flags.cy = true;
+        system.step();
+        assert_eq!(system.cpu.pc, 0x0003);
+        system.cpu.flags.cy = false;
+        system.step();
+        assert_eq!(system.cpu.pc, 0x0010);
+    }
+
+    #[test]
+    fn conditional_jumps() {
+        let bytecode = [
+            0xda, 0x34, 0x12, // JC 0x1234
+            0xe2, 0x34, 0x12, // JPO 0x1234
+            0xfa, 0x00, 0x00, // JM 0x0000
+        ];
+        let mut system = Emulator::new(&bytecode).unwrap();
+        system.cpu.flags.p = true;
+        system.cpu.flags.s = true;
+        system.step();
+        assert_eq!(system.cpu.pc, 0x0003);
+        system.step();
+        assert_eq!(system.cpu.pc, 0x0006);
+        system.step();
+        assert_eq!(system.cpu.pc, 0x0000);
+    }
+
+    #[test]
+    fn conditional_call_and_return() {
+        let bytecode = [
+            0xc4, 0x08, 0x00, // CNZ 0x0008
+            0xcc, 0x08, 0x00, // CZ 0x0008
+            0x76, //             HLT
+            0x00, //             NOP
+            0xc0, //             RNZ
+            0xc8, //             RZ
+        ];
+        let mut system = Emulator::new(&bytecode).unwrap();
+        system.cpu.sp = 0x2400;
+        system.cpu.flags.z = true;
+        let mut step = |pc, sp, cycles| {
+            let start = system.cpu.cycles();
+            system.step();
+            assert_eq!(system.cpu.pc, pc);
+            assert_eq!(system.cpu.sp, sp);
+            assert_eq!(system.cpu.cycles() - start, cycles);
+        };
+        step(0x0003, 0x2400, 11);
+        step(0x0008, 0x23fe, 17);
+        step(0x0009, 0x23fe, 5);
+        step(0x0006, 0x2400, 11);
+        assert_eq!(system.mmu().read_byte(0x23fe), 0x06);
+        assert_eq!(system.mmu().read_byte(0x23ff), 0x00);
+    }
+
+    #[test]
+    fn rst() {
+        let bytecode = [
+            0x00, // NOP
+            0xef, // RST 5
+        ];
+        let mut system = Emulator::new(&bytecode).unwrap();
+        system.cpu.sp = 0x2400;
+        system.step();
+        let start = system.cpu.cycles();
+        system.step();
+        assert_eq!(system.cpu.pc, 0x0028);
+        assert_eq!(system.cpu.cycles() - start, 11);
+        assert_eq!(system.cpu.sp, 0x23fe);
+        assert_eq!(system.mmu().read_byte(0x23fe), 0x02);
+        assert_eq!(system.mmu().read_byte(0x23ff), 0x00);
+    }
+
+    #[test]
+    fn pchl() {
+        let bytecode = [0xe9];
+        let mut system = Emulator::new(&bytecode).unwrap();
+        system.cpu.h = 0x12;
+        system.cpu.l = 0x34;
+        system.step();
+        assert_eq!(system.cpu.pc, 0x1234);
+    }
+
+    #[test]
+    fn pc_wraps_around() {
+        let interconnect = Interconnect::from_parts(Ram::new(), BasicIO::default());
+        let mut system = Emulator::with_interconnect(interconnect);
+        system.mmu_mut().write_byte(0xffff, 0x00); // NOP
+        system.mmu_mut().write_byte(0x0000, 0x76); // HLT
+        system.cpu.pc = 0xffff;
+        system.run();
+        assert_eq!(system.cpu.halted(), true);
+        assert_eq!(system.cpu.pc, 0x0001);
+    }
 }
@@ -112,6 +112,77 @@ impl I8080 {
         Ok(())
     }
 
+    /// #STAX - Store Accumulator
+    ///
+    /// Opcodes: 0x02, 0x12
+    /// Supported Registers: B(0x02), D(0x12)
+    ///
+    /// The contents of the accumulator are stored in the memory location addressed by registers
+    /// BC or DE.
+    ///
+    /// #Errors
+    /// Fails if given registers A, C, E, H, L, M, SP.
+    pub(crate) fn stax<T: Mmu>(&mut self, register: Register, interconnect: &mut T) -> Result<()> {
+        let pair = match register {
+            Register::B | Register::D => register.get_pair().unwrap(),
+            _r => {
+                return Err(EmulateError::UnsupportedRegister {
+                    opcode: Opcode::STAX(register),
+                    register,
+                })
+            }
+        };
+        let loc = concat_bytes(
+            self.get_8bit_register(register)?,
+            self.get_8bit_register(pair)?,
+        );
+        interconnect.write_byte(loc, self.a);
+        Ok(())
+    }
+
+    /// #LHLD - Load H and L Direct
+    ///
+    /// Opcodes: 0x2a
+    /// Params: Two byte memory location following the opcode
+    ///
+    /// L is loaded from the given address and H from the address after it.
+    pub(crate) fn lhld<T: Mmu>(&mut self, data: InstructionData, interconnect: &T) -> Result<()> {
+        if let Some(addr) = data.addr() {
+            let low = interconnect.read_byte(addr);
+            let high = interconnect.read_byte(addr.wrapping_add(1));
+            self.set_m(concat_bytes(high, low));
+        } else {
+            return Err(EmulateError::InvalidInstructionData {
+                opcode: Opcode::LHLD,
+                data,
+            });
+        }
+        Ok(())
+    }
+
+    /// #SHLD - Store H and L Direct
+    ///
+    /// Opcodes: 0x22
+    /// Params: Two byte memory location following the opcode
+    ///
+    /// L is stored at the given address and H at the address after it.
+    pub(crate) fn shld<T: Mmu>(
+        &mut self,
+        data: InstructionData,
+        interconnect: &mut T,
+    ) -> Result<()> {
+        if let Some(addr) = data.addr() {
+            interconnect.write_byte(addr, self.l);
+            interconnect.write_byte(addr.wrapping_add(1), self.h);
+        } else {
+            return Err(EmulateError::InvalidInstructionData {
+                opcode: Opcode::SHLD,
+                data,
+            });
+        }
+        Ok(())
+    }
+
     /// #MOV - Move
     ///
     /// Opcodes: 0x40 - 0x7f; excluding 0x76
@@ -289,9 +360,32 @@ impl I8080 {
         self.set_8bit_register(Register::E, l);
         Ok(())
     }
+
+    ///XTHL - Exchange Stack Top With H and L
+    ///
+    /// Opcodes: 0xe3
+    ///
+    /// L is exchanged with the byte at SP and H with the byte above it.
+    pub(crate) fn xthl<T: Mmu>(&mut self, interconnect: &mut T) -> Result<()> {
+        let low = interconnect.read_byte(self.sp);
+        let high = interconnect.read_byte(self.sp.wrapping_add(1));
+        interconnect.write_byte(self.sp, self.l);
+        interconnect.write_byte(self.sp.wrapping_add(1), self.h);
+        self.set_m(concat_bytes(high, low));
+        Ok(())
+    }
+
+    ///SPHL - Load SP From H and L
+    ///
+    /// Opcodes: 0xf9
+    pub(crate) fn sphl(&mut self) -> Result<()> {
+        self.set_sp(self.m());
+        Ok(())
+    }
 }
 
 #[cfg(test)]
+#[allow(clippy::needless_borrows_for_generic_args)]
 mod tests {
     use crate::mmu::Mmu;
     use crate::Emulator;
@@ -304,7 +398,7 @@ mod tests {
             0x21, 0x11, 0xff, //LXI H, 0xff11
             0x31, 0xbb, 0xaa, //LXI SP, 0xaabb
         ];
-        let mut system = Emulator::new(&bytecode);
+        let mut system = Emulator::new(&bytecode).unwrap();
         system.run();
         assert_eq!(system.cpu.b, 0xbb);
         assert_eq!(system.cpu.c, 0xcc);
@@ -321,7 +415,7 @@ mod tests {
             0x0a, // LDAX B
             0x1a, // LDAX D
         ];
-        let mut system = Emulator::new(&bytecode);
+        let mut system = Emulator::new(&bytecode).unwrap();
         system.cpu.b = 0x20;
         system.cpu.d = 0x20;
         system.cpu.e = 0x01;
@@ -340,7 +434,7 @@ mod tests {
             0x4e, // MOV(C,M)
             0x77, // MOV(M,A)
         ];
-        let mut system = Emulator::new(&bytecode);
+        let mut system = Emulator::new(&bytecode).unwrap();
         system.cpu.d = 0xbd;
         system.cpu.a = 0xaa;
         system.cpu.h = 0x20;
@@ -359,7 +453,7 @@ mod tests {
             0x26, 0x20, //MVI H, 0x20
             0x36, 0xff, //MVI M, 0xff
         ];
-        let mut system = Emulator::new(&bytecode);
+        let mut system = Emulator::new(&bytecode).unwrap();
         system.run();
         assert_eq!(system.cpu.h, 0x20);
         assert_eq!(system.mmu().read_byte(0x2000), 0xff);
@@ -371,7 +465,7 @@ mod tests {
             0xd5, // PUSH D
             0xf5, // PUSH PSW
         ];
-        let mut system = Emulator::new(&bytecode);
+        let mut system = Emulator::new(&bytecode).unwrap();
         system.cpu.sp = 0x2400;
         system.cpu.d = 0x8f;
         system.cpu.e = 0x9d;
@@ -396,7 +490,7 @@ mod tests {
             0xd1, // POP D
             0xf1, // POP PSW
         ];
-        let mut system = Emulator::new(&bytecode);
+        let mut system = Emulator::new(&bytecode).unwrap();
         system.cpu.sp = 0x2400;
         system.cpu.a = 0xaa;
         system.cpu.b = 0xbb;
@@ -424,7 +518,7 @@ mod tests {
     #[test]
     fn xchg() {
         let bytecode = [0xeb];
-        let mut system = Emulator::new(&bytecode);
+        let mut system = Emulator::new(&bytecode).unwrap();
         system.cpu.h = 0x00;
         system.cpu.l = 0xff;
         system.cpu.d = 0x33;
@@ -435,4 +529,83 @@ mod tests {
         assert_eq!(system.cpu.d, 0x00);
         assert_eq!(system.cpu.e, 0xff);
     }
+
+    #[test]
+    fn stax() {
+        let bytecode = [
+            0x02, // STAX B
+            0x12, // STAX D
+        ];
+        let mut system = Emulator::new(&bytecode).unwrap();
+        system.cpu.b = 0x20;
+        system.cpu.c = 0x00;
+        system.cpu.d = 0x20;
+        system.cpu.e = 0x01;
+        system.cpu.a = 0x5a;
+        system.step();
+        system.cpu.a = 0xa5;
+        system.step();
+        assert_eq!(system.mmu().read_byte(0x2000), 0x5a);
+        assert_eq!(system.mmu().read_byte(0x2001), 0xa5);
+    }
+
+    #[test]
+    fn lhld_shld() {
+        let bytecode = [
+            0x2a, 0x00, 0x20, // LHLD 0x2000
+            0x22, 0x10, 0x20, // SHLD 0x2010
+        ];
+        let mut system = Emulator::new(&bytecode).unwrap();
+        system.mmu_mut().write_byte(0x2000, 0x34);
+        system.mmu_mut().write_byte(0x2001, 0x12);
+        system.run();
+        assert_eq!(system.cpu.h, 0x12);
+        assert_eq!(system.cpu.l, 0x34);
+        assert_eq!(system.mmu().read_byte(0x2010), 0x34);
+        assert_eq!(system.mmu().read_byte(0x2011), 0x12);
+    }
+
+    #[test]
+    fn xthl() {
+        let bytecode = [0xe3];
+        let mut system = Emulator::new(&bytecode).unwrap();
+        system.cpu.sp = 0x23fe;
+        system.cpu.h = 0x0b;
+        system.cpu.l = 0x3c;
+        system.mmu_mut().write_byte(0x23fe, 0xf0);
+        system.mmu_mut().write_byte(0x23ff, 0x0d);
+        system.run();
+        assert_eq!(system.cpu.h, 0x0d);
+        assert_eq!(system.cpu.l, 0xf0);
+        assert_eq!(system.mmu().read_byte(0x23fe), 0x3c);
+        assert_eq!(system.mmu().read_byte(0x23ff), 0x0b);
+        assert_eq!(system.cpu.sp, 0x23fe);
+    }
+
+    #[test]
+    fn sphl() {
+        let bytecode = [0xf9];
+        let mut system = Emulator::new(&bytecode).unwrap();
+        system.cpu.h = 0x50;
+        system.cpu.l = 0x6c;
+        system.run();
+        assert_eq!(system.cpu.sp, 0x506c);
+    }
+
+    #[test]
+    fn stack_wraps_around() {
+        let bytecode = [
+            0xc5, // PUSH B
+            0xc1, // POP B
+        ];
+        let mut system = Emulator::new(&bytecode).unwrap();
+        system.cpu.sp = 0x0000;
+        system.step();
+        assert_eq!(system.cpu.sp, 0xfffe);
+        system.step();
+        assert_eq!(system.cpu.sp, 0x0000);
+        // Nothing answers at the top of the Space Invaders address space.
+        assert_eq!(system.cpu.b, 0xff);
+        assert_eq!(system.cpu.c, 0xff);
+    }
 }
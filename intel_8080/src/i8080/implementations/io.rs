@@ -1,13 +1,20 @@
 use crate::{
     i8080::{error::EmulateError, Result, I8080, Register},
     instruction::{InstructionData, Opcode},
+    interconnect,
     io::IO,
+    mmu::Mmu,
 };
 
 impl I8080 {
-    pub(crate) fn out<U: IO>(&mut self, data: InstructionData, io: &mut U) -> Result<()> {
+    pub(crate) fn out<T: Mmu, U: IO>(
+        &mut self,
+        data: InstructionData,
+        mmu: &mut T,
+        io: &mut U,
+    ) -> Result<()> {
         if let Some(port) = data.first() {
-            io.write_port(port, self.a);
+            interconnect::write_port(mmu, io, port, self.a);
         } else {
             return Err(EmulateError::InvalidInstructionData {
                 opcode: Opcode::OUT,
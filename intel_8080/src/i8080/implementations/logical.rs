@@ -7,9 +7,7 @@ use crate::{
 impl I8080 {
     pub(crate) fn cpi(&mut self, data: InstructionData) -> Result<()> {
         if let Some(value) = data.first() {
-            let (v, c) = self.a.complement_sub(value);
-            self.flags.set_non_carry_flags(v);
-            self.flags.cy = c;
+            self.sub_from_a(value, false);
         } else {
             return Err(EmulateError::InvalidInstructionData {
                 opcode: Opcode::CPI,
@@ -25,6 +23,7 @@ impl I8080 {
             self.set_8bit_register(Register::A, result);
             self.flags.set_non_carry_flags(result);
             self.flags.cy = false;
+            self.flags.ac = false;
         } else {
             return Err(EmulateError::InvalidInstructionData {
                 opcode: Opcode::ANI,
@@ -45,6 +44,8 @@ impl I8080 {
             Register::M => interconnect.read_byte(self.m()),
             _r => self.get_8bit_register(_r)?,
         };
+        // The 8080 sets AC from bit 3 of the operands of an AND.
+        self.flags.ac = (self.a | value) & 0x08 != 0;
         let result = self.a & value;
         self.flags.set_non_carry_flags(result);
         self.flags.cy = false;
@@ -66,12 +67,82 @@ impl I8080 {
         let result = self.a ^ value;
         self.flags.set_non_carry_flags(result);
         self.flags.cy = false;
+        self.flags.ac = false;
         self.set_8bit_register(Register::A, result);
         Ok(())
     }
+
+    pub(crate) fn xri(&mut self, data: InstructionData) -> Result<()> {
+        if let Some(value) = data.first() {
+            self.logical_result(self.a ^ value);
+        } else {
+            return Err(EmulateError::InvalidInstructionData {
+                opcode: Opcode::XRI,
+                data,
+            });
+        }
+        Ok(())
+    }
+
+    pub(crate) fn ora<T: Mmu>(&mut self, register: Register, interconnect: &T) -> Result<()> {
+        let value = self.operand(Opcode::ORA(register), register, interconnect)?;
+        self.logical_result(self.a | value);
+        Ok(())
+    }
+
+    pub(crate) fn ori(&mut self, data: InstructionData) -> Result<()> {
+        if let Some(value) = data.first() {
+            self.logical_result(self.a | value);
+        } else {
+            return Err(EmulateError::InvalidInstructionData {
+                opcode: Opcode::ORI,
+                data,
+            });
+        }
+        Ok(())
+    }
+
+    /// #CMP - Compare Register or Memory With Accumulator
+    ///
+    /// Sets the flags as SUB would, leaving the accumulator unchanged.
+    pub(crate) fn cmp<T: Mmu>(&mut self, register: Register, interconnect: &T) -> Result<()> {
+        let value = self.operand(Opcode::CMP(register), register, interconnect)?;
+        self.sub_from_a(value, false);
+        Ok(())
+    }
+
+    /// #CMA - Complement Accumulator
+    pub(crate) fn cma(&mut self) -> Result<()> {
+        self.set_8bit_register(Register::A, !self.a);
+        Ok(())
+    }
+
+    /// #CMC - Complement Carry
+    pub(crate) fn cmc(&mut self) -> Result<()> {
+        self.flags.cy = !self.flags.cy;
+        Ok(())
+    }
+
+    /// #STC - Set Carry
+    pub(crate) fn stc(&mut self) -> Result<()> {
+        self.flags.cy = true;
+        Ok(())
+    }
+
+    /// Stores the result of an OR or XOR, which clear both carries.
+    fn logical_result(&mut self, result: u8) {
+        self.flags.set_non_carry_flags(result);
+        self.flags.cy = false;
+        self.flags.ac = false;
+        self.set_8bit_register(Register::A, result);
+    }
 }
 
 #[cfg(test)]
+#[allow(
+    clippy::bool_assert_comparison,
+    clippy::needless_borrows_for_generic_args
+)]
 mod tests {
     use crate::mmu::Mmu;
     use crate::Emulator;
@@ -83,7 +154,7 @@ mod tests {
             0xfe, 0x5f, // CPI 0x5f
             0xfe, 0x4f, // CPI 0x4f
         ];
-        let mut system = Emulator::new(&bytecode);
+        let mut system = Emulator::new(&bytecode).unwrap();
         system.cpu.a = 0x5f;
         system.step();
         assert_eq!(system.cpu.flags.z, false);
@@ -105,7 +176,7 @@ mod tests {
             0xe6, 0x0f, // ANI 0x0f
             0xe6, 0x22, // ANI 0x22
         ];
-        let mut system = Emulator::new(&bytecode);
+        let mut system = Emulator::new(&bytecode).unwrap();
         system.cpu.a = 0x3a;
         system.step();
         assert_eq!(system.cpu.a, 0x0a);
@@ -125,7 +196,7 @@ mod tests {
             0xa6, // ANA M
             0xa7, // ANA A
         ];
-        let mut system = Emulator::new(&bytecode);
+        let mut system = Emulator::new(&bytecode).unwrap();
         system.cpu.a = 0x0a;
         system.cpu.h = 0x20;
         system.cpu.l = 0xc5;
@@ -146,7 +217,7 @@ mod tests {
             0xae, // XRA M
             0xaf, // XRA A
         ];
-        let mut system = Emulator::new(&bytecode);
+        let mut system = Emulator::new(&bytecode).unwrap();
         system.cpu.a = 0x0a;
         system.cpu.h = 0x20;
         system.cpu.l = 0xc5;
@@ -158,4 +229,99 @@ mod tests {
         system.step();
         assert_eq!(system.cpu.a, 0x00);
     }
+
+    #[test]
+    fn ana_auxiliary_carry() {
+        let bytecode = [
+            0xa0, //       ANA B
+            0xa0, //       ANA B
+            0xe6, 0xff, // ANI 0xff
+        ];
+        let mut system = Emulator::new(&bytecode).unwrap();
+        system.cpu.a = 0x08;
+        system.cpu.b = 0x01;
+        system.step();
+        assert_eq!(system.cpu.a, 0x00);
+        assert_eq!(system.cpu.flags.ac, true);
+        system.cpu.a = 0x01;
+        system.cpu.b = 0x02;
+        system.step();
+        assert_eq!(system.cpu.flags.ac, false);
+        system.cpu.a = 0x08;
+        system.cpu.flags.ac = true;
+        system.step();
+        assert_eq!(system.cpu.flags.ac, false);
+    }
+
+    #[test]
+    fn ora_ori_xri() {
+        let bytecode = [
+            0xb1, //       ORA C
+            0xf6, 0x80, // ORI 0x80
+            0xee, 0x81, // XRI 0x81
+        ];
+        let mut system = Emulator::new(&bytecode).unwrap();
+        system.cpu.a = 0x33;
+        system.cpu.c = 0x0f;
+        system.cpu.flags.cy = true;
+        system.cpu.flags.ac = true;
+        system.step();
+        assert_eq!(system.cpu.a, 0x3f);
+        assert_eq!(system.cpu.flags.cy, false);
+        assert_eq!(system.cpu.flags.ac, false);
+        system.step();
+        assert_eq!(system.cpu.a, 0xbf);
+        assert_eq!(system.cpu.flags.s, true);
+        system.step();
+        assert_eq!(system.cpu.a, 0x3e);
+        assert_eq!(system.cpu.flags.s, false);
+    }
+
+    #[test]
+    fn cmp() {
+        let bytecode = [
+            0xbb, // CMP E
+            0xbb, // CMP E
+            0xbe, // CMP M
+        ];
+        let mut system = Emulator::new(&bytecode).unwrap();
+        system.cpu.a = 0x0a;
+        system.cpu.e = 0x05;
+        system.cpu.h = 0x20;
+        system.cpu.l = 0x00;
+        system.mmu_mut().write_byte(0x2000, 0x0b);
+        system.step();
+        assert_eq!(system.cpu.a, 0x0a);
+        assert_eq!(system.cpu.flags.z, false);
+        assert_eq!(system.cpu.flags.cy, false);
+        system.cpu.e = 0x0a;
+        system.step();
+        assert_eq!(system.cpu.flags.z, true);
+        assert_eq!(system.cpu.flags.cy, false);
+        system.step();
+        assert_eq!(system.cpu.a, 0x0a);
+        assert_eq!(system.cpu.flags.z, false);
+        assert_eq!(system.cpu.flags.cy, true);
+    }
+
+    #[test]
+    fn cma_cmc_stc() {
+        let bytecode = [
+            0x2f, // CMA
+            0x3f, // CMC
+            0x37, // STC
+            0x3f, // CMC
+        ];
+        let mut system = Emulator::new(&bytecode).unwrap();
+        system.cpu.a = 0x51;
+        system.step();
+        assert_eq!(system.cpu.a, 0xae);
+        assert_eq!(system.cpu.flags.cy, false);
+        system.step();
+        assert_eq!(system.cpu.flags.cy, true);
+        system.step();
+        assert_eq!(system.cpu.flags.cy, true);
+        system.step();
+        assert_eq!(system.cpu.flags.cy, false);
+    }
 }
@@ -5,4 +5,52 @@ impl I8080 {
         self.interrupts_enabled = true;
         Ok(())
     }
+
+    pub(crate) fn di(&mut self) -> Result<()> {
+        self.interrupts_enabled = false;
+        Ok(())
+    }
+
+    /// Stops the CPU until the next interrupt.
+    pub(crate) fn hlt(&mut self) -> Result<()> {
+        self.halted = true;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::bool_assert_comparison,
+    clippy::needless_borrows_for_generic_args
+)]
+mod tests {
+    use crate::Emulator;
+
+    #[test]
+    fn di_ei() {
+        let bytecode = [
+            0xf3, // DI
+            0xfb, // EI
+        ];
+        let mut system = Emulator::new(&bytecode).unwrap();
+        system.step();
+        assert_eq!(system.cpu.interrupts_enabled(), false);
+        system.step();
+        assert_eq!(system.cpu.interrupts_enabled(), true);
+    }
+
+    #[test]
+    fn hlt() {
+        let bytecode = [
+            0x76, // HLT
+            0x3c, // INR A
+        ];
+        let mut system = Emulator::new(&bytecode).unwrap();
+        system.step();
+        assert_eq!(system.cpu.halted(), true);
+        assert_eq!(system.cpu.pc, 0x0001);
+        system.step();
+        assert_eq!(system.cpu.pc, 0x0001);
+        assert_eq!(system.cpu.a, 0x00);
+    }
 }
@@ -1,3 +1,5 @@
+use crate::i8080::ConditionalFlags;
+
 use std::fmt::{self, Display};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -54,3 +56,18 @@ impl Display for Register {
         write!(f, "{}", s)
     }
 }
+
+/// Snapshot of the programmer visible CPU state.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Registers {
+    pub a: u8,
+    pub b: u8,
+    pub c: u8,
+    pub d: u8,
+    pub e: u8,
+    pub h: u8,
+    pub l: u8,
+    pub sp: u16,
+    pub pc: u16,
+    pub flags: ConditionalFlags,
+}
@@ -4,7 +4,13 @@ pub use self::opcode::Opcode;
 mod instruction_data;
 pub(crate) use self::instruction_data::InstructionData;
 
-use crate::i8080::split_bytes;
+mod syntax;
+pub use self::syntax::{InstructionDisplay, OpcodeDisplay, Syntax};
+
+mod disassembler;
+pub use self::disassembler::Disassembler;
+
+use crate::i8080::{concat_bytes, split_bytes};
 use failure::bail;
 use failure::Error;
 use std::fmt::{self, Display};
@@ -53,6 +59,24 @@ impl Instruction {
         }
     }
 
+    /// Decodes the instruction at the start of `bytes`.
+    ///
+    /// Returns `None` if `bytes` ends before the instruction's operands do.
+    pub fn decode(bytes: &[u8]) -> Option<Instruction> {
+        use self::opcode::OpcodeSize;
+        let opcode = Opcode::from(*bytes.first()?);
+        let instruction = match opcode.size() {
+            OpcodeSize::Unary => Instruction::new_unary(opcode),
+            OpcodeSize::Binary => Instruction::new_binary(opcode, *bytes.get(1)?),
+            OpcodeSize::Trinary => {
+                let addr = concat_bytes(*bytes.get(2)?, *bytes.get(1)?);
+                Instruction::new_trinary(opcode, addr)
+            }
+        };
+        instruction.ok()
+    }
+
+    #[allow(clippy::len_without_is_empty)]
     pub fn len(&self) -> u16 {
         match self.opcode.size() {
             self::opcode::OpcodeSize::Unary => 1,
@@ -68,6 +92,30 @@ impl Instruction {
     pub fn data(&self) -> InstructionData {
         self.data
     }
+
+    /// Returns a helper that prints the instruction in the given mnemonic syntax.
+    pub fn display(&self, syntax: Syntax) -> InstructionDisplay<'static> {
+        InstructionDisplay {
+            instruction: *self,
+            syntax,
+            symbols: None,
+        }
+    }
+
+    /// Encodes the instruction into its 1-3 byte machine form, with 16 bit
+    /// operands stored low byte first.
+    ///
+    /// #Errors
+    /// Fails if the opcode has no 8080 encoding, see `Opcode::encode`.
+    pub fn encode(&self) -> Result<Vec<u8>, Error> {
+        let mut bytes = vec![self.opcode.encode()?];
+        match self.data.tuple() {
+            (Some(hi), Some(lo)) => bytes.extend_from_slice(&[lo, hi]),
+            (Some(byte), None) => bytes.push(byte),
+            (_, _) => {}
+        }
+        Ok(bytes)
+    }
 }
 
 impl Display for Instruction {
@@ -84,3 +132,33 @@ impl Display for Instruction {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{opcode::OpcodeSize, Instruction, Opcode};
+
+    /// Bytes the 8080 leaves undefined; they decode to NOP.
+    const UNDEFINED: [u8; 10] = [0x08, 0x10, 0x18, 0x28, 0x38, 0xcb, 0xd9, 0xdd, 0xed, 0xfd];
+
+    #[test]
+    fn encode_round_trips_every_opcode() {
+        for byte in 0..=0xffu8 {
+            let opcode = Opcode::from(byte);
+            let instruction = match opcode.size() {
+                OpcodeSize::Unary => Instruction::new_unary(opcode),
+                OpcodeSize::Binary => Instruction::new_binary(opcode, 0xa5),
+                OpcodeSize::Trinary => Instruction::new_trinary(opcode, 0x1234),
+            }
+            .unwrap();
+            let bytes = instruction.encode().unwrap();
+            let expected: &[u8] = match (UNDEFINED.contains(&byte), opcode.size()) {
+                (true, _) => &[0x00],
+                (false, OpcodeSize::Unary) => &[byte],
+                (false, OpcodeSize::Binary) => &[byte, 0xa5],
+                (false, OpcodeSize::Trinary) => &[byte, 0x34, 0x12],
+            };
+            assert_eq!(bytes, expected, "opcode 0x{:02x} ({})", byte, opcode);
+            assert_eq!(Opcode::from(bytes[0]), opcode);
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{
+    instruction::{Instruction, Syntax},
+    symbols::SymbolTable,
+};
+
+use std::fmt::Write;
+
+/// Linear sweep disassembler over a block of machine code.
+///
+/// Yields each decoded instruction along with the address it was found at
+/// and the bytes it was decoded from, stopping at the first instruction that runs past the end of the block.
+pub struct Disassembler<'a> {
+    bytes: &'a [u8],
+    origin: u16,
+    offset: usize,
+    symbols: Option<&'a SymbolTable>,
+}
+
+impl<'a> Disassembler<'a> {
+    /// Creates a disassembler for `bytes`, where `bytes[0]` lives at `origin`.
+    pub fn new(bytes: &'a [u8], origin: u16) -> Disassembler<'a> {
+        Disassembler {
+            bytes,
+            origin,
+            offset: 0,
+            symbols: None,
+        }
+    }
+
+    /// Labels the listing with `symbols` and prints matching operands by name.
+    pub fn with_symbols(self, symbols: &'a SymbolTable) -> Disassembler<'a> {
+        Disassembler {
+            symbols: Some(symbols),
+            ..self
+        }
+    }
+
+    /// Renders the remaining instructions as a listing of address, raw
+    /// bytes and mnemonic, one instruction per line.
+    pub fn listing(self, syntax: Syntax) -> String {
+        let mut out = String::new();
+        let symbols = self.symbols;
+        for (addr, bytes, instruction) in self {
+            let mut display = instruction.display(syntax);
+            if let Some(symbols) = symbols {
+                if let Some(name) = symbols.name(addr) {
+                    writeln!(out, "{}:", name).unwrap();
+                }
+                display = display.with_symbols(symbols);
+            }
+            let hex: Vec<String> = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+            writeln!(out, "{:04x}  {:<8}  {}", addr, hex.join(" "), display).unwrap();
+        }
+        out
+    }
+}
+
+impl<'a> Iterator for Disassembler<'a> {
+    type Item = (u16, &'a [u8], Instruction);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let instruction = Instruction::decode(self.bytes.get(self.offset..)?)?;
+        let addr = self.origin.wrapping_add(self.offset as u16);
+        let bytes = &self.bytes[self.offset..self.offset + instruction.len() as usize];
+        self.offset += bytes.len();
+        Some((addr, bytes, instruction))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Disassembler;
+    use crate::{instruction::Syntax, symbols::SymbolTable};
+
+    #[test]
+    fn disassembles_linear_sweep() {
+        let bytes = [0x21, 0x34, 0x12, 0x7e, 0xd3, 0x02, 0xc3];
+        let addrs: Vec<u16> = Disassembler::new(&bytes, 0x100)
+            .map(|(a, _, _)| a)
+            .collect();
+        assert_eq!(addrs, vec![0x100, 0x103, 0x104]);
+    }
+
+    #[test]
+    fn listing_uses_syntax() {
+        let bytes = [0x21, 0x34, 0x12, 0x7e];
+        let listing = Disassembler::new(&bytes, 0).listing(Syntax::Zilog);
+        let lines: Vec<&str> = listing.lines().map(|l| l.trim_end()).collect();
+        assert_eq!(
+            lines,
+            vec![
+                "0000  21 34 12  LD     HL,0x1234",
+                "0003  7e        LD     A,(HL)"
+            ]
+        );
+    }
+
+    #[test]
+    fn listing_shows_undefined_opcodes_as_read() {
+        let bytes = [0x08, 0xcb, 0x00];
+        let listing = Disassembler::new(&bytes, 0).listing(Syntax::Intel);
+        let lines: Vec<&str> = listing.lines().map(|l| l.trim_end()).collect();
+        assert_eq!(
+            lines,
+            vec![
+                "0000  08        NOP",
+                "0001  cb        NOP",
+                "0002  00        NOP"
+            ]
+        );
+    }
+
+    #[test]
+    fn listing_uses_symbols() {
+        let bytes = [0xc3, 0x03, 0x00, 0x00];
+        let mut symbols = SymbolTable::new();
+        symbols.insert("loop", 0x0003);
+        let listing = Disassembler::new(&bytes, 0)
+            .with_symbols(&symbols)
+            .listing(Syntax::Intel);
+        let lines: Vec<&str> = listing.lines().map(|l| l.trim_end()).collect();
+        assert_eq!(
+            lines,
+            vec![
+                "0000  c3 03 00  JMP    loop",
+                "loop:",
+                "0003  00        NOP"
+            ]
+        );
+    }
+}
@@ -1,7 +1,9 @@
 use crate::{
     i8080::Register,
+    instruction::{OpcodeDisplay, Syntax},
 };
 
+use failure::{bail, Error};
 use std::fmt::{self, Display};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -392,6 +394,151 @@ impl Opcode {
         }
     }
 
+    /// Returns a helper that prints the opcode in the given mnemonic syntax.
+    pub fn display(&self, syntax: Syntax) -> OpcodeDisplay {
+        OpcodeDisplay {
+            opcode: *self,
+            syntax,
+        }
+    }
+
+    /// Encodes the opcode back into its machine byte; the inverse of `From<u8>`.
+    ///
+    /// #Errors
+    /// Fails for register operands the 8080 has no encoding for, such as
+    /// `LXI(A)`, `MOV(M, M)` or `RST(8)`.
+    pub fn encode(&self) -> Result<u8, Error> {
+        use self::Opcode::*;
+        let byte = match *self {
+            NOP => 0x00,
+            LXI(r) => 0x01 | pair_code(*self, r)? << 4,
+            STAX(r) => 0x02 | indirect_pair_code(*self, r)? << 4,
+            INX(r) => 0x03 | pair_code(*self, r)? << 4,
+            INR(r) => 0x04 | register_code(*self, r)? << 3,
+            DCR(r) => 0x05 | register_code(*self, r)? << 3,
+            MVI(r) => 0x06 | register_code(*self, r)? << 3,
+            DAD(r) => 0x09 | pair_code(*self, r)? << 4,
+            LDAX(r) => 0x0a | indirect_pair_code(*self, r)? << 4,
+            DCX(r) => 0x0b | pair_code(*self, r)? << 4,
+            MOV(Register::M, Register::M) => bail!("MOV M,M has no encoding, it is HLT"),
+            MOV(d, s) => 0x40 | register_code(*self, d)? << 3 | register_code(*self, s)?,
+            PUSH(r) => 0xc5 | stack_pair_code(*self, r)? << 4,
+            POP(r) => 0xc1 | stack_pair_code(*self, r)? << 4,
+            ADD(r) => 0x80 | register_code(*self, r)?,
+            ADC(r) => 0x88 | register_code(*self, r)?,
+            SUB(r) => 0x90 | register_code(*self, r)?,
+            SBB(r) => 0x98 | register_code(*self, r)?,
+            ANA(r) => 0xa0 | register_code(*self, r)?,
+            XRA(r) => 0xa8 | register_code(*self, r)?,
+            ORA(r) => 0xb0 | register_code(*self, r)?,
+            CMP(r) => 0xb8 | register_code(*self, r)?,
+            RLC => 0x07,
+            RRC => 0x0f,
+            RAL => 0x17,
+            RAR => 0x1f,
+            RIM => 0x20,
+            SHLD => 0x22,
+            LHLD => 0x2a,
+            DAA => 0x27,
+            CMA => 0x2f,
+            SIM => 0x30,
+            STA => 0x32,
+            STC => 0x37,
+            LDA => 0x3a,
+            CMC => 0x3f,
+            RNZ => 0xc0,
+            JNZ => 0xc2,
+            JMP => 0xc3,
+            CNZ => 0xc4,
+            ADI => 0xc6,
+            RST(n) if n < 8 => 0xc7 | n << 3,
+            RST(n) => bail!("RST vector {} is out of range 0-7", n),
+            RZ => 0xc8,
+            RET => 0xc9,
+            HLT => 0x76,
+            JZ => 0xca,
+            CZ => 0xcc,
+            CALL => 0xcd,
+            ACI => 0xce,
+            RNC => 0xd0,
+            JNC => 0xd2,
+            OUT => 0xd3,
+            CNC => 0xd4,
+            SUI => 0xd6,
+            RC => 0xd8,
+            JC => 0xda,
+            IN => 0xdb,
+            CC => 0xdc,
+            SBI => 0xde,
+            RPO => 0xe0,
+            JPO => 0xe2,
+            XTHL => 0xe3,
+            CPO => 0xe4,
+            ANI => 0xe6,
+            RPE => 0xe8,
+            PCHL => 0xe9,
+            JPE => 0xea,
+            XCHG => 0xeb,
+            CPE => 0xec,
+            XRI => 0xee,
+            RP => 0xf0,
+            JP => 0xf2,
+            DI => 0xf3,
+            CP => 0xf4,
+            ORI => 0xf6,
+            RM => 0xf8,
+            SPHL => 0xf9,
+            JM => 0xfa,
+            EI => 0xfb,
+            CM => 0xfc,
+            CPI => 0xfe,
+        };
+        Ok(byte)
+    }
+
+    /// Number of clock cycles (states) the opcode takes.
+    ///
+    /// Conditional calls and returns take longer when their condition is met;
+    /// `condition_met` is ignored for every other opcode.
+    pub fn cycles(&self, condition_met: bool) -> u8 {
+        use self::Opcode::*;
+        match self {
+            MOV(Register::M, _) | MOV(_, Register::M) => 7,
+            MOV(_, _) => 5,
+            MVI(Register::M) => 10,
+            MVI(_) => 7,
+            INR(Register::M) | DCR(Register::M) => 10,
+            INR(_) | DCR(_) => 5,
+            ADD(Register::M) | ADC(Register::M) | SUB(Register::M) | SBB(Register::M) => 7,
+            ANA(Register::M) | XRA(Register::M) | ORA(Register::M) | CMP(Register::M) => 7,
+            ADD(_) | ADC(_) | SUB(_) | SBB(_) | ANA(_) | XRA(_) | ORA(_) | CMP(_) => 4,
+            LXI(_) | DAD(_) | POP(_) => 10,
+            STAX(_) | LDAX(_) => 7,
+            INX(_) | DCX(_) => 5,
+            PUSH(_) => 11,
+            SHLD | LHLD => 16,
+            STA | LDA => 13,
+            ADI | ACI | SUI | SBI | ANI | XRI | ORI | CPI => 7,
+            JMP | JNZ | JZ | JNC | JC | JPO | JPE | JP | JM => 10,
+            CALL => 17,
+            CNZ | CZ | CNC | CC | CPO | CPE | CP | CM => match condition_met {
+                true => 17,
+                false => 11,
+            },
+            RET => 10,
+            RNZ | RZ | RNC | RC | RPO | RPE | RP | RM => match condition_met {
+                true => 11,
+                false => 5,
+            },
+            RST(_) => 11,
+            IN | OUT => 10,
+            XTHL => 18,
+            HLT => 7,
+            PCHL | SPHL => 5,
+            NOP | RLC | RRC | RAL | RAR | RIM | SIM | DAA | CMA | STC | CMC | XCHG | DI | EI => 4,
+        }
+    }
+
     pub(super) fn num_registers(&self) -> u8 {
         use self::Opcode::*;
         match self {
@@ -586,6 +733,51 @@ impl Display for Opcode {
     }
 }
 
+/// The 3 bit `DDD`/`SSS` field used by MOV, MVI, INR, DCR and the accumulator group.
+fn register_code(opcode: Opcode, register: Register) -> Result<u8, Error> {
+    let code = match register {
+        Register::B => 0,
+        Register::C => 1,
+        Register::D => 2,
+        Register::E => 3,
+        Register::H => 4,
+        Register::L => 5,
+        Register::M => 6,
+        Register::A => 7,
+        Register::SP => bail!("{:?} is unsupported for Opcode {}", register, opcode),
+    };
+    Ok(code)
+}
+
+/// The 2 bit `RP` field used by LXI, INX, DCX and DAD.
+fn pair_code(opcode: Opcode, register: Register) -> Result<u8, Error> {
+    let code = match register {
+        Register::B => 0,
+        Register::D => 1,
+        Register::H => 2,
+        Register::SP => 3,
+        _ => bail!("{:?} is unsupported for Opcode {}", register, opcode),
+    };
+    Ok(code)
+}
+
+/// The `RP` field for PUSH and POP, where Register::A stands in for PSW.
+fn stack_pair_code(opcode: Opcode, register: Register) -> Result<u8, Error> {
+    match register {
+        Register::A => Ok(3),
+        Register::SP => bail!("{:?} is unsupported for Opcode {}", register, opcode),
+        _ => pair_code(opcode, register),
+    }
+}
+
+/// The `RP` field for LDAX and STAX, which only address through BC or DE.
+fn indirect_pair_code(opcode: Opcode, register: Register) -> Result<u8, Error> {
+    match register {
+        Register::B | Register::D => pair_code(opcode, register),
+        _ => bail!("{:?} is unsupported for Opcode {}", register, opcode),
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum OpcodeSize {
     Unary,
@@ -602,3 +794,43 @@ impl OpcodeSize {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Opcode;
+    use crate::i8080::Register;
+
+    #[test]
+    fn encode_rejects_unencodable_registers() {
+        assert!(Opcode::LXI(Register::A).encode().is_err());
+        assert!(Opcode::LDAX(Register::H).encode().is_err());
+        assert!(Opcode::PUSH(Register::SP).encode().is_err());
+        assert!(Opcode::MOV(Register::SP, Register::A).encode().is_err());
+        assert!(Opcode::MOV(Register::M, Register::M).encode().is_err());
+        assert!(Opcode::RST(8).encode().is_err());
+    }
+
+    #[test]
+    fn cycles() {
+        assert_eq!(Opcode::MOV(Register::A, Register::B).cycles(false), 5);
+        assert_eq!(Opcode::MOV(Register::A, Register::M).cycles(false), 7);
+        assert_eq!(Opcode::MVI(Register::M).cycles(false), 10);
+        assert_eq!(Opcode::CNZ.cycles(true), 17);
+        assert_eq!(Opcode::CNZ.cycles(false), 11);
+        assert_eq!(Opcode::RZ.cycles(true), 11);
+        assert_eq!(Opcode::RZ.cycles(false), 5);
+        assert_eq!(Opcode::JNZ.cycles(false), 10);
+    }
+
+    #[test]
+    fn encode_register_variants() {
+        assert_eq!(Opcode::LXI(Register::SP).encode().unwrap(), 0x31);
+        assert_eq!(Opcode::PUSH(Register::A).encode().unwrap(), 0xf5);
+        assert_eq!(Opcode::POP(Register::D).encode().unwrap(), 0xd1);
+        assert_eq!(
+            Opcode::MOV(Register::M, Register::A).encode().unwrap(),
+            0x77
+        );
+        assert_eq!(Opcode::RST(7).encode().unwrap(), 0xff);
+    }
+}
@@ -0,0 +1,309 @@
+use crate::{
+    i8080::Register,
+    instruction::{opcode::OpcodeSize, Instruction, Opcode},
+    symbols::SymbolTable,
+};
+
+use std::fmt::{self, Display};
+
+/// Mnemonic style used when printing opcodes and instructions.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Syntax {
+    /// Intel 8080 mnemonics: `MOV A,M`, `LXI H, 0x1234`
+    #[default]
+    Intel,
+    /// Zilog Z80 mnemonics for the 8080 subset: `LD A,(HL)`, `LD HL,0x1234`
+    Zilog,
+}
+
+/// Helper returned by `Opcode::display` to print an opcode in a given syntax.
+pub struct OpcodeDisplay {
+    pub(super) opcode: Opcode,
+    pub(super) syntax: Syntax,
+}
+
+impl Display for OpcodeDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.syntax {
+            Syntax::Intel => write!(f, "{}", self.opcode),
+            Syntax::Zilog => {
+                let placeholder = match self.opcode.size() {
+                    OpcodeSize::Trinary => "nn",
+                    _ => "n",
+                };
+                let (mnemonic, operands) = zilog(self.opcode, placeholder);
+                write!(f, "{:<7}{}", mnemonic, operands)
+            }
+        }
+    }
+}
+
+/// Helper returned by `Instruction::display` to print an instruction in a given syntax.
+pub struct InstructionDisplay<'a> {
+    pub(super) instruction: Instruction,
+    pub(super) syntax: Syntax,
+    pub(super) symbols: Option<&'a SymbolTable>,
+}
+
+impl<'a> InstructionDisplay<'a> {
+    /// Prints 16 bit operands that match a symbol by name instead of in hex.
+    pub fn with_symbols<'b>(self, symbols: &'b SymbolTable) -> InstructionDisplay<'b> {
+        InstructionDisplay {
+            symbols: Some(symbols),
+            instruction: self.instruction,
+            syntax: self.syntax,
+        }
+    }
+
+    fn label(&self) -> Option<&'a str> {
+        let addr = self.instruction.data().addr()?;
+        self.symbols?.name(addr)
+    }
+}
+
+impl<'a> Display for InstructionDisplay<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let data = self.instruction.data().to_string();
+        match (self.syntax, self.label()) {
+            (Syntax::Intel, None) => write!(f, "{}", self.instruction),
+            // Only 16 bit operands have labels, so this follows the three byte
+            // layouts of `Instruction`'s `Display`.
+            (Syntax::Intel, Some(label)) => {
+                let opcode = self.instruction.opcode();
+                match opcode.num_registers() {
+                    0 => write!(f, "{}{:<11}", opcode, label),
+                    _ => write!(f, "{}, {:<8}", opcode, label),
+                }
+            }
+            (Syntax::Zilog, label) => {
+                let (mnemonic, operands) = zilog(self.instruction.opcode(), label.unwrap_or(&data));
+                write!(f, "{:<7}{:<11}", mnemonic, operands)
+            }
+        }
+    }
+}
+
+fn zilog_register(register: Register) -> &'static str {
+    match register {
+        Register::A => "A",
+        Register::B => "B",
+        Register::C => "C",
+        Register::D => "D",
+        Register::E => "E",
+        Register::H => "H",
+        Register::L => "L",
+        Register::M => "(HL)",
+        Register::SP => "SP",
+    }
+}
+
+/// Register pairs as named by LXI, INX, DCX, DAD, LDAX and STAX.
+fn zilog_pair(register: Register) -> &'static str {
+    match register {
+        Register::B => "BC",
+        Register::D => "DE",
+        Register::H => "HL",
+        Register::SP => "SP",
+        r => zilog_register(r),
+    }
+}
+
+/// Register pairs as named by PUSH and POP, where Register::A stands in for PSW.
+fn zilog_stack_pair(register: Register) -> &'static str {
+    match register {
+        Register::A => "AF",
+        r => zilog_pair(r),
+    }
+}
+
+/// Translates an opcode into its Zilog mnemonic and operand field, with `n`
+/// standing in for the immediate data or address.
+fn zilog(opcode: Opcode, n: &str) -> (&'static str, String) {
+    use self::Opcode::*;
+    let alu = |op: &'static str, r: Register| (op, format!("A,{}", zilog_register(r)));
+    let plain = |op: &'static str, r: &str| (op, r.to_string());
+    match opcode {
+        NOP => plain("NOP", ""),
+        LXI(r) => ("LD", format!("{},{}", zilog_pair(r), n)),
+        STAX(r) => ("LD", format!("({}),A", zilog_pair(r))),
+        INX(r) => plain("INC", zilog_pair(r)),
+        INR(r) => plain("INC", zilog_register(r)),
+        DCR(r) => plain("DEC", zilog_register(r)),
+        MVI(r) => ("LD", format!("{},{}", zilog_register(r), n)),
+        DAD(r) => ("ADD", format!("HL,{}", zilog_pair(r))),
+        LDAX(r) => ("LD", format!("A,({})", zilog_pair(r))),
+        DCX(r) => plain("DEC", zilog_pair(r)),
+        MOV(d, s) => ("LD", format!("{},{}", zilog_register(d), zilog_register(s))),
+        PUSH(r) => plain("PUSH", zilog_stack_pair(r)),
+        POP(r) => plain("POP", zilog_stack_pair(r)),
+        ADD(r) => alu("ADD", r),
+        ADC(r) => alu("ADC", r),
+        SUB(r) => plain("SUB", zilog_register(r)),
+        SBB(r) => alu("SBC", r),
+        ANA(r) => plain("AND", zilog_register(r)),
+        XRA(r) => plain("XOR", zilog_register(r)),
+        ORA(r) => plain("OR", zilog_register(r)),
+        CMP(r) => plain("CP", zilog_register(r)),
+        RLC => plain("RLCA", ""),
+        RRC => plain("RRCA", ""),
+        RAL => plain("RLA", ""),
+        RAR => plain("RRA", ""),
+        RIM => plain("RIM", ""),
+        SHLD => ("LD", format!("({}),HL", n)),
+        LHLD => ("LD", format!("HL,({})", n)),
+        DAA => plain("DAA", ""),
+        CMA => plain("CPL", ""),
+        SIM => plain("SIM", ""),
+        STA => ("LD", format!("({}),A", n)),
+        STC => plain("SCF", ""),
+        LDA => ("LD", format!("A,({})", n)),
+        CMC => plain("CCF", ""),
+        RNZ => plain("RET", "NZ"),
+        JNZ => ("JP", format!("NZ,{}", n)),
+        JMP => plain("JP", n),
+        CNZ => ("CALL", format!("NZ,{}", n)),
+        ADI => ("ADD", format!("A,{}", n)),
+        RST(v) => ("RST", format!("0x{:02x}", v.wrapping_mul(8))),
+        RZ => plain("RET", "Z"),
+        RET => plain("RET", ""),
+        HLT => plain("HALT", ""),
+        JZ => ("JP", format!("Z,{}", n)),
+        CZ => ("CALL", format!("Z,{}", n)),
+        CALL => plain("CALL", n),
+        ACI => ("ADC", format!("A,{}", n)),
+        RNC => plain("RET", "NC"),
+        JNC => ("JP", format!("NC,{}", n)),
+        OUT => ("OUT", format!("({}),A", n)),
+        CNC => ("CALL", format!("NC,{}", n)),
+        SUI => plain("SUB", n),
+        RC => plain("RET", "C"),
+        JC => ("JP", format!("C,{}", n)),
+        IN => ("IN", format!("A,({})", n)),
+        CC => ("CALL", format!("C,{}", n)),
+        SBI => ("SBC", format!("A,{}", n)),
+        RPO => plain("RET", "PO"),
+        JPO => ("JP", format!("PO,{}", n)),
+        XTHL => plain("EX", "(SP),HL"),
+        CPO => ("CALL", format!("PO,{}", n)),
+        ANI => plain("AND", n),
+        RPE => plain("RET", "PE"),
+        PCHL => plain("JP", "(HL)"),
+        JPE => ("JP", format!("PE,{}", n)),
+        XCHG => plain("EX", "DE,HL"),
+        CPE => ("CALL", format!("PE,{}", n)),
+        XRI => plain("XOR", n),
+        RP => plain("RET", "P"),
+        JP => ("JP", format!("P,{}", n)),
+        DI => plain("DI", ""),
+        CP => ("CALL", format!("P,{}", n)),
+        ORI => plain("OR", n),
+        RM => plain("RET", "M"),
+        SPHL => plain("LD", "SP,HL"),
+        JM => ("JP", format!("M,{}", n)),
+        EI => plain("EI", ""),
+        CM => ("CALL", format!("M,{}", n)),
+        CPI => plain("CP", n),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Syntax;
+    use crate::{
+        i8080::Register,
+        instruction::{Instruction, Opcode},
+        symbols::SymbolTable,
+    };
+
+    #[test]
+    fn zilog_instructions() {
+        let mov = Instruction::new_unary(Opcode::MOV(Register::A, Register::M)).unwrap();
+        assert_eq!(
+            mov.display(Syntax::Zilog).to_string().trim_end(),
+            "LD     A,(HL)"
+        );
+        let lxi = Instruction::new_trinary(Opcode::LXI(Register::H), 0x1234).unwrap();
+        assert_eq!(
+            lxi.display(Syntax::Zilog).to_string().trim_end(),
+            "LD     HL,0x1234"
+        );
+        let sta = Instruction::new_trinary(Opcode::STA, 0x20ff).unwrap();
+        assert_eq!(
+            sta.display(Syntax::Zilog).to_string().trim_end(),
+            "LD     (0x20ff),A"
+        );
+        let jnz = Instruction::new_trinary(Opcode::JNZ, 0x0040).unwrap();
+        assert_eq!(
+            jnz.display(Syntax::Zilog).to_string().trim_end(),
+            "JP     NZ,0x0040"
+        );
+        let rst = Instruction::new_unary(Opcode::RST(7)).unwrap();
+        assert_eq!(
+            rst.display(Syntax::Zilog).to_string().trim_end(),
+            "RST    0x38"
+        );
+    }
+
+    #[test]
+    fn zilog_opcodes_use_placeholders() {
+        assert_eq!(
+            Opcode::MVI(Register::M).display(Syntax::Zilog).to_string(),
+            "LD     (HL),n"
+        );
+        assert_eq!(
+            Opcode::PUSH(Register::A).display(Syntax::Zilog).to_string(),
+            "PUSH   AF"
+        );
+        assert_eq!(
+            Opcode::LHLD.display(Syntax::Zilog).to_string(),
+            "LD     HL,(nn)"
+        );
+    }
+
+    #[test]
+    fn intel_matches_display() {
+        let lxi = Instruction::new_trinary(Opcode::LXI(Register::H), 0x1234).unwrap();
+        assert_eq!(lxi.display(Syntax::Intel).to_string(), lxi.to_string());
+        let opcode = Opcode::MOV(Register::A, Register::M);
+        assert_eq!(
+            opcode.display(Syntax::Intel).to_string(),
+            opcode.to_string()
+        );
+    }
+
+    #[test]
+    fn operands_use_symbols() {
+        let mut symbols = SymbolTable::new();
+        symbols.insert("DrawSprite", 0x15d3);
+        let call = Instruction::new_trinary(Opcode::CALL, 0x15d3).unwrap();
+        assert_eq!(
+            call.display(Syntax::Intel)
+                .with_symbols(&symbols)
+                .to_string()
+                .trim_end(),
+            "CALL   DrawSprite"
+        );
+        assert_eq!(
+            call.display(Syntax::Zilog)
+                .with_symbols(&symbols)
+                .to_string()
+                .trim_end(),
+            "CALL   DrawSprite"
+        );
+        let lxi = Instruction::new_trinary(Opcode::LXI(Register::H), 0x15d3).unwrap();
+        assert_eq!(
+            lxi.display(Syntax::Intel)
+                .with_symbols(&symbols)
+                .to_string()
+                .trim_end(),
+            "LXI    H, DrawSprite"
+        );
+        let jmp = Instruction::new_trinary(Opcode::JMP, 0x15d4).unwrap();
+        assert_eq!(
+            jmp.display(Syntax::Intel)
+                .with_symbols(&symbols)
+                .to_string(),
+            jmp.to_string()
+        );
+    }
+}
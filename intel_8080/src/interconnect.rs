@@ -2,29 +2,43 @@ use crate::io::basic_io::BasicIO;
 use crate::io::IO;
 use crate::mmu::basic_mmu::BasicMMU;
 use crate::mmu::Mmu;
+use crate::mmu::Observed;
 use crate::mmu::Rom;
 use crate::pic::InterruptController;
+use failure::Error;
 
 pub struct Interconnect<T: Mmu, U: IO> {
-    pub mmu: T,
+    /// The board's memory, wrapped so every access reaches the observers.
+    /// The wrapped `T` is reached through `Deref`.
+    pub mmu: Observed<T>,
     pub io: U,
     pub interrupt_controller: InterruptController,
 }
 
 impl Interconnect<BasicMMU, BasicIO> {
-    pub fn new<U: Into<Rom>>(rom: U) -> Interconnect<BasicMMU, BasicIO> {
-        Interconnect {
-            mmu: BasicMMU::new(rom),
+    /// #Errors
+    /// Fails if `rom` does not fit in the Space Invaders memory map.
+    pub fn new<U: Into<Rom>>(rom: U) -> Result<Interconnect<BasicMMU, BasicIO>, Error> {
+        Ok(Interconnect {
+            mmu: Observed::new(BasicMMU::new(rom)?),
             io: BasicIO::default(),
             interrupt_controller: InterruptController::default(),
-        }
+        })
     }
 }
 
 impl<T: Mmu, U: IO> Interconnect<T, U> {
+    pub fn from_parts(mmu: T, io: U) -> Interconnect<T, U> {
+        Interconnect {
+            mmu: Observed::new(mmu),
+            io,
+            interrupt_controller: InterruptController::default(),
+        }
+    }
+
     pub fn with_mmu(self, mmu: T) -> Interconnect<T, U> {
         Interconnect {
-            mmu,
+            mmu: Observed::new(mmu),
             io: self.io,
             interrupt_controller: self.interrupt_controller,
         }
@@ -37,4 +51,17 @@ impl<T: Mmu, U: IO> Interconnect<T, U> {
             interrupt_controller: self.interrupt_controller,
         }
     }
+
+    /// Performs an OUT write the way the CPU does, see `write_port`.
+    pub fn write_port(&mut self, port: u8, value: u8) {
+        write_port(&mut self.mmu, &mut self.io, port, value)
+    }
+}
+
+/// Routes an OUT write: the memory controller gets first refusal so that
+/// bank select ports can remap memory, and everything else goes to the `IO`.
+pub(crate) fn write_port<T: Mmu, U: IO>(mmu: &mut T, io: &mut U, port: u8, value: u8) {
+    if !mmu.port_write(port, value) {
+        io.write_port(port, value);
+    }
 }
@@ -1,6 +1,33 @@
 pub mod basic_io;
+pub mod bus;
+pub mod console;
+pub mod dip_switches;
+pub mod game_pad;
+pub mod i8251;
+pub mod i8253;
+pub mod i8255;
+pub mod i8259;
+
+pub use self::bus::{IoBus, IoBusBuilder, Ports};
+pub use self::console::{BufferConsole, Console, StdConsole};
+
+use crate::{instruction::Instruction, pic::InterruptController};
 
 pub trait IO {
     fn read_port(&self, port: u8) -> u8;
     fn write_port(&mut self, port: u8, value: u8);
+
+    /// Lets the hardware run for `cycles` CPU clock cycles. The emulator
+    /// calls this after every instruction, and every few cycles while the
+    /// CPU is halted. Devices that interrupt the CPU at a fixed vector raise
+    /// it on `interrupts`.
+    fn clock(&mut self, _cycles: u64, _interrupts: &mut InterruptController) {}
+
+    /// Interrupt acknowledge: called by the `InterruptController` when the
+    /// CPU can take an interrupt and none has been raised on it. Returns the
+    /// instruction an interrupt controller chip such as the 8259 puts on the
+    /// bus, if one is requesting service.
+    fn acknowledge_interrupt(&mut self) -> Option<Instruction> {
+        None
+    }
 }
@@ -1,32 +1,135 @@
-use super::IO;
+use super::{
+    dip_switches::{self, DipSwitches},
+    game_pad::{Button, GamePad},
+    IO,
+};
+use crate::{pic::InterruptController, video::Beam};
 use log::warn;
 
-#[derive(Default)]
+/// Port assignments of a Midway 8080 style board.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PortMap {
+    /// IN ports of the three input latches.
+    pub inputs: [u8; 3],
+    /// IN port returning the shifted value.
+    pub shift_result: u8,
+    /// OUT port setting the shift amount.
+    pub shift_amount: u8,
+    /// OUT port feeding a byte into the shift register.
+    pub shift_data: u8,
+    pub watchdog: u8,
+    pub sound: &'static [u8],
+}
+
+pub const SPACE_INVADERS_PORTS: PortMap = PortMap {
+    inputs: [0, 1, 2],
+    shift_result: 3,
+    shift_amount: 2,
+    shift_data: 4,
+    watchdog: 6,
+    sound: &[3, 5],
+};
+
+/// Idle values of the Space Invaders input latches. Unused input bits are
+/// tied high, and port 2 has the default `DipSwitches`.
+pub const SPACE_INVADERS_INPUTS: [u8; 3] = [0x0e, 0x08, 0x00];
+
+impl Default for PortMap {
+    fn default() -> PortMap {
+        SPACE_INVADERS_PORTS
+    }
+}
+
+/// The IO of a Midway 8080 board: input latches, the shift register, and
+/// the video beam, which raises RST 1 and RST 2 on the interrupt controller
+/// as the emulator clocks it.
 pub struct BasicIO {
-    input_0: u8,
-    input_1: u8,
-    input_2: u8,
+    ports: PortMap,
+    inputs: [u8; 3],
+    game_pad: GamePad,
     shift_register: ShiftRegister,
+    beam: Beam,
+}
+
+impl Default for BasicIO {
+    fn default() -> BasicIO {
+        BasicIO::new(SPACE_INVADERS_PORTS, SPACE_INVADERS_INPUTS)
+    }
+}
+
+impl BasicIO {
+    /// Creates the IO for a board with the given ports, with the input
+    /// latches idling at `inputs`.
+    pub fn new(ports: PortMap, inputs: [u8; 3]) -> BasicIO {
+        BasicIO {
+            ports,
+            inputs,
+            game_pad: GamePad::new(),
+            shift_register: ShiftRegister::default(),
+            beam: Beam::default(),
+        }
+    }
+
+    /// The video beam, for the frame count and the line it is on.
+    pub fn beam(&self) -> &Beam {
+        &self.beam
+    }
+
+    pub fn game_pad(&self) -> &GamePad {
+        &self.game_pad
+    }
+
+    pub fn game_pad_mut(&mut self) -> &mut GamePad {
+        &mut self.game_pad
+    }
+
+    pub fn press(&mut self, button: Button) {
+        self.game_pad.press(button);
+    }
+
+    pub fn release(&mut self, button: Button) {
+        self.game_pad.release(button);
+    }
+
+    /// The DIP switch settings read on the third input port, port 2 on
+    /// Space Invaders.
+    pub fn dip_switches(&self) -> DipSwitches {
+        DipSwitches::from_bits(self.inputs[2])
+    }
+
+    pub fn set_dip_switches(&mut self, switches: DipSwitches) {
+        self.inputs[2] = self.inputs[2] & !dip_switches::MASK | switches.bits();
+    }
 }
 
 impl IO for BasicIO {
     fn read_port(&self, port: u8) -> u8 {
-        match port {
-            0 => self.input_0,
-            1 => self.input_1,
-            2 => self.input_2,
-            3 => self.shift_register.read(),
-            _ => unimplemented!("Read for port {} unimplemented!", port),
+        if let Some(i) = self.ports.inputs.iter().position(|&p| p == port) {
+            self.inputs[i] | self.game_pad.bits(i)
+        } else if port == self.ports.shift_result {
+            self.shift_register.read()
+        } else {
+            unimplemented!("Read for port {} unimplemented!", port)
         }
     }
 
     fn write_port(&mut self, port: u8, value: u8) {
-        match port {
-            2 => self.shift_register.offset = value,
-            4 => self.shift_register.insert_value(value),
-            3 | 5 => warn!("Sound Out Unimplemented: Port {}", port),
-            6 => warn!("Watch-dog unimplemented: Port 6"),
-            _ => unimplemented!("Write for port {} unimplemented!", port),
+        if port == self.ports.shift_amount {
+            self.shift_register.offset = value;
+        } else if port == self.ports.shift_data {
+            self.shift_register.insert_value(value);
+        } else if self.ports.sound.contains(&port) {
+            warn!("Sound Out Unimplemented: Port {}", port);
+        } else if port == self.ports.watchdog {
+            warn!("Watch-dog unimplemented: Port {}", port);
+        } else {
+            unimplemented!("Write for port {} unimplemented!", port)
+        }
+    }
+
+    fn clock(&mut self, cycles: u64, interrupts: &mut InterruptController) {
+        if let Some(rst) = self.beam.advance(cycles) {
+            interrupts.generate_interrupt(rst);
         }
     }
 }
@@ -53,7 +156,37 @@ impl ShiftRegister {
 
 #[cfg(test)] 
 mod test {
-    use super::ShiftRegister;
+    use super::{BasicIO, Button, DipSwitches, ShiftRegister};
+    use crate::io::IO;
+
+    #[test]
+    fn reads_buttons_over_idle_inputs() {
+        let mut io = BasicIO::default();
+        assert_eq!(io.read_port(1), 0x08);
+        io.press(Button::Coin);
+        io.press(Button::Fire1);
+        io.press(Button::Tilt);
+        assert_eq!(io.read_port(0), 0x0e);
+        assert_eq!(io.read_port(1), 0x19);
+        assert_eq!(io.read_port(2), 0x04);
+        io.release(Button::Coin);
+        assert_eq!(io.read_port(1), 0x18);
+    }
+
+    #[test]
+    fn reads_dip_switches_on_port_2() {
+        let mut io = BasicIO::default();
+        assert_eq!(io.dip_switches(), DipSwitches::default());
+        io.press(Button::Left2);
+        for lives in 3..=6 {
+            let mut switches = DipSwitches::default();
+            switches.set_lives(lives).unwrap();
+            switches.set_coin_info(false);
+            io.set_dip_switches(switches);
+            assert_eq!(io.read_port(2), 0xa0 | (lives - 3));
+            assert_eq!(io.dip_switches(), switches);
+        }
+    }
 
     #[test]
     fn can_read_shift_register() {
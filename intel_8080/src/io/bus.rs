@@ -0,0 +1,281 @@
+//! Declarative port maps.
+//!
+//! A board describes its IO ports with `IoBus::builder()`, attaching each
+//! device to the block of ports it answers. `Ports` picks the block: a range
+//! of ports, optionally decoded through an address mask so the device is
+//! mirrored wherever the bits outside the mask are ignored, and optionally
+//! for reads or writes only. Devices see ports as offsets from the start of
+//! their block, so the same chip model works at any base.
+//!
+//! Declarations are applied in order and later ones replace earlier ones
+//! where they overlap, for reads and writes separately. Unclaimed ports
+//! read as the configured default and ignore writes.
+//!
+//! Devices the board needs to reach after building the bus, to drive their
+//! inputs or read their outputs, can be attached as `Rc<RefCell<D>>` with a
+//! clone kept by the board.
+
+use super::IO;
+use crate::{instruction::Instruction, pic::InterruptController};
+
+use failure::{bail, Error};
+use log::debug;
+use std::{cell::RefCell, rc::Rc};
+
+const PORTS: usize = 0x100;
+
+/// A block of ports a device is attached to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Ports {
+    start: u8,
+    end: u8,
+    mask: u8,
+    read: bool,
+    write: bool,
+}
+
+impl Ports {
+    /// The ports `start..=end`, fully decoded, for reads and writes.
+    pub fn range(start: u8, end: u8) -> Ports {
+        Ports {
+            start,
+            end,
+            mask: 0xff,
+            read: true,
+            write: true,
+        }
+    }
+
+    /// The single port `port`.
+    pub fn port(port: u8) -> Ports {
+        Ports::range(port, port)
+    }
+
+    /// Decodes only the address bits set in `mask`: a port belongs to the
+    /// block if it falls in the range once the other bits are cleared, so
+    /// the block is mirrored across them.
+    pub fn mask(self, mask: u8) -> Ports {
+        Ports { mask, ..self }
+    }
+
+    pub fn read_only(self) -> Ports {
+        Ports {
+            read: true,
+            write: false,
+            ..self
+        }
+    }
+
+    pub fn write_only(self) -> Ports {
+        Ports {
+            read: false,
+            write: true,
+            ..self
+        }
+    }
+
+    /// The offset of `port` within the block, if it belongs to it.
+    fn offset(&self, port: u8) -> Option<u8> {
+        let decoded = port & self.mask;
+        match (self.start..=self.end).contains(&decoded) {
+            true => Some(decoded - self.start),
+            false => None,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Target {
+    Unclaimed,
+    Device(usize, u8),
+}
+
+pub struct IoBusBuilder {
+    declarations: Vec<(Ports, Box<dyn IO>)>,
+    unclaimed: u8,
+}
+
+impl IoBusBuilder {
+    pub fn device<D: IO + 'static>(mut self, ports: Ports, device: D) -> IoBusBuilder {
+        self.declarations.push((ports, Box::new(device)));
+        self
+    }
+
+    /// Sets the value read from unclaimed ports. Defaults to 0xff, as on a
+    /// bus with pull-ups.
+    pub fn unclaimed(self, unclaimed: u8) -> IoBusBuilder {
+        IoBusBuilder { unclaimed, ..self }
+    }
+
+    /// #Errors
+    /// Fails if a block ends before it starts or has range bits outside its
+    /// mask.
+    pub fn build(self) -> Result<IoBus, Error> {
+        let mut bus = IoBus {
+            reads: vec![Target::Unclaimed; PORTS],
+            writes: vec![Target::Unclaimed; PORTS],
+            devices: Vec::new(),
+            unclaimed: self.unclaimed,
+        };
+        for (ports, device) in self.declarations {
+            if ports.end < ports.start || (ports.start | ports.end) & !ports.mask != 0 {
+                bail!(
+                    "ports 0x{:02x}-0x{:02x} cannot be decoded through mask 0x{:02x}",
+                    ports.start,
+                    ports.end,
+                    ports.mask
+                );
+            }
+            let index = bus.devices.len();
+            bus.devices.push(device);
+            for port in 0..PORTS {
+                if let Some(offset) = ports.offset(port as u8) {
+                    if ports.read {
+                        bus.reads[port] = Target::Device(index, offset);
+                    }
+                    if ports.write {
+                        bus.writes[port] = Target::Device(index, offset);
+                    }
+                }
+            }
+        }
+        Ok(bus)
+    }
+}
+
+/// An `IO` made of devices attached to blocks of ports.
+///
+/// Every device is clocked, and on interrupt acknowledge the devices are
+/// asked in the order they were declared, so earlier ones have priority as
+/// on a daisy chain.
+pub struct IoBus {
+    reads: Vec<Target>,
+    writes: Vec<Target>,
+    devices: Vec<Box<dyn IO>>,
+    unclaimed: u8,
+}
+
+impl IoBus {
+    pub fn builder() -> IoBusBuilder {
+        IoBusBuilder {
+            declarations: Vec::new(),
+            unclaimed: 0xff,
+        }
+    }
+}
+
+impl IO for IoBus {
+    fn read_port(&self, port: u8) -> u8 {
+        match self.reads[usize::from(port)] {
+            Target::Device(device, offset) => self.devices[device].read_port(offset),
+            Target::Unclaimed => {
+                debug!("Read from unclaimed port 0x{:02x}", port);
+                self.unclaimed
+            }
+        }
+    }
+
+    fn write_port(&mut self, port: u8, value: u8) {
+        match self.writes[usize::from(port)] {
+            Target::Device(device, offset) => self.devices[device].write_port(offset, value),
+            Target::Unclaimed => {
+                debug!("Write of 0x{:02x} to unclaimed port 0x{:02x}", value, port)
+            }
+        }
+    }
+
+    fn clock(&mut self, cycles: u64, interrupts: &mut InterruptController) {
+        for device in self.devices.iter_mut() {
+            device.clock(cycles, interrupts);
+        }
+    }
+
+    fn acknowledge_interrupt(&mut self) -> Option<Instruction> {
+        self.devices
+            .iter_mut()
+            .find_map(|device| device.acknowledge_interrupt())
+    }
+}
+
+/// A device shared between the bus and the board that wires it up.
+impl<D: IO> IO for Rc<RefCell<D>> {
+    fn read_port(&self, port: u8) -> u8 {
+        self.borrow().read_port(port)
+    }
+
+    fn write_port(&mut self, port: u8, value: u8) {
+        self.borrow_mut().write_port(port, value)
+    }
+
+    fn clock(&mut self, cycles: u64, interrupts: &mut InterruptController) {
+        self.borrow_mut().clock(cycles, interrupts)
+    }
+
+    fn acknowledge_interrupt(&mut self) -> Option<Instruction> {
+        self.borrow_mut().acknowledge_interrupt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IoBus, Ports};
+    use crate::io::{
+        i8255::{Port, I8255},
+        IO,
+    };
+    use std::{cell::RefCell, rc::Rc};
+
+    struct Latch(u8);
+
+    impl IO for Latch {
+        fn read_port(&self, port: u8) -> u8 {
+            self.0.wrapping_add(port)
+        }
+
+        fn write_port(&mut self, _port: u8, value: u8) {
+            self.0 = value;
+        }
+    }
+
+    #[test]
+    fn routes_ports_to_devices() {
+        let ppi = Rc::new(RefCell::new(I8255::new()));
+        let mut bus = IoBus::builder()
+            .unclaimed(0x00)
+            .device(Ports::range(0x10, 0x13).mask(0x13), Rc::clone(&ppi))
+            .device(Ports::port(0x20).read_only(), Latch(0x40))
+            .device(Ports::port(0x20).write_only(), Latch(0x80))
+            .device(Ports::range(0x30, 0x31), Latch(0x10))
+            .device(Ports::port(0x31), Latch(0x90))
+            .build()
+            .unwrap();
+
+        ppi.borrow_mut().set_input(Port::B, 0x5a);
+        assert_eq!(bus.read_port(0x11), 0x5a);
+        // A4 is decoded but A7 to A5, A3 and A2 are not.
+        assert_eq!(bus.read_port(0xf1), 0x5a);
+        assert_eq!(bus.read_port(0x01), 0x00);
+        bus.write_port(0x1f, 0x80);
+        bus.write_port(0x9c, 0x12);
+        assert_eq!(ppi.borrow().output(Port::A), 0x12);
+
+        // Reads and writes of port 0x20 go to different devices.
+        bus.write_port(0x20, 0x01);
+        assert_eq!(bus.read_port(0x20), 0x40);
+
+        assert_eq!(bus.read_port(0x30), 0x10);
+        assert_eq!(bus.read_port(0x31), 0x90);
+        bus.write_port(0x30, 0x20);
+        bus.write_port(0x42, 0x20);
+        assert_eq!(bus.read_port(0x30), 0x20);
+        assert_eq!(bus.read_port(0x42), 0x00);
+    }
+
+    #[test]
+    fn rejects_bad_blocks() {
+        let build = |ports| IoBus::builder().device(ports, Latch(0)).build();
+        assert!(build(Ports::range(0x11, 0x10)).is_err());
+        assert!(build(Ports::range(0x10, 0x13).mask(0x03)).is_err());
+        assert!(build(Ports::range(0x10, 0x13).mask(0x13).read_only()).is_ok());
+    }
+}
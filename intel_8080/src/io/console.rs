@@ -0,0 +1,145 @@
+use std::{
+    collections::VecDeque,
+    io::{self, Read, Write},
+    sync::mpsc::{self, Receiver, TryRecvError},
+    thread,
+};
+
+const CR: u8 = 0x0d;
+const LF: u8 = 0x0a;
+
+/// A terminal: the CP/M console device, reached through the BIOS CONST,
+/// CONIN and CONOUT entries and the BDOS console functions, or whatever is
+/// wired to a serial card.
+pub trait Console {
+    /// Whether a character is waiting to be read.
+    fn status(&mut self) -> bool;
+    /// Waits for the next character. Returns `None` once input has ended for
+    /// good, which stops the machine.
+    fn read(&mut self) -> Option<u8>;
+    fn write(&mut self, byte: u8);
+
+    /// Whether input has ended for good, so that `read` would return
+    /// `None`. Lets machines whose programs poll for input rather than
+    /// block on it stop when they start waiting for more.
+    fn ended(&mut self) -> bool {
+        false
+    }
+}
+
+/// Console on the host's stdin and stdout.
+///
+/// Host line endings are turned into the carriage returns CP/M programs
+/// expect. Input is still line buffered and echoed by the host terminal.
+pub struct StdConsole {
+    input: Receiver<u8>,
+    pending: Option<u8>,
+    out: io::Stdout,
+}
+
+impl StdConsole {
+    pub fn new() -> StdConsole {
+        let (tx, input) = mpsc::channel();
+        // Reading stdin blocks, so it happens on its own thread and CONST
+        // polls the channel.
+        thread::spawn(move || {
+            for byte in io::stdin().lock().bytes() {
+                let byte = match byte {
+                    Ok(LF) => CR,
+                    Ok(byte) => byte,
+                    Err(_) => break,
+                };
+                if tx.send(byte).is_err() {
+                    break;
+                }
+            }
+        });
+        StdConsole {
+            input,
+            pending: None,
+            out: io::stdout(),
+        }
+    }
+}
+
+impl Default for StdConsole {
+    fn default() -> StdConsole {
+        StdConsole::new()
+    }
+}
+
+impl Console for StdConsole {
+    fn status(&mut self) -> bool {
+        let _ = self.out.flush();
+        if self.pending.is_none() {
+            self.pending = self.input.try_recv().ok();
+        }
+        self.pending.is_some()
+    }
+
+    fn read(&mut self) -> Option<u8> {
+        let _ = self.out.flush();
+        self.pending.take().or_else(|| self.input.recv().ok())
+    }
+
+    fn ended(&mut self) -> bool {
+        !self.status() && self.input.try_recv() == Err(TryRecvError::Disconnected)
+    }
+
+    fn write(&mut self, byte: u8) {
+        let _ = self.out.write_all(&[byte]);
+        if byte == LF {
+            let _ = self.out.flush();
+        }
+    }
+}
+
+/// Console with scripted input that collects the output, for running
+/// programs unattended.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BufferConsole {
+    input: VecDeque<u8>,
+    output: Vec<u8>,
+}
+
+impl BufferConsole {
+    /// Creates a console that will type `input`, with newlines sent as
+    /// carriage returns.
+    pub fn new(input: &str) -> BufferConsole {
+        BufferConsole {
+            input: input
+                .bytes()
+                .map(|b| if b == LF { CR } else { b })
+                .collect(),
+            output: Vec::new(),
+        }
+    }
+
+    pub fn output(&self) -> &[u8] {
+        &self.output
+    }
+
+    /// Output so far as text, with CP/M's CR LF line endings turned into
+    /// newlines.
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.output).replace("\r\n", "\n")
+    }
+}
+
+impl Console for BufferConsole {
+    fn status(&mut self) -> bool {
+        !self.input.is_empty()
+    }
+
+    fn read(&mut self) -> Option<u8> {
+        self.input.pop_front()
+    }
+
+    fn ended(&mut self) -> bool {
+        self.input.is_empty()
+    }
+
+    fn write(&mut self, byte: u8) {
+        self.output.push(byte);
+    }
+}
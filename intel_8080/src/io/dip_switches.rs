@@ -0,0 +1,210 @@
+//! The Space Invaders DIP switches, read on input port 2.
+//!
+//! * bits 0 and 1 (DIP 3 and 5): bases per game, 3 to 6;
+//! * bit 3 (DIP 6): bonus base at 1500 points when off, 1000 when on;
+//! * bit 7 (DIP 7): coin info on the attract screen when off.
+//!
+//! Settings can be loaded from a config file of `key = value` lines, with
+//! blank lines and `#` comments ignored:
+//!
+//! ```text
+//! lives = 5
+//! bonus-life = 1000
+//! coin-info = off
+//! ```
+
+use failure::{bail, Error};
+use std::{fs, path::Path};
+
+const LIVES: u8 = 0x03;
+const BONUS_LIFE_1000: u8 = 0x08;
+const COIN_INFO_OFF: u8 = 0x80;
+/// Bits of port 2 set by the DIP switches.
+pub const MASK: u8 = LIVES | BONUS_LIFE_1000 | COIN_INFO_OFF;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DipSwitches {
+    lives: u8,
+    bonus_life: u16,
+    coin_info: bool,
+}
+
+impl Default for DipSwitches {
+    /// Three bases, a bonus base at 1500 and coin info shown: all switches
+    /// off.
+    fn default() -> DipSwitches {
+        DipSwitches {
+            lives: 3,
+            bonus_life: 1500,
+            coin_info: true,
+        }
+    }
+}
+
+impl DipSwitches {
+    /// Reads the settings from the bits of port 2.
+    pub fn from_bits(bits: u8) -> DipSwitches {
+        DipSwitches {
+            lives: 3 + (bits & LIVES),
+            bonus_life: match bits & BONUS_LIFE_1000 {
+                0 => 1500,
+                _ => 1000,
+            },
+            coin_info: bits & COIN_INFO_OFF == 0,
+        }
+    }
+
+    /// The bits the switches set on port 2, within `MASK`.
+    pub fn bits(&self) -> u8 {
+        let mut bits = self.lives - 3;
+        if self.bonus_life == 1000 {
+            bits |= BONUS_LIFE_1000;
+        }
+        if !self.coin_info {
+            bits |= COIN_INFO_OFF;
+        }
+        bits
+    }
+
+    pub fn lives(&self) -> u8 {
+        self.lives
+    }
+
+    /// #Errors
+    /// Fails unless `lives` is 3 to 6.
+    pub fn set_lives(&mut self, lives: u8) -> Result<(), Error> {
+        if !(3..=6).contains(&lives) {
+            bail!("lives must be 3 to 6, not {}", lives);
+        }
+        self.lives = lives;
+        Ok(())
+    }
+
+    /// Points scored for the bonus base.
+    pub fn bonus_life(&self) -> u16 {
+        self.bonus_life
+    }
+
+    /// #Errors
+    /// Fails unless `points` is 1000 or 1500.
+    pub fn set_bonus_life(&mut self, points: u16) -> Result<(), Error> {
+        if points != 1000 && points != 1500 {
+            bail!(
+                "the bonus life comes at 1000 or 1500 points, not {}",
+                points
+            );
+        }
+        self.bonus_life = points;
+        Ok(())
+    }
+
+    /// Whether the attract screen shows the coin info.
+    pub fn coin_info(&self) -> bool {
+        self.coin_info
+    }
+
+    pub fn set_coin_info(&mut self, coin_info: bool) {
+        self.coin_info = coin_info;
+    }
+
+    /// Changes one setting by name: `lives`, `bonus-life` or `coin-info`
+    /// (`on` or `off`).
+    ///
+    /// #Errors
+    /// Fails if the setting is unknown or the value out of range.
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), Error> {
+        let value = value.trim();
+        match key.trim() {
+            "lives" => match value.parse() {
+                Ok(lives) => self.set_lives(lives),
+                Err(_) => bail!("lives must be a number, not {}", value),
+            },
+            "bonus-life" => match value.parse() {
+                Ok(points) => self.set_bonus_life(points),
+                Err(_) => bail!("bonus-life must be a number, not {}", value),
+            },
+            "coin-info" => {
+                let coin_info = match value {
+                    "on" => true,
+                    "off" => false,
+                    _ => bail!("coin-info must be on or off, not {}", value),
+                };
+                self.set_coin_info(coin_info);
+                Ok(())
+            }
+            key => bail!("unknown DIP switch setting {}", key),
+        }
+    }
+
+    /// Parses a config file, starting from the default settings.
+    ///
+    /// #Errors
+    /// Fails on the first line that is not a valid setting.
+    pub fn parse(text: &str) -> Result<DipSwitches, Error> {
+        let mut switches = DipSwitches::default();
+        for (number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let result = match line.find('=') {
+                Some(i) => switches.set(&line[..i], &line[i + 1..]),
+                None => bail!("line {}: expected key = value: {}", number + 1, line),
+            };
+            if let Err(e) = result {
+                bail!("line {}: {}", number + 1, e);
+            }
+        }
+        Ok(switches)
+    }
+
+    /// #Errors
+    /// Fails if the file cannot be read or parsed.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<DipSwitches, Error> {
+        let path = path.as_ref();
+        match fs::read_to_string(path) {
+            Ok(text) => DipSwitches::parse(&text),
+            Err(e) => bail!("unable to read {}: {}", path.display(), e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DipSwitches;
+
+    #[test]
+    fn round_trips_every_setting() {
+        let mut switches = DipSwitches::default();
+        assert_eq!(switches.bits(), 0x00);
+        for lives in 3..=6 {
+            for &bonus_life in [1000, 1500].iter() {
+                for &coin_info in [false, true].iter() {
+                    switches.set_lives(lives).unwrap();
+                    switches.set_bonus_life(bonus_life).unwrap();
+                    switches.set_coin_info(coin_info);
+                    assert_eq!(DipSwitches::from_bits(switches.bits()), switches);
+                }
+            }
+        }
+        assert_eq!(switches.bits(), 0x03);
+        assert!(switches.set_lives(7).is_err());
+        assert!(switches.set_bonus_life(2000).is_err());
+        assert_eq!(switches.lives(), 6);
+    }
+
+    #[test]
+    fn parses_config_files() {
+        let text = "# QA: longest games\nlives = 6\n\ncoin-info=off\n";
+        let switches = DipSwitches::parse(text).unwrap();
+        assert_eq!(switches.lives(), 6);
+        assert_eq!(switches.bonus_life(), 1500);
+        assert!(!switches.coin_info());
+        assert_eq!(switches.bits(), 0x83);
+
+        let error = DipSwitches::parse("lives = 4\nlives = 2\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 2: lives must be 3 to 6, not 2");
+        assert!(DipSwitches::parse("bonus-life 1000").is_err());
+        assert!(DipSwitches::parse("colour = on").is_err());
+    }
+}
@@ -0,0 +1,127 @@
+//! The Space Invaders control panel.
+//!
+//! Every control closes a switch that pulls its input bit high while it is
+//! held:
+//!
+//! * port 1: bit 0 coin, bit 1 2P start, bit 2 1P start, bits 4 to 6 player
+//!   1 fire, left and right;
+//! * port 2: bit 2 tilt, bits 4 to 6 player 2 fire, left and right.
+//!
+//! Bit 3 of port 1 is tied high and the rest of port 2 carries DIP
+//! switches, which `BasicIO` keeps in its idle input values.
+
+/// Input latch of port 1.
+const PORT_1: usize = 1;
+/// Input latch of port 2.
+const PORT_2: usize = 2;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Button {
+    Coin,
+    Start1,
+    Start2,
+    Fire1,
+    Left1,
+    Right1,
+    Fire2,
+    Left2,
+    Right2,
+    Tilt,
+}
+
+impl Button {
+    pub const ALL: [Button; 10] = [
+        Button::Coin,
+        Button::Start1,
+        Button::Start2,
+        Button::Fire1,
+        Button::Left1,
+        Button::Right1,
+        Button::Fire2,
+        Button::Left2,
+        Button::Right2,
+        Button::Tilt,
+    ];
+
+    /// The input latch and bit the button pulls high.
+    fn bit(self) -> (usize, u8) {
+        match self {
+            Button::Coin => (PORT_1, 0x01),
+            Button::Start2 => (PORT_1, 0x02),
+            Button::Start1 => (PORT_1, 0x04),
+            Button::Fire1 => (PORT_1, 0x10),
+            Button::Left1 => (PORT_1, 0x20),
+            Button::Right1 => (PORT_1, 0x40),
+            Button::Tilt => (PORT_2, 0x04),
+            Button::Fire2 => (PORT_2, 0x10),
+            Button::Left2 => (PORT_2, 0x20),
+            Button::Right2 => (PORT_2, 0x40),
+        }
+    }
+}
+
+/// Buttons held down.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GamePad {
+    latches: [u8; 3],
+}
+
+impl GamePad {
+    pub fn new() -> GamePad {
+        GamePad::default()
+    }
+
+    pub fn press(&mut self, button: Button) {
+        self.set(button, true);
+    }
+
+    pub fn release(&mut self, button: Button) {
+        self.set(button, false);
+    }
+
+    pub fn set(&mut self, button: Button, pressed: bool) {
+        let (latch, bit) = button.bit();
+        match pressed {
+            true => self.latches[latch] |= bit,
+            false => self.latches[latch] &= !bit,
+        }
+    }
+
+    pub fn is_pressed(&self, button: Button) -> bool {
+        let (latch, bit) = button.bit();
+        self.latches[latch] & bit != 0
+    }
+
+    pub fn release_all(&mut self) {
+        self.latches = [0; 3];
+    }
+
+    /// Bits pulled high in input latch `latch`, 0 to 2.
+    pub fn bits(&self, latch: usize) -> u8 {
+        self.latches[latch]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Button, GamePad};
+
+    #[test]
+    fn sets_one_bit_per_button() {
+        let mut pad = GamePad::new();
+        for &button in Button::ALL.iter() {
+            pad.press(button);
+        }
+        assert_eq!(pad.bits(0), 0x00);
+        assert_eq!(pad.bits(1), 0x77);
+        assert_eq!(pad.bits(2), 0x74);
+
+        pad.release(Button::Coin);
+        pad.release(Button::Right2);
+        assert!(!pad.is_pressed(Button::Coin));
+        assert!(pad.is_pressed(Button::Start2));
+        assert_eq!((pad.bits(1), pad.bits(2)), (0x76, 0x34));
+        pad.release_all();
+        assert_eq!(pad, GamePad::new());
+    }
+}
@@ -0,0 +1,241 @@
+//! The Intel 8251A USART, with its serial lines wired to a `Console`.
+//!
+//! The USART takes two ports, told apart by C/D on A0: data at the even one
+//! and mode, command and status at the odd one. After a reset the first
+//! control write is the mode instruction. In synchronous mode one or two
+//! sync characters follow it, and then every control write is a command.
+//!
+//! Characters go to the console as soon as they are written, so the
+//! transmitter is always ready once enabled. A received character is
+//! waiting whenever the console has one. Line errors never happen, so the
+//! parity, overrun and framing error flags always read 0.
+
+use super::{Console, IO};
+
+use std::cell::{Cell, Ref, RefCell, RefMut};
+
+// Mode instruction bits.
+const BAUD_FACTOR: u8 = 0x03;
+const CHARACTER_LENGTH: u8 = 0x0c;
+/// In synchronous mode, a single sync character rather than two.
+const SINGLE_SYNC: u8 = 0x80;
+
+// Command instruction bits.
+const TXEN: u8 = 0x01;
+const DTR: u8 = 0x02;
+const RXE: u8 = 0x04;
+const RTS: u8 = 0x20;
+const INTERNAL_RESET: u8 = 0x40;
+const ENTER_HUNT: u8 = 0x80;
+
+// Status bits.
+pub const TXRDY: u8 = 0x01;
+pub const RXRDY: u8 = 0x02;
+pub const TXEMPTY: u8 = 0x04;
+pub const SYNDET: u8 = 0x40;
+pub const DSR: u8 = 0x80;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Control {
+    Mode,
+    /// Sync characters still to come.
+    Sync(u8),
+    Command,
+}
+
+pub struct I8251<C: Console> {
+    console: RefCell<C>,
+    control: Control,
+    mode: u8,
+    sync: [u8; 2],
+    command: u8,
+    /// Character written while the transmitter was disabled.
+    tx: Option<u8>,
+    /// Last character received, which the data register keeps returning
+    /// until a new one arrives.
+    rx: Cell<u8>,
+    /// Whether the hunt for sync characters is over.
+    sync_detected: bool,
+}
+
+impl<C: Console> I8251<C> {
+    pub fn new(console: C) -> I8251<C> {
+        I8251 {
+            console: RefCell::new(console),
+            control: Control::Mode,
+            mode: 0,
+            sync: [0; 2],
+            command: 0,
+            tx: None,
+            rx: Cell::new(0),
+            sync_detected: false,
+        }
+    }
+
+    pub fn console(&self) -> Ref<'_, C> {
+        self.console.borrow()
+    }
+
+    pub fn console_mut(&mut self) -> RefMut<'_, C> {
+        self.console.borrow_mut()
+    }
+
+    /// Returns the USART to waiting for a mode instruction, as the RESET pin
+    /// or an internal reset command does.
+    pub fn reset(&mut self) {
+        self.control = Control::Mode;
+        self.command = 0;
+        self.tx = None;
+        self.sync_detected = false;
+    }
+
+    /// The RxRDY pin: a character is waiting and the receiver is enabled.
+    pub fn rx_ready(&self) -> bool {
+        self.command & RXE != 0 && self.console.borrow_mut().status()
+    }
+
+    /// The TxRDY pin: the transmitter is enabled and can take a character.
+    pub fn tx_ready(&self) -> bool {
+        self.command & TXEN != 0 && self.tx.is_none()
+    }
+
+    /// The DTR and RTS outputs, which are active low on the chip but
+    /// reported here as true when asserted.
+    pub fn modem_control(&self) -> (bool, bool) {
+        (self.command & DTR != 0, self.command & RTS != 0)
+    }
+
+    fn synchronous(&self) -> bool {
+        self.mode & BAUD_FACTOR == 0
+    }
+
+    /// Mask of the bits in a character, 5 to 8 of them.
+    fn character_mask(&self) -> u8 {
+        0xff >> (3 - ((self.mode & CHARACTER_LENGTH) >> 2))
+    }
+
+    fn status(&self) -> u8 {
+        let mut status = DSR;
+        if self.tx.is_none() {
+            status |= TXRDY | TXEMPTY;
+        }
+        if self.rx_ready() {
+            status |= RXRDY;
+        }
+        if self.synchronous() && self.sync_detected {
+            status |= SYNDET;
+        }
+        status
+    }
+
+    fn read_data(&self) -> u8 {
+        if self.rx_ready() {
+            if let Some(c) = self.console.borrow_mut().read() {
+                self.rx.set(c & self.character_mask());
+            }
+        }
+        self.rx.get()
+    }
+
+    fn write_data(&mut self, value: u8) {
+        let value = value & self.character_mask();
+        match self.command & TXEN {
+            0 => self.tx = Some(value),
+            _ => self.console.get_mut().write(value),
+        }
+    }
+
+    fn write_control(&mut self, value: u8) {
+        match self.control {
+            Control::Mode => {
+                self.mode = value;
+                self.control = match (self.synchronous(), value & SINGLE_SYNC) {
+                    (false, _) => Control::Command,
+                    (true, 0) => Control::Sync(2),
+                    (true, _) => Control::Sync(1),
+                };
+            }
+            Control::Sync(left) => {
+                let count = if self.mode & SINGLE_SYNC != 0 { 1 } else { 2 };
+                self.sync[usize::from(count - left)] = value;
+                self.control = match left {
+                    1 => Control::Command,
+                    _ => Control::Sync(left - 1),
+                };
+            }
+            Control::Command => self.write_command(value),
+        }
+    }
+
+    fn write_command(&mut self, value: u8) {
+        if value & INTERNAL_RESET != 0 {
+            self.reset();
+            return;
+        }
+        self.command = value;
+        // Without a line to hunt on, sync is found straight away.
+        if value & ENTER_HUNT != 0 {
+            self.sync_detected = true;
+        }
+        if value & TXEN != 0 {
+            if let Some(c) = self.tx.take() {
+                self.console.get_mut().write(c);
+            }
+        }
+    }
+}
+
+impl<C: Console> IO for I8251<C> {
+    fn read_port(&self, port: u8) -> u8 {
+        match port & 1 {
+            0 => self.read_data(),
+            _ => self.status(),
+        }
+    }
+
+    fn write_port(&mut self, port: u8, value: u8) {
+        match port & 1 {
+            0 => self.write_data(value),
+            _ => self.write_control(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DSR, I8251, RXRDY, SYNDET, TXEMPTY, TXRDY};
+    use crate::io::{BufferConsole, IO};
+
+    #[test]
+    fn sends_and_receives_characters() {
+        let mut usart = I8251::new(BufferConsole::new("ab"));
+        // Asynchronous, 16x clock, 7 bits, two stop bits.
+        usart.write_port(0x41, 0xca);
+        usart.write_port(0x41, 0x00);
+        assert_eq!(usart.read_port(0x41), DSR | TXEMPTY | TXRDY);
+        // Nothing is sent until the transmitter is enabled.
+        usart.write_port(0x40, b'X' | 0x80);
+        assert_eq!(usart.read_port(0x41), DSR);
+        assert!(usart.console().output().is_empty());
+        usart.write_port(0x41, 0x27);
+        assert_eq!(usart.console().output(), b"X");
+        assert!(usart.tx_ready() && usart.rx_ready());
+        assert_eq!(usart.modem_control(), (true, true));
+
+        assert_eq!(usart.read_port(0x41), DSR | RXRDY | TXEMPTY | TXRDY);
+        assert_eq!(usart.read_port(0x40), b'a');
+        assert_eq!(usart.read_port(0x40), b'b');
+        assert_eq!(usart.read_port(0x41) & RXRDY, 0);
+        assert_eq!(usart.read_port(0x40), b'b');
+
+        // An internal reset takes a new mode: synchronous, 5 bits, one sync
+        // character.
+        usart.write_port(0x41, 0x40);
+        usart.write_port(0x41, 0x80);
+        usart.write_port(0x41, 0x16);
+        usart.write_port(0x41, 0x81);
+        assert_eq!(usart.read_port(0x41), DSR | SYNDET | TXEMPTY | TXRDY);
+        usart.write_port(0x40, 0xff);
+        assert_eq!(usart.console().output(), b"X\x1f");
+    }
+}
@@ -0,0 +1,414 @@
+//! The Intel 8253 programmable interval timer.
+//!
+//! The timer takes four ports, picked by A1 and A0: counters 0 to 2 and the
+//! control word register. All three counters count on one input clock,
+//! which `IO::clock` derives from CPU cycles. Each counter's gate is an
+//! input the board drives with `set_gate`, high unless told otherwise, and
+//! its OUT pin is read with `out`. Boards wire OUT to an interrupt
+//! controller by collecting its rising edges with `take_edges`.
+//!
+//! All six modes are modelled to the clock tick, including the tick a new
+//! count takes to load, except that mode 3 counts down by one rather than
+//! two, which makes no difference at OUT.
+
+use super::IO;
+use crate::pic::InterruptController;
+
+use std::cell::Cell;
+
+pub const COUNTERS: usize = 3;
+
+// Control word fields.
+const SELECT_COUNTER: u8 = 0xc0;
+const READ_LOAD: u8 = 0x30;
+const MODE: u8 = 0x0e;
+const BCD: u8 = 0x01;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Access {
+    Lsb,
+    Msb,
+    /// LSB then MSB.
+    Word,
+}
+
+#[derive(Clone, Debug)]
+struct Counter {
+    mode: u8,
+    bcd: bool,
+    access: Access,
+    /// Count register, as written.
+    initial: u16,
+    /// Counting element, as a binary value. A count of 0 loads as the full
+    /// 65536, or 10000 in BCD.
+    count: u32,
+    /// A count load is due on the next clock tick.
+    load: bool,
+    counting: bool,
+    /// In modes 4 and 5, whether the strobe has already been given.
+    strobed: bool,
+    out: bool,
+    gate: bool,
+    /// Rising edges of OUT not yet collected.
+    edges: u32,
+    /// LSB waiting for its MSB.
+    write_lsb: Option<u8>,
+    latch: Cell<Option<u16>>,
+    read_msb: Cell<bool>,
+}
+
+impl Default for Counter {
+    fn default() -> Counter {
+        Counter {
+            mode: 0,
+            bcd: false,
+            access: Access::Lsb,
+            initial: 0,
+            count: 0,
+            load: false,
+            counting: false,
+            strobed: false,
+            out: false,
+            gate: true,
+            edges: 0,
+            write_lsb: None,
+            latch: Cell::new(None),
+            read_msb: Cell::new(false),
+        }
+    }
+}
+
+fn from_bcd(value: u16) -> u32 {
+    (0..4).fold(0, |n, digit| {
+        n * 10 + u32::from(value >> (12 - 4 * digit) & 0x0f).min(9)
+    })
+}
+
+fn to_bcd(value: u32) -> u16 {
+    (0..4).fold(0, |n, digit| {
+        n | ((value / 10u32.pow(digit) % 10) as u16) << (4 * digit)
+    })
+}
+
+impl Counter {
+    fn modulus(&self) -> u32 {
+        match self.bcd {
+            true => 10000,
+            false => 0x10000,
+        }
+    }
+
+    /// The count loaded from the count register, 1 to the modulus.
+    fn reload(&self) -> u32 {
+        let count = match self.bcd {
+            true => from_bcd(self.initial),
+            false => u32::from(self.initial),
+        };
+        match count {
+            0 => self.modulus(),
+            count => count,
+        }
+    }
+
+    /// The counting element as the CPU reads it.
+    fn value(&self) -> u16 {
+        let count = self.count % self.modulus();
+        match self.bcd {
+            true => to_bcd(count),
+            false => count as u16,
+        }
+    }
+
+    fn set_out(&mut self, out: bool) {
+        if out && !self.out {
+            self.edges += 1;
+        }
+        self.out = out;
+    }
+
+    fn set_mode(&mut self, control: u8) {
+        // Modes 6 and 7 are modes 2 and 3.
+        self.mode = match (control & MODE) >> 1 {
+            mode @ 6..=7 => mode - 4,
+            mode => mode,
+        };
+        self.bcd = control & BCD != 0;
+        self.access = match (control & READ_LOAD) >> 4 {
+            1 => Access::Lsb,
+            2 => Access::Msb,
+            _ => Access::Word,
+        };
+        self.load = false;
+        self.counting = false;
+        self.write_lsb = None;
+        self.latch.set(None);
+        self.read_msb.set(false);
+        self.out = self.mode != 0;
+    }
+
+    fn latch(&self) {
+        if self.latch.get().is_none() {
+            self.latch.set(Some(self.value()));
+        }
+    }
+
+    fn read(&self) -> u16 {
+        self.latch.get().unwrap_or_else(|| self.value())
+    }
+
+    fn read_byte(&self) -> u8 {
+        let value = self.read();
+        let byte = match self.access {
+            Access::Lsb => value as u8,
+            Access::Msb => (value >> 8) as u8,
+            Access::Word if self.read_msb.get() => (value >> 8) as u8,
+            Access::Word => {
+                self.read_msb.set(true);
+                return value as u8;
+            }
+        };
+        self.read_msb.set(false);
+        self.latch.set(None);
+        byte
+    }
+
+    fn write_byte(&mut self, value: u8) {
+        self.initial = match (self.access, self.write_lsb.take()) {
+            (Access::Lsb, _) => u16::from(value),
+            (Access::Msb, _) => u16::from(value) << 8,
+            (Access::Word, Some(lsb)) => u16::from(value) << 8 | u16::from(lsb),
+            (Access::Word, None) => {
+                self.write_lsb = Some(value);
+                // Writing the first byte stops the count in mode 0.
+                if self.mode == 0 {
+                    self.counting = false;
+                }
+                return;
+            }
+        };
+        match self.mode {
+            0 => {
+                self.out = false;
+                self.load = true;
+            }
+            // The new count waits for the next period or trigger.
+            2 | 3 if self.counting => {}
+            2..=4 => self.load = true,
+            _ => {}
+        }
+    }
+
+    fn set_gate(&mut self, gate: bool) {
+        let rising = gate && !self.gate;
+        self.gate = gate;
+        match self.mode {
+            1 | 2 | 3 | 5 if rising => self.load = true,
+            2 | 3 if !gate => self.set_out(true),
+            _ => {}
+        }
+    }
+
+    fn tick(&mut self) {
+        if self.load {
+            self.load = false;
+            self.counting = true;
+            self.strobed = false;
+            self.count = self.reload();
+            match self.mode {
+                1 => self.out = false,
+                3 => self.set_out(true),
+                _ => {}
+            }
+            return;
+        }
+        if !self.counting || (!self.gate && matches!(self.mode, 0 | 2 | 3 | 4)) {
+            return;
+        }
+        if matches!(self.mode, 4 | 5) && !self.out {
+            self.set_out(true);
+        }
+        self.count = match self.count {
+            0 => self.modulus() - 1,
+            count => count - 1,
+        };
+        match self.mode {
+            0 | 1 if self.count == 0 => self.set_out(true),
+            2 if self.count == 1 => self.out = false,
+            2 if self.count == 0 => {
+                self.count = self.reload();
+                self.set_out(true);
+            }
+            3 => {
+                if self.count == 0 {
+                    self.count = self.reload();
+                }
+                let out = self.count > self.reload() / 2;
+                self.set_out(out);
+            }
+            4 | 5 if self.count == 0 && !self.strobed => {
+                self.strobed = true;
+                self.out = false;
+            }
+            _ => {}
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct I8253 {
+    counters: [Counter; COUNTERS],
+    cpu_hz: u64,
+    clock_hz: u64,
+    /// CPU cycles times `clock_hz` not yet turned into ticks.
+    remainder: u64,
+}
+
+impl I8253 {
+    /// Creates a timer whose counters run at `clock_hz` on a CPU running at
+    /// `cpu_hz`.
+    ///
+    /// #Panics
+    /// Panics if `cpu_hz` is 0.
+    pub fn new(cpu_hz: u64, clock_hz: u64) -> I8253 {
+        assert!(cpu_hz > 0, "the CPU clock cannot be 0 Hz");
+        I8253 {
+            counters: Default::default(),
+            cpu_hz,
+            clock_hz,
+            remainder: 0,
+        }
+    }
+
+    /// Clocks the counters directly, `ticks` times.
+    pub fn tick(&mut self, ticks: u64) {
+        for _ in 0..ticks {
+            for counter in self.counters.iter_mut() {
+                counter.tick();
+            }
+        }
+    }
+
+    pub fn out(&self, counter: usize) -> bool {
+        self.counters[counter].out
+    }
+
+    /// Drives the gate input of `counter`.
+    pub fn set_gate(&mut self, counter: usize, gate: bool) {
+        self.counters[counter].set_gate(gate);
+    }
+
+    /// Returns how many times OUT of `counter` has gone high since the last
+    /// call.
+    pub fn take_edges(&mut self, counter: usize) -> u32 {
+        std::mem::replace(&mut self.counters[counter].edges, 0)
+    }
+
+    fn write_control(&mut self, value: u8) {
+        let counter = usize::from(value >> 6);
+        if value & SELECT_COUNTER == SELECT_COUNTER {
+            // Read-back is an 8254 command; the 8253 ignores it.
+            return;
+        }
+        match value & READ_LOAD {
+            0 => self.counters[counter].latch(),
+            _ => self.counters[counter].set_mode(value),
+        }
+    }
+}
+
+impl IO for I8253 {
+    fn read_port(&self, port: u8) -> u8 {
+        match usize::from(port & 3) {
+            COUNTERS => 0xff,
+            counter => self.counters[counter].read_byte(),
+        }
+    }
+
+    fn write_port(&mut self, port: u8, value: u8) {
+        match usize::from(port & 3) {
+            COUNTERS => self.write_control(value),
+            counter => self.counters[counter].write_byte(value),
+        }
+    }
+
+    fn clock(&mut self, cycles: u64, _interrupts: &mut InterruptController) {
+        let elapsed = self.remainder + cycles * self.clock_hz;
+        self.remainder = elapsed % self.cpu_hz;
+        self.tick(elapsed / self.cpu_hz);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::I8253;
+    use crate::{io::IO, pic::InterruptController};
+
+    #[test]
+    fn counts_in_each_mode() {
+        let mut timer = I8253::new(2_000_000, 1_000_000);
+        let mut interrupts = InterruptController::default();
+        // Counter 0: mode 0, LSB only, count 3.
+        timer.write_port(3, 0x10);
+        timer.write_port(0, 3);
+        assert!(!timer.out(0));
+        timer.clock(6, &mut interrupts);
+        assert!(!timer.out(0));
+        timer.clock(3, &mut interrupts);
+        timer.clock(1, &mut interrupts);
+        assert!(timer.out(0));
+        assert_eq!(timer.take_edges(0), 1);
+        assert_eq!(timer.read_port(0), 0xff);
+
+        // Counter 1: mode 2, LSB then MSB, count 0x0104.
+        timer.write_port(3, 0x74);
+        timer.write_port(1, 0x04);
+        timer.write_port(1, 0x01);
+        timer.tick(1 + 3 * 0x104 - 1);
+        assert!(!timer.out(1));
+        assert_eq!(timer.take_edges(1), 2);
+        timer.tick(1);
+        assert_eq!(timer.take_edges(1), 1);
+        // Latching holds the count while the counter runs on.
+        timer.write_port(3, 0x40);
+        timer.tick(4);
+        assert_eq!(timer.read_port(1), 0x04);
+        assert_eq!(timer.read_port(1), 0x01);
+        assert_eq!(timer.read_port(1), 0x00);
+        assert_eq!(timer.read_port(1), 0x01);
+        // Gating stops the count and holds OUT high.
+        timer.set_gate(1, false);
+        timer.tick(0x104);
+        assert_eq!(timer.take_edges(1), 0);
+
+        // Counter 2: mode 3, BCD, count 5 is high 3 ticks and low 2.
+        timer.write_port(3, 0x97);
+        timer.write_port(2, 0x05);
+        let wave: Vec<_> = (0..11)
+            .map(|_| {
+                timer.tick(1);
+                timer.out(2)
+            })
+            .collect();
+        let expected = [1, 1, 1, 0, 0, 1, 1, 1, 0, 0, 1];
+        assert_eq!(wave, expected.iter().map(|&b| b == 1).collect::<Vec<_>>());
+        timer.write_port(3, 0x80);
+        assert_eq!(timer.read_port(2), 0x05);
+    }
+
+    #[test]
+    fn strobes_once_per_trigger() {
+        let mut timer = I8253::new(1, 1);
+        // Mode 5 waits for a rising gate, then strobes after the count.
+        timer.write_port(3, 0x1a);
+        timer.write_port(0, 2);
+        timer.tick(10);
+        assert_eq!(timer.take_edges(0), 0);
+        timer.set_gate(0, false);
+        timer.set_gate(0, true);
+        timer.tick(3);
+        assert!(!timer.out(0));
+        timer.tick(10);
+        assert!(timer.out(0));
+        assert_eq!(timer.take_edges(0), 1);
+    }
+}
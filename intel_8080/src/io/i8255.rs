@@ -0,0 +1,160 @@
+//! The Intel 8255A programmable peripheral interface.
+//!
+//! The PPI takes four ports, picked by A1 and A0: ports A, B and C and the
+//! control word register. A control word with bit 7 set picks the mode and
+//! direction of each group, group A being port A and the upper half of port
+//! C and group B port B and the lower half, and clears the output latches.
+//! With bit 7 clear it sets or resets one bit of port C.
+//!
+//! Mode 0 is modelled in full. In modes 1 and 2 the ports move data the
+//! same way, but the strobe, acknowledge and interrupt lines on port C are
+//! not driven, so boards using the handshake have to drive port C's inputs
+//! themselves.
+
+use super::IO;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Port {
+    A,
+    B,
+    C,
+}
+
+// Mode set control word bits.
+const MODE_SET: u8 = 0x80;
+const A_INPUT: u8 = 0x10;
+const C_UPPER_INPUT: u8 = 0x08;
+const B_INPUT: u8 = 0x02;
+const C_LOWER_INPUT: u8 = 0x01;
+
+#[derive(Clone, Debug)]
+pub struct I8255 {
+    control: u8,
+    /// Output latches of ports A, B and C.
+    latches: [u8; 3],
+    /// Levels driven onto the pins from outside.
+    inputs: [u8; 3],
+}
+
+impl Default for I8255 {
+    /// After a reset every port is an input in mode 0.
+    fn default() -> I8255 {
+        I8255 {
+            control: MODE_SET | A_INPUT | C_UPPER_INPUT | B_INPUT | C_LOWER_INPUT,
+            latches: [0; 3],
+            inputs: [0xff; 3],
+        }
+    }
+}
+
+impl I8255 {
+    pub fn new() -> I8255 {
+        I8255::default()
+    }
+
+    /// Mask of the bits of `port` that are inputs.
+    pub fn input_mask(&self, port: Port) -> u8 {
+        let control = self.control;
+        match port {
+            Port::A if control & A_INPUT != 0 => 0xff,
+            Port::B if control & B_INPUT != 0 => 0xff,
+            Port::A | Port::B => 0x00,
+            Port::C => {
+                let upper = if control & C_UPPER_INPUT != 0 {
+                    0xf0
+                } else {
+                    0
+                };
+                let lower = if control & C_LOWER_INPUT != 0 {
+                    0x0f
+                } else {
+                    0
+                };
+                upper | lower
+            }
+        }
+    }
+
+    /// Drives the pins of `port` from outside. Only the bits that are inputs
+    /// are seen by the CPU.
+    pub fn set_input(&mut self, port: Port, value: u8) {
+        self.inputs[port as usize] = value;
+    }
+
+    /// The levels the PPI drives onto the pins of `port`. Bits that are
+    /// inputs read 1, as the lines are pulled up.
+    pub fn output(&self, port: Port) -> u8 {
+        self.latches[port as usize] | self.input_mask(port)
+    }
+
+    fn read(&self, port: Port) -> u8 {
+        let mask = self.input_mask(port);
+        let i = port as usize;
+        self.inputs[i] & mask | self.latches[i] & !mask
+    }
+
+    fn write_control(&mut self, value: u8) {
+        if value & MODE_SET != 0 {
+            self.control = value;
+            self.latches = [0; 3];
+        } else {
+            let bit = 1 << ((value >> 1) & 7);
+            match value & 1 {
+                0 => self.latches[Port::C as usize] &= !bit,
+                _ => self.latches[Port::C as usize] |= bit,
+            }
+        }
+    }
+}
+
+impl IO for I8255 {
+    fn read_port(&self, port: u8) -> u8 {
+        match port & 3 {
+            0 => self.read(Port::A),
+            1 => self.read(Port::B),
+            2 => self.read(Port::C),
+            // The control word cannot be read back.
+            _ => 0xff,
+        }
+    }
+
+    fn write_port(&mut self, port: u8, value: u8) {
+        match port & 3 {
+            0 => self.latches[Port::A as usize] = value,
+            1 => self.latches[Port::B as usize] = value,
+            2 => self.latches[Port::C as usize] = value,
+            _ => self.write_control(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Port, I8255};
+    use crate::io::IO;
+
+    #[test]
+    fn moves_data_both_ways() {
+        let mut ppi = I8255::new();
+        ppi.set_input(Port::A, 0x12);
+        ppi.set_input(Port::C, 0x5a);
+        assert_eq!(ppi.read_port(0xf4), 0x12);
+        assert_eq!(ppi.output(Port::B), 0xff);
+
+        // A out, B in, C upper in and lower out.
+        ppi.write_port(0xf7, 0x8a);
+        ppi.write_port(0xf4, 0x34);
+        ppi.write_port(0xf6, 0xff);
+        assert_eq!(ppi.output(Port::A), 0x34);
+        assert_eq!(ppi.read_port(0xf4), 0x34);
+        assert_eq!(ppi.read_port(0xf6), 0x5f);
+        ppi.write_port(0xf7, 0x02);
+        ppi.write_port(0xf7, 0x0f);
+        assert_eq!(ppi.output(Port::C), 0xfd);
+        assert_eq!(ppi.read_port(0xf6), 0x5d);
+
+        // A new mode clears the latches.
+        ppi.write_port(0xf7, 0x80);
+        assert_eq!(ppi.output(Port::C), 0x00);
+    }
+}
@@ -0,0 +1,475 @@
+//! The Intel 8259A programmable interrupt controller, in 8080 mode.
+//!
+//! The PIC takes two ports, told apart by A0. On interrupt acknowledge it
+//! puts a CALL to the vector of the highest priority request on the bus.
+//! Vectors are 4 or 8 bytes apart in a 32 or 64 byte table whose address is
+//! set by ICW1 and ICW2.
+//!
+//! Cascading is accepted but not modelled: ICW3 is stored and ignored, and
+//! the PIC always acts as a master with no slaves. The 8086 mode of ICW4 is
+//! not supported, as the CPU could not take its vectors.
+
+use super::IO;
+use crate::instruction::{opcode::Opcode, Instruction};
+
+use log::warn;
+
+// ICW1 bits.
+const ICW1: u8 = 0x10;
+const IC4: u8 = 0x01;
+const SNGL: u8 = 0x02;
+/// Call address interval of 4 rather than 8.
+const ADI: u8 = 0x04;
+const LTIM: u8 = 0x08;
+// ICW4 bits.
+const UPM: u8 = 0x01;
+const AEOI: u8 = 0x02;
+// OCW3 bits.
+const OCW3: u8 = 0x08;
+const READ_REGISTER: u8 = 0x02;
+const READ_ISR: u8 = 0x01;
+const POLL: u8 = 0x04;
+const ESMM: u8 = 0x40;
+const SMM: u8 = 0x20;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Init {
+    /// Waiting for ICW1.
+    Uninitialized,
+    Icw2,
+    Icw3,
+    Icw4,
+    Ready,
+}
+
+#[derive(Clone, Debug)]
+pub struct I8259 {
+    init: Init,
+    icw1: u8,
+    /// High byte of the vector addresses.
+    icw2: u8,
+    icw4: u8,
+    irr: u8,
+    isr: u8,
+    imr: u8,
+    /// Levels of the IR inputs, for edge detection.
+    lines: u8,
+    /// The IR line with the lowest priority; the next one round has the
+    /// highest.
+    lowest: u8,
+    read_isr: bool,
+    poll: bool,
+    special_mask: bool,
+    rotate_on_aeoi: bool,
+}
+
+impl Default for I8259 {
+    fn default() -> I8259 {
+        I8259 {
+            init: Init::Uninitialized,
+            icw1: 0,
+            icw2: 0,
+            icw4: 0,
+            irr: 0,
+            isr: 0,
+            imr: 0,
+            lines: 0,
+            lowest: 7,
+            read_isr: false,
+            poll: false,
+            special_mask: false,
+            rotate_on_aeoi: false,
+        }
+    }
+}
+
+impl I8259 {
+    pub fn new() -> I8259 {
+        I8259::default()
+    }
+
+    /// Drives IR input `line`. In edge triggered mode a request is latched
+    /// when the line goes high; in level triggered mode the request follows
+    /// the line.
+    pub fn set_irq(&mut self, line: u8, high: bool) {
+        let bit = 1 << (line & 7);
+        let was_high = self.lines & bit != 0;
+        if high {
+            self.lines |= bit;
+            if self.icw1 & LTIM != 0 || !was_high {
+                self.irr |= bit;
+            }
+        } else {
+            self.lines &= !bit;
+            if self.icw1 & LTIM != 0 {
+                self.irr &= !bit;
+            }
+        }
+    }
+
+    /// Pulses IR input `line` high and low again, as a device strobing its
+    /// interrupt output does.
+    pub fn pulse(&mut self, line: u8) {
+        self.set_irq(line, true);
+        self.set_irq(line, false);
+    }
+
+    /// Whether the INT output is asserted.
+    pub fn interrupt(&self) -> bool {
+        self.next_request().is_some()
+    }
+
+    pub fn irr(&self) -> u8 {
+        self.irr
+    }
+
+    pub fn isr(&self) -> u8 {
+        self.isr
+    }
+
+    pub fn imr(&self) -> u8 {
+        self.imr
+    }
+
+    /// Interrupt acknowledge: moves the highest priority request in service
+    /// and returns the CALL to its vector.
+    pub fn acknowledge(&mut self) -> Option<Instruction> {
+        let line = self.take_request()?;
+        let low = match self.icw1 & ADI {
+            0 => self.icw1 & 0xc0 | line << 3,
+            _ => self.icw1 & 0xe0 | line << 2,
+        };
+        let addr = u16::from(self.icw2) << 8 | u16::from(low);
+        Some(Instruction::new_trinary(Opcode::CALL, addr).unwrap())
+    }
+
+    /// IR lines in priority order, highest first.
+    fn priorities(&self) -> impl Iterator<Item = u8> {
+        let first = self.lowest + 1;
+        (0..8).map(move |i| (first + i) & 7)
+    }
+
+    /// The highest priority request that may interrupt what is in service.
+    fn next_request(&self) -> Option<u8> {
+        if self.init != Init::Ready {
+            return None;
+        }
+        let requests = self.irr & !self.imr;
+        for line in self.priorities() {
+            let bit = 1 << line;
+            // In special mask mode, masked levels in service do not hold
+            // back the others.
+            if self.isr & bit != 0 && !(self.special_mask && self.imr & bit != 0) {
+                return None;
+            }
+            if requests & bit != 0 {
+                return Some(line);
+            }
+        }
+        None
+    }
+
+    fn take_request(&mut self) -> Option<u8> {
+        let line = self.next_request()?;
+        let bit = 1 << line;
+        if self.icw1 & LTIM == 0 {
+            self.irr &= !bit;
+        }
+        if self.icw4 & AEOI != 0 {
+            if self.rotate_on_aeoi {
+                self.lowest = line;
+            }
+        } else {
+            self.isr |= bit;
+        }
+        Some(line)
+    }
+
+    /// The highest priority level in service.
+    fn highest_in_service(&self) -> Option<u8> {
+        self.priorities().find(|&line| self.isr & 1 << line != 0)
+    }
+
+    fn write_command(&mut self, value: u8) {
+        if value & ICW1 != 0 {
+            self.icw1 = value;
+            self.icw4 = 0;
+            self.imr = 0;
+            self.isr = 0;
+            self.irr = 0;
+            self.lowest = 7;
+            self.special_mask = false;
+            self.read_isr = false;
+            self.poll = false;
+            self.init = Init::Icw2;
+        } else if value & OCW3 != 0 {
+            if value & READ_REGISTER != 0 {
+                self.read_isr = value & READ_ISR != 0;
+            }
+            self.poll = value & POLL != 0;
+            if value & ESMM != 0 {
+                self.special_mask = value & SMM != 0;
+            }
+        } else {
+            self.write_ocw2(value);
+        }
+    }
+
+    /// End of interrupt and priority rotation commands.
+    fn write_ocw2(&mut self, value: u8) {
+        let level = value & 7;
+        match value >> 5 {
+            // Rotate in automatic EOI mode, clear and set.
+            0b000 => self.rotate_on_aeoi = false,
+            0b100 => self.rotate_on_aeoi = true,
+            // Non-specific EOI, and with rotation.
+            0b001 | 0b101 => {
+                if let Some(line) = self.highest_in_service() {
+                    self.isr &= !(1 << line);
+                    if value & 0x80 != 0 {
+                        self.lowest = line;
+                    }
+                }
+            }
+            // Specific EOI, and with rotation.
+            0b011 | 0b111 => {
+                self.isr &= !(1 << level);
+                if value & 0x80 != 0 {
+                    self.lowest = level;
+                }
+            }
+            // Set priority.
+            0b110 => self.lowest = level,
+            _ => {}
+        }
+    }
+
+    fn write_data(&mut self, value: u8) {
+        self.init = match self.init {
+            Init::Icw2 => {
+                self.icw2 = value;
+                match (self.icw1 & SNGL, self.icw1 & IC4) {
+                    (0, _) => Init::Icw3,
+                    (_, 0) => Init::Ready,
+                    _ => Init::Icw4,
+                }
+            }
+            Init::Icw3 => match self.icw1 & IC4 {
+                0 => Init::Ready,
+                _ => Init::Icw4,
+            },
+            Init::Icw4 => {
+                if value & UPM != 0 {
+                    warn!("8259 set to 8086 mode, which the 8080 cannot use");
+                }
+                self.icw4 = value;
+                Init::Ready
+            }
+            Init::Uninitialized | Init::Ready => {
+                self.imr = value;
+                self.init
+            }
+        };
+    }
+
+    /// Reads the status register selected by OCW3, or the poll result if a
+    /// poll command was given, which acknowledges the request as INTA
+    /// would.
+    pub fn read(&mut self, port: u8) -> u8 {
+        if port & 1 != 0 {
+            return self.imr;
+        }
+        if self.poll {
+            self.poll = false;
+            return match self.take_request() {
+                Some(line) => 0x80 | line,
+                None => 0x00,
+            };
+        }
+        match self.read_isr {
+            true => self.isr,
+            false => self.irr,
+        }
+    }
+}
+
+impl IO for I8259 {
+    /// Reading the status registers has no side effects, except in poll
+    /// mode, which needs `I8259::read`.
+    fn read_port(&self, port: u8) -> u8 {
+        match (port & 1, self.read_isr) {
+            (1, _) => self.imr,
+            (_, true) => self.isr,
+            (_, false) => self.irr,
+        }
+    }
+
+    fn write_port(&mut self, port: u8, value: u8) {
+        match port & 1 {
+            0 => self.write_command(value),
+            _ => self.write_data(value),
+        }
+    }
+
+    fn acknowledge_interrupt(&mut self) -> Option<Instruction> {
+        self.acknowledge()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::I8259;
+    use crate::{
+        instruction::{opcode::Opcode, Instruction},
+        interconnect::Interconnect,
+        io::{i8253::I8253, IO},
+        loader,
+        mmu::Ram,
+        pic::InterruptController,
+        Emulator,
+    };
+
+    fn call(addr: u16) -> Option<Instruction> {
+        Some(Instruction::new_trinary(Opcode::CALL, addr).unwrap())
+    }
+
+    #[test]
+    fn vectors_by_priority() {
+        let mut pic = I8259::new();
+        pic.pulse(3);
+        assert_eq!(pic.acknowledge(), None);
+
+        // Single, interval 4, table at 0x1020.
+        pic.write_port(0, 0x36);
+        pic.write_port(1, 0x10);
+        pic.write_port(1, 0b0000_0100);
+        assert_eq!(pic.imr(), 0x04);
+
+        pic.pulse(2);
+        assert!(!pic.interrupt());
+        pic.pulse(5);
+        pic.pulse(3);
+        assert_eq!(pic.irr(), 0x2c);
+        assert_eq!(pic.acknowledge(), call(0x102c));
+        assert_eq!(pic.isr(), 0x08);
+        // IR5 waits for the EOI of the higher priority IR3.
+        assert_eq!(pic.acknowledge(), None);
+        pic.write_port(0, 0x20);
+        assert_eq!(pic.acknowledge(), call(0x1034));
+
+        // Unmasking IR2 lets it in ahead of IR5.
+        pic.write_port(1, 0x00);
+        assert_eq!(pic.acknowledge(), call(0x1028));
+        assert_eq!(pic.isr(), 0x24);
+        pic.write_port(0, 0x0b);
+        assert_eq!(pic.read_port(0), 0x24);
+        pic.write_port(0, 0x62);
+        assert_eq!(pic.read_port(0), 0x20);
+    }
+
+    #[test]
+    fn polls_and_rotates() {
+        let mut pic = I8259::new();
+        // Interval 8, level triggered, ICW4 with automatic EOI.
+        pic.write_port(0, 0x1b);
+        pic.write_port(1, 0x00);
+        pic.write_port(1, 0x02);
+        pic.set_irq(1, true);
+        pic.set_irq(6, true);
+        pic.write_port(0, 0x0c);
+        assert_eq!(pic.read(0), 0x81);
+        assert_eq!(pic.isr(), 0x00);
+
+        // Rotating makes IR1 the lowest priority, so IR6 goes first.
+        pic.write_port(0, 0xc1);
+        assert_eq!(pic.acknowledge(), call(0x0030));
+        pic.set_irq(6, false);
+        assert_eq!(pic.acknowledge(), call(0x0008));
+    }
+
+    /// A timer whose counter 0 drives IR0, with the PIC at 0x10 and the
+    /// timer at 0x20.
+    struct Board {
+        pic: I8259,
+        pit: I8253,
+    }
+
+    impl IO for Board {
+        fn read_port(&self, port: u8) -> u8 {
+            match port & 0xf0 {
+                0x10 => self.pic.read_port(port),
+                0x20 => self.pit.read_port(port),
+                _ => 0xff,
+            }
+        }
+
+        fn write_port(&mut self, port: u8, value: u8) {
+            match port & 0xf0 {
+                0x10 => self.pic.write_port(port, value),
+                0x20 => self.pit.write_port(port, value),
+                _ => {}
+            }
+        }
+
+        fn clock(&mut self, cycles: u64, interrupts: &mut InterruptController) {
+            self.pit.clock(cycles, interrupts);
+            for _ in 0..self.pit.take_edges(0) {
+                self.pic.pulse(0);
+            }
+        }
+
+        fn acknowledge_interrupt(&mut self) -> Option<Instruction> {
+            self.pic.acknowledge()
+        }
+    }
+
+    #[test]
+    fn timer_interrupts_the_cpu() {
+        let program = [
+            0x31, 0x00, 0x10, // LXI SP,0x1000
+            0x3e, 0x56, //       MVI A,0x56    ICW1: vectors at 0x40, 4 apart
+            0xd3, 0x10, //       OUT 0x10
+            0x3e, 0x00, //       MVI A,0x00    ICW2
+            0xd3, 0x11, //       OUT 0x11
+            0x3e, 0xfe, //       MVI A,0xfe    only IR0 unmasked
+            0xd3, 0x11, //       OUT 0x11
+            0x3e, 0x14, //       MVI A,0x14    counter 0 in mode 2
+            0xd3, 0x23, //       OUT 0x23
+            0x3e, 0x64, //       MVI A,100
+            0xd3, 0x20, //       OUT 0x20
+            0x06, 0x00, //       MVI B,0
+            0xfb, //             EI
+            0x76, //             HLT
+            0x78, //             MOV A,B
+            0xfe, 0x03, //       CPI 3
+            0xc2, 0x1a, 0x00, // JNZ 0x001a
+            0xf3, //             DI
+            0x76, //             HLT
+        ];
+        let handler = [
+            0x04, //       INR B
+            0x3e, 0x20, // MVI A,0x20    non-specific EOI
+            0xd3, 0x10, // OUT 0x10
+            0xfb, //       EI
+            0xc9, //       RET
+        ];
+        let mut ram = Ram::new();
+        loader::load_binary(&mut ram, &program, 0x0000, 0x0000).unwrap();
+        loader::load_binary(&mut ram, &handler, 0x0040, 0x0000).unwrap();
+        let board = Board {
+            pic: I8259::new(),
+            pit: I8253::new(2_000_000, 2_000_000),
+        };
+        let mut emulator = Emulator::with_interconnect(Interconnect::from_parts(ram, board));
+        for _ in 0..10_000 {
+            if emulator.cpu().halted() && !emulator.cpu().interrupts_enabled() {
+                break;
+            }
+            emulator.try_step().unwrap();
+        }
+        assert!(emulator.cpu().halted());
+        assert!(!emulator.cpu().interrupts_enabled());
+        assert_eq!(emulator.cpu().registers().b, 3);
+        assert!((300..400).contains(&emulator.cpu().cycles()));
+    }
+}
@@ -1,9 +1,18 @@
+pub mod altair;
+pub mod coverage;
+pub mod cpm;
 pub mod i8080;
 pub mod instruction;
 pub mod interconnect;
 pub mod io;
+pub mod loader;
 pub mod mmu;
 pub mod pic;
+pub mod profiler;
+pub mod rom_set;
+pub mod symbols;
+pub mod trace;
+pub mod video;
 
 use log::error;
 
@@ -12,26 +21,67 @@ use self::{
     instruction::{Instruction, Opcode},
     interconnect::Interconnect,
     io::{basic_io::BasicIO, IO},
-    mmu::{basic_mmu::BasicMMU, Mmu, Rom},
+    mmu::{basic_mmu::BasicMMU, MemoryAccessKind, MemoryObserver, Mmu, Rom},
+    trace::{Recorder, TraceRecord, Tracer},
+    video::{Beam, Frame},
 };
 
-use failure::Error;
+use failure::{bail, Error};
+use std::cell::RefCell;
+
+/// Cycles the IO hardware is clocked for each step while the CPU is halted.
+const HALT_CYCLES: u64 = 4;
 
 pub struct Emulator<T: Mmu, U: IO> {
     cpu: I8080,
     pub interconnect: Interconnect<T, U>,
+    tracers: Vec<Box<dyn Tracer>>,
+    steps: u64,
 }
 
 impl Emulator<BasicMMU, BasicIO> {
-    pub fn new<U: Into<Rom>>(rom: U) -> Emulator<BasicMMU, BasicIO> {
-        Emulator {
-            cpu: I8080::new(),
-            interconnect: Interconnect::new(rom),
+    /// #Errors
+    /// Fails if `rom` does not fit in the Space Invaders memory map.
+    pub fn new<U: Into<Rom>>(rom: U) -> Result<Emulator<BasicMMU, BasicIO>, Error> {
+        Ok(Emulator::with_interconnect(Interconnect::new(rom)?))
+    }
+}
+
+impl<T: Mmu> Emulator<T, BasicIO> {
+    /// Runs to the end of the current video frame, so from the start of one
+    /// frame it runs exactly one. The beam raises the board's interrupts on
+    /// the way.
+    ///
+    /// #Errors
+    /// Fails if an instruction fails or the CPU runs off the end of the ROM.
+    pub fn run_frame(&mut self) -> Result<(), Error> {
+        let frame = self.beam().frame();
+        while self.beam().frame() == frame {
+            if !self.cpu.halted() && self.cpu.pc() as usize >= self.mmu().rom_len() {
+                bail!("PC 0x{:04x} is past the end of the ROM", self.cpu.pc());
+            }
+            self.try_step()?;
         }
+        Ok(())
+    }
+
+    /// The video beam, for the frame count and the line it is on.
+    pub fn beam(&self) -> &Beam {
+        self.interconnect.io.beam()
     }
 }
 
 impl<T: Mmu, U: IO> Emulator<T, U> {
+    /// Creates an emulator for a board with its own memory and IO hardware.
+    pub fn with_interconnect(interconnect: Interconnect<T, U>) -> Emulator<T, U> {
+        Emulator {
+            cpu: I8080::new(),
+            interconnect,
+            tracers: Vec::new(),
+            steps: 0,
+        }
+    }
+
     pub fn step(&mut self) {
         if let Err(e) = self.try_step() {
             error!("{}", e);
@@ -39,16 +89,14 @@ impl<T: Mmu, U: IO> Emulator<T, U> {
     }
 
     pub fn try_step(&mut self) -> Result<(), Error> {
-        if self.cpu.interrupts_enabled() {
-            if let Some(instruction) = self.interconnect.interrupt_controller.consume_interrupt() {
-                self.cpu
-                    .emulate_instruction(instruction, &mut self.interconnect, true)?;
-            }
+        let cycles = self.cpu.cycles();
+        if let Some(instruction) = self.pending_interrupt() {
+            self.execute(instruction, None)?;
         }
-        if let Some(instruction) = self.next_instruction() {
-            self.cpu
-                .emulate_instruction(instruction, &mut self.interconnect, false)?;
+        if let Some((instruction, bytes)) = self.next_instruction() {
+            self.execute(instruction, Some(bytes))?;
         }
+        self.clock(cycles);
         Ok(())
     }
 
@@ -58,41 +106,145 @@ impl<T: Mmu, U: IO> Emulator<T, U> {
         }
     }
 
+    /// Runs until the CPU halts or leaves the ROM.
     pub fn try_run(&mut self) -> Result<(), Error> {
-        if self.cpu.interrupts_enabled() {
-            if let Some(instruction) = self.interconnect.interrupt_controller.consume_interrupt() {
-                self.cpu
-                    .emulate_instruction(instruction, &mut self.interconnect, true)?;
+        loop {
+            let cycles = self.cpu.cycles();
+            if let Some(instruction) = self.pending_interrupt() {
+                self.execute(instruction, None)?;
+            }
+            match self.next_instruction() {
+                Some((instruction, bytes)) => self.execute(instruction, Some(bytes))?,
+                None => return Ok(()),
             }
+            self.clock(cycles);
+        }
+    }
+
+    /// The interrupt the CPU takes next, if interrupts are enabled and the
+    /// interconnect's interrupt controller has one waiting.
+    fn pending_interrupt(&mut self) -> Option<Instruction> {
+        if !self.cpu.interrupts_enabled() {
+            return None;
         }
-        while let Some(instruction) = self.next_instruction() {
+        let Interconnect {
+            io,
+            interrupt_controller,
+            ..
+        } = &mut self.interconnect;
+        interrupt_controller.acknowledge(io)
+    }
+
+    /// Clocks the IO hardware for the cycles taken since `start`. A halted
+    /// CPU takes none, but time still passes for the hardware that will
+    /// wake it.
+    fn clock(&mut self, start: u64) {
+        let cycles = match self.cpu.cycles() - start {
+            0 if self.cpu.halted() => HALT_CYCLES,
+            cycles => cycles,
+        };
+        let Interconnect {
+            io,
+            interrupt_controller,
+            ..
+        } = &mut self.interconnect;
+        io.clock(cycles, interrupt_controller);
+    }
+
+    /// Registers a tracer to receive a `TraceRecord` for every instruction
+    /// executed from now on.
+    pub fn add_tracer<V: Tracer + 'static>(&mut self, tracer: V) {
+        self.tracers.push(Box::new(tracer));
+    }
+
+    /// Removes all registered tracers.
+    pub fn clear_tracers(&mut self) {
+        self.tracers.clear();
+    }
+
+    /// Registers an observer to be told about every memory access made from
+    /// now on.
+    pub fn add_observer<V: MemoryObserver + 'static>(&mut self, observer: V) {
+        self.interconnect.mmu.add_observer(observer);
+    }
+
+    /// Removes all registered memory observers.
+    pub fn clear_observers(&mut self) {
+        self.interconnect.mmu.clear_observers();
+    }
+
+    /// Executes an instruction fetched as `bytes`, or injected by an
+    /// interrupt if there are none.
+    fn execute(&mut self, instruction: Instruction, bytes: Option<[u8; 3]>) -> Result<(), Error> {
+        let is_interrupt = bytes.is_none();
+        if self.tracers.is_empty() {
             self.cpu
-                .emulate_instruction(instruction, &mut self.interconnect, false)?
+                .emulate_instruction(instruction, &mut self.interconnect, is_interrupt)?;
+            self.steps += 1;
+            return Ok(());
+        }
+
+        let registers = self.cpu.registers();
+        let cycles = self.cpu.cycles();
+        let bytes = match bytes {
+            Some(bytes) => bytes[..instruction.len() as usize].to_vec(),
+            None => instruction.encode()?,
+        };
+        let accesses = RefCell::new(Vec::new());
+        {
+            let Interconnect { mmu, io, .. } = &mut self.interconnect;
+            self.cpu.execute(
+                instruction,
+                &mut Recorder::new(mmu, &accesses),
+                &mut Recorder::new(io, &accesses),
+                is_interrupt,
+            )?;
+        }
+        let record = TraceRecord {
+            step: self.steps,
+            pc: registers.pc,
+            bytes,
+            instruction,
+            interrupt: is_interrupt,
+            registers,
+            cycles: (self.cpu.cycles() - cycles) as u8,
+            accesses: accesses.into_inner(),
+        };
+        self.steps += 1;
+        for tracer in self.tracers.iter_mut() {
+            tracer.trace(&record)?;
         }
         Ok(())
     }
 
-    fn next_instruction(&self) -> Option<Instruction> {
+    /// Fetches the instruction at PC, along with the bytes it was read
+    /// from. Each byte is read once, as an opcode fetch or an operand.
+    fn next_instruction(&self) -> Option<(Instruction, [u8; 3])> {
         use self::instruction::opcode::OpcodeSize;
-        if (self.cpu.pc() as usize) >= self.mmu().rom_len() {
-            None
-        } else {
-            let opcode = Opcode::from(self.mmu().read_byte(self.cpu.pc()));
-            let instruction = match opcode.size() {
-                OpcodeSize::Binary => {
-                    let data = self.mmu().read_byte(self.cpu.pc() + 1);
-                    Instruction::new_binary(opcode, data).unwrap()
-                }
-                OpcodeSize::Trinary => {
-                    let data_low = self.mmu().read_byte(self.cpu.pc() + 1) as u16;
-                    let data_high = self.mmu().read_byte(self.cpu.pc() + 2) as u16;
-                    let addr = (data_high << 8) | data_low;
-                    Instruction::new_trinary(opcode, addr).unwrap()
-                }
-                OpcodeSize::Unary => Instruction::new_unary(opcode).unwrap(),
-            };
-            Some(instruction)
+        if self.cpu.halted() || (self.cpu.pc() as usize) >= self.mmu().rom_len() {
+            return None;
+        }
+        let mmu = &self.interconnect.mmu;
+        let pc = self.cpu.pc();
+        let mut bytes = [mmu.read_access(MemoryAccessKind::Fetch, pc), 0, 0];
+        let opcode = Opcode::from(bytes[0]);
+        let operands = match opcode.size() {
+            OpcodeSize::Unary => 0,
+            OpcodeSize::Binary => 1,
+            OpcodeSize::Trinary => 2,
+        };
+        for (i, byte) in bytes.iter_mut().enumerate().take(operands + 1).skip(1) {
+            *byte = mmu.read_access(MemoryAccessKind::Operand, pc.wrapping_add(i as u16));
         }
+        let instruction = match opcode.size() {
+            OpcodeSize::Binary => Instruction::new_binary(opcode, bytes[1]),
+            OpcodeSize::Trinary => {
+                let addr = u16::from(bytes[2]) << 8 | u16::from(bytes[1]);
+                Instruction::new_trinary(opcode, addr)
+            }
+            OpcodeSize::Unary => Instruction::new_unary(opcode),
+        };
+        Some((instruction.unwrap(), bytes))
     }
 
     pub fn cpu(&self) -> &I8080 {
@@ -110,4 +262,10 @@ impl<T: Mmu, U: IO> Emulator<T, U> {
     pub fn mmu_mut(&mut self) -> &mut impl Mmu {
         &mut self.interconnect.mmu
     }
+
+    /// The picture on screen, rendered from the Midway video RAM. Reading
+    /// the screen is not a memory access, so observers are not told.
+    pub fn frame(&self) -> Frame {
+        Frame::from_mmu(&*self.interconnect.mmu)
+    }
 }
@@ -0,0 +1,312 @@
+//! Loading programs into memory.
+//!
+//! Programs come either as Intel HEX files, which carry their own load
+//! addresses, or as raw binaries placed at an address given by the user. Both
+//! are written through `Mmu::load_byte`, so they can fill ROM regions as well
+//! as RAM.
+//!
+//! Intel HEX start addresses are taken from a start segment (03) or start
+//! linear (05) record or, as written by Intel's 8080 assemblers, from the
+//! address field of the end of file record. `write_hex` uses the latter.
+
+use crate::mmu::Mmu;
+
+use failure::{bail, format_err, Error};
+use std::{fs, io::Write, path::Path};
+
+/// Number of data bytes `write_hex` puts in each record.
+const RECORD_LEN: usize = 16;
+
+/// What a loader put in memory.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Loaded {
+    /// Entry point of the program, if it has one.
+    pub start: Option<u16>,
+    /// Number of bytes written.
+    pub len: usize,
+    /// Lowest and highest addresses written, if any.
+    pub range: Option<(u16, u16)>,
+}
+
+impl Loaded {
+    fn store<T: Mmu>(&mut self, mmu: &mut T, addr: u16, value: u8) {
+        mmu.load_byte(addr, value);
+        self.len += 1;
+        self.range = match self.range {
+            Some((low, high)) => Some((low.min(addr), high.max(addr))),
+            None => Some((addr, addr)),
+        };
+    }
+}
+
+/// Places `data` at `addr`, with execution to begin at `start`.
+///
+/// #Errors
+/// Fails if the data runs past 0xffff.
+pub fn load_binary<T: Mmu>(
+    mmu: &mut T,
+    data: &[u8],
+    addr: u16,
+    start: u16,
+) -> Result<Loaded, Error> {
+    if addr as usize + data.len() > 0x10000 {
+        bail!(
+            "{} bytes loaded at 0x{:04x} run past 0xffff",
+            data.len(),
+            addr
+        );
+    }
+    let mut loaded = Loaded {
+        start: Some(start),
+        ..Loaded::default()
+    };
+    for (i, &value) in data.iter().enumerate() {
+        loaded.store(mmu, addr + i as u16, value);
+    }
+    Ok(loaded)
+}
+
+pub fn load_binary_file<T: Mmu, P: AsRef<Path>>(
+    mmu: &mut T,
+    path: P,
+    addr: u16,
+    start: u16,
+) -> Result<Loaded, Error> {
+    let path = path.as_ref();
+    match fs::read(path) {
+        Ok(data) => load_binary(mmu, &data, addr, start),
+        Err(e) => bail!("unable to read {}: {}", path.display(), e),
+    }
+}
+
+/// Loads the records of an Intel HEX file. Blank lines are skipped and
+/// anything after the end of file record is ignored.
+///
+/// #Errors
+/// Fails on a malformed record, a bad checksum, an unsupported record type or
+/// data that would land above 0xffff. Bytes before the bad record have
+/// already been loaded.
+pub fn load_hex<T: Mmu>(mmu: &mut T, text: &str) -> Result<Loaded, Error> {
+    let mut loaded = Loaded::default();
+    // Base added by extended segment and extended linear address records.
+    let mut base = 0usize;
+    let mut eof = false;
+    for (number, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let record = match parse_record(line) {
+            Ok(record) => record,
+            Err(e) => bail!("line {}: {}", number + 1, e),
+        };
+        let (addr, kind, data) = (record.addr, record.kind, &record.data[..]);
+        match (kind, data.len()) {
+            (0x00, _) => {
+                let start = base + addr as usize;
+                if start + data.len() > 0x10000 {
+                    bail!(
+                        "line {}: data at 0x{:x} runs past 0xffff",
+                        number + 1,
+                        start
+                    );
+                }
+                for (i, &value) in data.iter().enumerate() {
+                    loaded.store(mmu, (start + i) as u16, value);
+                }
+            }
+            (0x01, 0) => {
+                if addr != 0 && loaded.start.is_none() {
+                    loaded.start = Some(addr);
+                }
+                eof = true;
+                break;
+            }
+            (0x02, 2) => base = (be_u16(data) as usize) << 4,
+            (0x04, 2) => base = (be_u16(data) as usize) << 16,
+            (0x03, 4) => {
+                let segment = (be_u16(&data[..2]) as usize) << 4;
+                loaded.start = Some(start_addr(number, segment + be_u16(&data[2..]) as usize)?);
+            }
+            (0x05, 4) => {
+                let linear = (be_u16(&data[..2]) as usize) << 16 | be_u16(&data[2..]) as usize;
+                loaded.start = Some(start_addr(number, linear)?);
+            }
+            (0x01..=0x05, len) => bail!(
+                "line {}: record type {:02x} with {} data bytes",
+                number + 1,
+                kind,
+                len
+            ),
+            _ => bail!("line {}: unknown record type {:02x}", number + 1, kind),
+        }
+    }
+    if !eof {
+        bail!("missing end of file record");
+    }
+    Ok(loaded)
+}
+
+pub fn load_hex_file<T: Mmu, P: AsRef<Path>>(mmu: &mut T, path: P) -> Result<Loaded, Error> {
+    let path = path.as_ref();
+    match fs::read_to_string(path) {
+        Ok(text) => load_hex(mmu, &text),
+        Err(e) => bail!("unable to read {}: {}", path.display(), e),
+    }
+}
+
+/// Dumps `start..=end` as Intel HEX data records, followed by an end of file
+/// record carrying `entry`, or 0 if there is none.
+pub fn write_hex<T: Mmu, W: Write>(
+    mmu: &T,
+    start: u16,
+    end: u16,
+    entry: Option<u16>,
+    mut out: W,
+) -> std::io::Result<()> {
+    let mut addr = start as usize;
+    while addr <= end as usize {
+        let len = RECORD_LEN.min(end as usize + 1 - addr);
+        let data: Vec<u8> = (addr..addr + len)
+            .map(|a| mmu.read_byte(a as u16))
+            .collect();
+        writeln!(out, "{}", format_record(addr as u16, 0x00, &data))?;
+        addr += len;
+    }
+    writeln!(out, "{}", format_record(entry.unwrap_or(0), 0x01, &[]))
+}
+
+struct Record {
+    addr: u16,
+    kind: u8,
+    data: Vec<u8>,
+}
+
+fn parse_record(line: &str) -> Result<Record, Error> {
+    let digits = match line.strip_prefix(':') {
+        Some(digits) => digits,
+        None => bail!("record does not start with ':'"),
+    };
+    if !digits.is_ascii() || digits.len() < 10 || !digits.len().is_multiple_of(2) {
+        bail!("record is too short or malformed");
+    }
+    let bytes = (0..digits.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&digits[i..i + 2], 16))
+        .collect::<Result<Vec<u8>, _>>()
+        .map_err(|_| format_err!("record has non-hex digits"))?;
+    let len = bytes[0] as usize;
+    if bytes.len() != len + 5 {
+        bail!(
+            "record claims {} data bytes but has {}",
+            len,
+            bytes.len() - 5
+        );
+    }
+    let sum = bytes.iter().fold(0u8, |sum, &b| sum.wrapping_add(b));
+    if sum != 0 {
+        bail!("bad checksum {:02x}", bytes[len + 4]);
+    }
+    Ok(Record {
+        addr: be_u16(&bytes[1..3]),
+        kind: bytes[3],
+        data: bytes[4..4 + len].to_vec(),
+    })
+}
+
+fn format_record(addr: u16, kind: u8, data: &[u8]) -> String {
+    let mut bytes = vec![data.len() as u8, (addr >> 8) as u8, addr as u8, kind];
+    bytes.extend_from_slice(data);
+    let sum = bytes.iter().fold(0u8, |sum, &b| sum.wrapping_add(b));
+    bytes.push(sum.wrapping_neg());
+    let digits: String = bytes.iter().map(|b| format!("{:02X}", b)).collect();
+    format!(":{}", digits)
+}
+
+fn be_u16(bytes: &[u8]) -> u16 {
+    (bytes[0] as u16) << 8 | bytes[1] as u16
+}
+
+fn start_addr(number: usize, addr: usize) -> Result<u16, Error> {
+    if addr > 0xffff {
+        bail!(
+            "line {}: start address 0x{:x} is above 0xffff",
+            number + 1,
+            addr
+        );
+    }
+    Ok(addr as u16)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{load_binary, load_hex, write_hex, Loaded};
+    use crate::mmu::{MemoryMap, Mmu};
+
+    fn map() -> MemoryMap {
+        MemoryMap::builder()
+            .rom(0x0000, 0x1fff, [])
+            .ram(0x2000, 0x2fff)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn loads_hex_records() {
+        let mut mmu = map();
+        let hex = ":03000000210020BC\n\
+                   \n\
+                   :020000020100FB\n\
+                   :02000000AABB99\n\
+                   :0400000300000100F8\n\
+                   :00000001FF\n\
+                   :this is ignored\n";
+        let loaded = load_hex(&mut mmu, hex).unwrap();
+        assert_eq!(
+            loaded,
+            Loaded {
+                start: Some(0x0100),
+                len: 5,
+                range: Some((0x0000, 0x1001)),
+            }
+        );
+        // The empty ROM grew to take the data, with open bus in the gap.
+        assert_eq!(mmu.rom_len(), 0x1002);
+        assert_eq!(mmu.read_byte(0x0002), 0x20);
+        assert_eq!(mmu.read_byte(0x0003), 0xff);
+        assert_eq!(mmu.read_byte(0x1001), 0xbb);
+    }
+
+    #[test]
+    fn rejects_bad_hex() {
+        let mut mmu = map();
+        let err = load_hex(&mut mmu, ":0100000011EF\n:00000001FF").unwrap_err();
+        assert_eq!(err.to_string(), "line 1: bad checksum ef");
+        assert!(load_hex(&mut mmu, "0100000011EE").is_err());
+        assert!(load_hex(&mut mmu, ":0100000011EE").is_err());
+        assert!(load_hex(&mut mmu, ":02FFFF00112233CD\n:00000001FF").is_err());
+        assert!(load_hex(&mut mmu, ":00000006FA\n:00000001FF").is_err());
+    }
+
+    #[test]
+    fn binary_round_trips_through_hex() {
+        let program: Vec<u8> = (0..40).collect();
+        let mut mmu = map();
+        let loaded = load_binary(&mut mmu, &program, 0x2010, 0x2018).unwrap();
+        assert_eq!(loaded.range, Some((0x2010, 0x2037)));
+        assert!(load_binary(&mut mmu, &program, 0xfff0, 0xfff0).is_err());
+
+        let mut hex = Vec::new();
+        write_hex(&mmu, 0x2010, 0x2037, loaded.start, &mut hex).unwrap();
+        let hex = String::from_utf8(hex).unwrap();
+        assert_eq!(hex.lines().count(), 4);
+        assert!(hex.ends_with(":00201801C7\n"));
+
+        let mut copy = map();
+        let reloaded = load_hex(&mut copy, &hex).unwrap();
+        assert_eq!(reloaded, loaded);
+        for addr in 0x2010..=0x2037 {
+            assert_eq!(copy.read_byte(addr), mmu.read_byte(addr));
+        }
+    }
+}
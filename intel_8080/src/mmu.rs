@@ -1,10 +1,41 @@
 pub mod rom;
 pub use self::rom::Rom;
 
+pub mod banked;
+pub use self::banked::{BankSnapshot, BankWindow, BankedMmu};
+
 pub mod basic_mmu;
 
+pub mod memory_map;
+pub use self::memory_map::{MemoryDevice, MemoryMap, MemoryMapBuilder};
+
+pub mod observer;
+pub use self::observer::{MemoryAccess, MemoryAccessKind, MemoryObserver, Observed};
+
+pub mod ram;
+pub use self::ram::Ram;
+
 pub trait Mmu {
     fn read_byte(&self, addr: u16) -> u8;
     fn write_byte(&mut self, addr: u16, value: u8);
     fn rom_len(&self) -> usize;
+
+    /// Stores a byte on behalf of a program loader. Unlike `write_byte` this
+    /// may also fill ROM, as a programmer would before the chip is fitted.
+    fn load_byte(&mut self, addr: u16, value: u8) {
+        self.write_byte(addr, value)
+    }
+
+    /// Offers an OUT write to the memory controller before it reaches the
+    /// `IO`, so bank switching hardware can remap memory. Returns true if the
+    /// port belongs to the controller, in which case the `IO` never sees it.
+    fn port_write(&mut self, _port: u8, _value: u8) -> bool {
+        false
+    }
+
+    /// Reads a byte, saying why it is read, so an `Observed` memory can
+    /// tell instruction fetches from data. Other memories just read it.
+    fn read_access(&self, _kind: MemoryAccessKind, addr: u16) -> u8 {
+        self.read_byte(addr)
+    }
 }
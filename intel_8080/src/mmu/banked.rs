@@ -0,0 +1,371 @@
+//! Bank switched memory.
+//!
+//! `BankedMmu` overlays one or more bank windows on top of another `Mmu`.
+//! Each window is a fixed range of the address space, typically 16K or 32K,
+//! showing one of several ROM or RAM banks. Writing a bank number to the
+//! window's select port with OUT switches the bank; the write is claimed
+//! through `Mmu::port_write` so it never reaches the board's `IO`.
+
+use log::error;
+
+use super::Mmu;
+
+use failure::{bail, Error};
+
+/// Value read past the end of a ROM bank shorter than its window.
+const OPEN_BUS: u8 = 0xff;
+
+struct Bank {
+    data: Box<[u8]>,
+    writable: bool,
+}
+
+pub struct BankWindow {
+    start: u16,
+    len: usize,
+    port: u8,
+    banks: Vec<Bank>,
+    selected: usize,
+}
+
+impl BankWindow {
+    /// Creates a window of `len` bytes at `start` whose bank is selected by
+    /// writing to `port`. Bank numbers wrap around the number of banks, as
+    /// if the unused high bits of the select latch were not decoded.
+    pub fn new(start: u16, len: usize, port: u8) -> BankWindow {
+        BankWindow {
+            start,
+            len,
+            port,
+            banks: Vec::new(),
+            selected: 0,
+        }
+    }
+
+    /// Appends `count` zeroed RAM banks.
+    pub fn ram_banks(mut self, count: usize) -> BankWindow {
+        for _ in 0..count {
+            self.banks.push(Bank {
+                data: vec![0; self.len].into_boxed_slice(),
+                writable: true,
+            });
+        }
+        self
+    }
+
+    /// Appends a ROM bank. Writes to it are ignored.
+    pub fn rom_bank<R: AsRef<[u8]>>(mut self, data: R) -> BankWindow {
+        self.banks.push(Bank {
+            data: Box::from(data.as_ref()),
+            writable: false,
+        });
+        self
+    }
+
+    pub fn port(&self) -> u8 {
+        self.port
+    }
+
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    fn end(&self) -> usize {
+        self.start as usize + self.len
+    }
+
+    fn contains(&self, addr: u16) -> bool {
+        addr >= self.start && (addr as usize) < self.end()
+    }
+}
+
+/// Selected banks and RAM bank contents of a `BankedMmu`. Memory belonging
+/// to the underlying `Mmu` is not included.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BankSnapshot {
+    /// Selected bank of each window, in the order the windows were added.
+    pub selected: Vec<usize>,
+    ram: Vec<Vec<Box<[u8]>>>,
+}
+
+pub struct BankedMmu<T: Mmu> {
+    base: T,
+    windows: Vec<BankWindow>,
+}
+
+impl<T: Mmu> BankedMmu<T> {
+    /// Wraps `base`, which handles every address outside the bank windows.
+    pub fn new(base: T) -> BankedMmu<T> {
+        BankedMmu {
+            base,
+            windows: Vec::new(),
+        }
+    }
+
+    /// #Errors
+    /// Fails if the window has no banks, runs past 0xffff, overlaps another
+    /// window or has a ROM bank larger than itself.
+    pub fn add_window(&mut self, window: BankWindow) -> Result<(), Error> {
+        if window.banks.is_empty() || window.len == 0 {
+            bail!("bank window at 0x{:04x} is empty", window.start);
+        }
+        if window.end() > 0x10000 {
+            bail!("bank window at 0x{:04x} runs past 0xffff", window.start);
+        }
+        if let Some(other) = self
+            .windows
+            .iter()
+            .find(|w| (window.start as usize) < w.end() && (w.start as usize) < window.end())
+        {
+            bail!(
+                "bank window at 0x{:04x} overlaps the window at 0x{:04x}",
+                window.start,
+                other.start
+            );
+        }
+        if window.banks.iter().any(|b| b.data.len() > window.len) {
+            bail!(
+                "ROM bank does not fit in the window at 0x{:04x}",
+                window.start
+            );
+        }
+        self.windows.push(window);
+        Ok(())
+    }
+
+    pub fn windows(&self) -> &[BankWindow] {
+        &self.windows
+    }
+
+    pub fn base(&self) -> &T {
+        &self.base
+    }
+
+    pub fn base_mut(&mut self) -> &mut T {
+        &mut self.base
+    }
+
+    pub fn snapshot(&self) -> BankSnapshot {
+        BankSnapshot {
+            selected: self.windows.iter().map(|w| w.selected).collect(),
+            ram: self
+                .windows
+                .iter()
+                .map(|w| {
+                    w.banks
+                        .iter()
+                        .filter(|b| b.writable)
+                        .map(|b| b.data.clone())
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    /// #Errors
+    /// Fails if the snapshot was taken from a differently configured
+    /// `BankedMmu`.
+    pub fn restore(&mut self, snapshot: &BankSnapshot) -> Result<(), Error> {
+        let matches = snapshot.selected.len() == self.windows.len()
+            && self
+                .windows
+                .iter()
+                .zip(snapshot.selected.iter().zip(snapshot.ram.iter()))
+                .all(|(w, (&selected, ram))| {
+                    let banks: Vec<_> = w.banks.iter().filter(|b| b.writable).collect();
+                    selected < w.banks.len()
+                        && banks.len() == ram.len()
+                        && banks.iter().zip(ram).all(|(b, r)| b.data.len() == r.len())
+                });
+        if !matches {
+            bail!("snapshot does not match the bank window layout");
+        }
+        for (window, (&selected, ram)) in self
+            .windows
+            .iter_mut()
+            .zip(snapshot.selected.iter().zip(snapshot.ram.iter()))
+        {
+            window.selected = selected;
+            let banks = window.banks.iter_mut().filter(|b| b.writable);
+            for (bank, data) in banks.zip(ram) {
+                bank.data.copy_from_slice(data);
+            }
+        }
+        Ok(())
+    }
+
+    fn window(&self, addr: u16) -> Option<&BankWindow> {
+        self.windows.iter().find(|w| w.contains(addr))
+    }
+}
+
+impl<T: Mmu> Mmu for BankedMmu<T> {
+    fn read_byte(&self, addr: u16) -> u8 {
+        match self.window(addr) {
+            Some(window) => {
+                let offset = (addr - window.start) as usize;
+                let bank = &window.banks[window.selected];
+                bank.data.get(offset).cloned().unwrap_or(OPEN_BUS)
+            }
+            None => self.base.read_byte(addr),
+        }
+    }
+
+    fn write_byte(&mut self, addr: u16, value: u8) {
+        match self.windows.iter_mut().find(|w| w.contains(addr)) {
+            Some(window) => {
+                let offset = (addr - window.start) as usize;
+                let bank = &mut window.banks[window.selected];
+                if bank.writable {
+                    bank.data[offset] = value;
+                } else {
+                    error!("Attempting to write to ROM");
+                }
+            }
+            None => self.base.write_byte(addr, value),
+        }
+    }
+
+    /// Code may run anywhere in a bank window as well as in the ROM of the
+    /// underlying `Mmu`.
+    fn rom_len(&self) -> usize {
+        self.windows
+            .iter()
+            .map(BankWindow::end)
+            .fold(self.base.rom_len(), usize::max)
+    }
+
+    /// Loads into the selected bank, ROM or RAM, or else the underlying `Mmu`.
+    /// Bytes past the end of a short ROM bank are dropped.
+    fn load_byte(&mut self, addr: u16, value: u8) {
+        match self.windows.iter_mut().find(|w| w.contains(addr)) {
+            Some(window) => {
+                let offset = (addr - window.start) as usize;
+                let bank = &mut window.banks[window.selected];
+                if let Some(byte) = bank.data.get_mut(offset) {
+                    *byte = value;
+                }
+            }
+            None => self.base.load_byte(addr, value),
+        }
+    }
+
+    fn port_write(&mut self, port: u8, value: u8) -> bool {
+        let mut handled = false;
+        for window in self.windows.iter_mut().filter(|w| w.port == port) {
+            window.selected = value as usize % window.banks.len();
+            handled = true;
+        }
+        handled || self.base.port_write(port, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BankWindow, BankedMmu};
+    use crate::{
+        interconnect::Interconnect,
+        io::basic_io::BasicIO,
+        mmu::{MemoryMap, Mmu},
+        Emulator,
+    };
+
+    fn banked(program: &[u8]) -> BankedMmu<MemoryMap> {
+        let base = MemoryMap::builder()
+            .rom(0x0000, 0x3fff, program)
+            .ram(0xc000, 0xffff)
+            .build()
+            .unwrap();
+        let mut mmu = BankedMmu::new(base);
+        let window = BankWindow::new(0x8000, 0x4000, 0x10)
+            .rom_bank([0xaa, 0xbb])
+            .ram_banks(2);
+        mmu.add_window(window).unwrap();
+        mmu
+    }
+
+    #[test]
+    fn out_switches_banks() {
+        let program = [
+            0x3e, 0x01, //       MVI A, 0x01
+            0xd3, 0x10, //       OUT 0x10
+            0x3e, 0x5a, //       MVI A, 0x5a
+            0x32, 0x01, 0x80, // STA 0x8001
+            0x3e, 0x00, //       MVI A, 0x00
+            0xd3, 0x10, //       OUT 0x10
+            0x3a, 0x00, 0x80, // LDA 0x8000
+            0x76, //             HLT
+        ];
+        let interconnect = Interconnect::from_parts(banked(&program), BasicIO::default());
+        let mut system = Emulator::with_interconnect(interconnect);
+        system.run();
+
+        assert_eq!(system.cpu().registers().a, 0xaa);
+        let mmu = &mut system.interconnect.mmu;
+        assert_eq!(mmu.windows()[0].selected(), 0);
+        assert_eq!(mmu.read_byte(0x8001), 0xbb);
+        assert_eq!(mmu.read_byte(0x8002), 0xff);
+        assert!(mmu.port_write(0x10, 0x01));
+        assert_eq!(mmu.read_byte(0x8001), 0x5a);
+        // Bank numbers wrap around the three banks.
+        mmu.port_write(0x10, 0x05);
+        assert_eq!(mmu.windows()[0].selected(), 2);
+        assert!(!mmu.port_write(0x11, 0x00));
+    }
+
+    #[test]
+    fn runs_code_in_a_bank() {
+        let program = [
+            0x3e, 0x02, //       MVI A, 0x02
+            0xd3, 0x10, //       OUT 0x10
+            0x3e, 0x3c, //       MVI A, 0x3c (INR A)
+            0x32, 0x00, 0x80, // STA 0x8000
+            0x3e, 0x76, //       MVI A, 0x76 (HLT)
+            0x32, 0x01, 0x80, // STA 0x8001
+            0x3e, 0x41, //       MVI A, 0x41
+            0xc3, 0x00, 0x80, // JMP 0x8000
+        ];
+        let mmu = banked(&program);
+        assert_eq!(mmu.rom_len(), 0xc000);
+        let interconnect = Interconnect::from_parts(mmu, BasicIO::default());
+        let mut system = Emulator::with_interconnect(interconnect);
+        system.try_run().unwrap();
+
+        assert!(system.cpu().halted());
+        assert_eq!(system.cpu().registers().a, 0x42);
+        assert_eq!(system.cpu().pc(), 0x8002);
+    }
+
+    #[test]
+    fn snapshot_restores_selection() {
+        let mut mmu = banked(&[]);
+        mmu.port_write(0x10, 0x01);
+        mmu.write_byte(0x8000, 0x12);
+        let snapshot = mmu.snapshot();
+        assert_eq!(snapshot.selected, vec![1]);
+
+        mmu.write_byte(0x8000, 0x34);
+        mmu.port_write(0x10, 0x02);
+        mmu.restore(&snapshot).unwrap();
+        assert_eq!(mmu.windows()[0].selected(), 1);
+        assert_eq!(mmu.read_byte(0x8000), 0x12);
+
+        assert!(banked(&[]).restore(&banked(&[]).snapshot()).is_ok());
+        let mut other = BankedMmu::new(MemoryMap::builder().build().unwrap());
+        assert!(other.restore(&snapshot).is_err());
+    }
+
+    #[test]
+    fn rejects_bad_windows() {
+        let mut mmu = banked(&[]);
+        let overlapping = BankWindow::new(0xa000, 0x4000, 0x11).ram_banks(1);
+        assert!(mmu.add_window(overlapping).is_err());
+        assert!(mmu
+            .add_window(BankWindow::new(0x4000, 0x4000, 0x11))
+            .is_err());
+        let past_end = BankWindow::new(0xf000, 0x2000, 0x11).ram_banks(1);
+        assert!(mmu.add_window(past_end).is_err());
+        let big_rom = BankWindow::new(0x4000, 0x10, 0x11).rom_bank([0; 0x11]);
+        assert!(mmu.add_window(big_rom).is_err());
+    }
+}
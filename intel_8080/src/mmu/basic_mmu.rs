@@ -1,57 +1,81 @@
-use log::error;
-
 pub mod mem_map;
 
-mod game_pad;
-mod vram;
-mod wram;
-
-use self::game_pad::GamePad;
-use self::vram::Vram;
-use self::wram::Wram;
-
 use self::mem_map::*;
-use super::{Mmu, Rom};
+use super::{MemoryMap, Mmu, Rom};
+use failure::Error;
 
 pub struct BasicMMU {
-    rom: Rom,
-    wram: Wram,
-    vram: Vram,
-    game_pad: GamePad,
+    map: MemoryMap,
 }
 
 impl BasicMMU {
-    pub fn new<T: Into<Rom>>(rom: T) -> BasicMMU {
-        let rom = rom.into();
-        BasicMMU {
-            rom,
-            wram: Wram::new(),
-            vram: Vram::new(),
-            game_pad: GamePad::new(),
-        }
+    /// #Errors
+    /// Fails if `rom` is larger than the board's ROM region.
+    pub fn new<T: Into<Rom>>(rom: T) -> Result<BasicMMU, Error> {
+        let map = MemoryMap::builder()
+            .rom(ROM_START, ROM_END, rom)
+            .ram(WRAM_START, WRAM_END)
+            .ram(VRAM_START, VRAM_END)
+            .mirror(RAM_MIRROR_START, RAM_MIRROR_END, WRAM_START)
+            .open_bus(OPEN_BUS)
+            .build()?;
+        Ok(BasicMMU::from_map(map))
+    }
+
+    /// Wraps the memory map of another Midway 8080 board.
+    pub fn from_map(map: MemoryMap) -> BasicMMU {
+        BasicMMU { map }
     }
 }
 
 impl Mmu for BasicMMU {
     fn read_byte(&self, addr: u16) -> u8 {
-        match addr {
-            ROM_START...ROM_END => self.rom.read_byte(addr - ROM_START),
-            WRAM_START...WRAM_END => self.wram.read_byte(addr - WRAM_START),
-            VRAM_START...VRAM_END => self.vram.read_byte(addr - VRAM_START),
-            _ => panic!("Unrecognized Address: 0x{:04x}", addr),
-        }
+        self.map.read_byte(addr)
     }
 
     fn write_byte(&mut self, addr: u16, value: u8) {
-        match addr {
-            ROM_START...ROM_END => error!("Attempting to write to ROM"),
-            WRAM_START...WRAM_END => self.wram.write_byte(addr - WRAM_START, value),
-            VRAM_START...VRAM_END => self.vram.write_byte(addr - VRAM_START, value),
-            _ => panic!("Unrecognized Address: 0x{:04x}", addr),
-        }
+        self.map.write_byte(addr, value)
     }
 
     fn rom_len(&self) -> usize {
-        self.rom.len()
+        self.map.rom_len()
+    }
+
+    fn load_byte(&mut self, addr: u16, value: u8) {
+        self.map.load_byte(addr, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{mem_map::*, BasicMMU};
+    use crate::mmu::Mmu;
+
+    #[test]
+    fn ram_mirror() {
+        let mut mmu = BasicMMU::new([0x00]).unwrap();
+        mmu.write_byte(WRAM_START + 1, 0x12);
+        assert_eq!(mmu.read_byte(RAM_MIRROR_START + 1), 0x12);
+
+        mmu.write_byte(RAM_MIRROR_END, 0x34);
+        assert_eq!(mmu.read_byte(VRAM_END), 0x34);
+
+        mmu.write_byte(0x4400, 0x56);
+        assert_eq!(mmu.read_byte(VRAM_START), 0x56);
+    }
+
+    #[test]
+    fn open_bus_beyond_mirror() {
+        let mut mmu = BasicMMU::new([0x00]).unwrap();
+        mmu.write_byte(0x6000, 0x12);
+        assert_eq!(mmu.read_byte(0x6000), OPEN_BUS);
+        assert_eq!(mmu.read_byte(0xffff), OPEN_BUS);
+        assert_eq!(mmu.read_byte(ROM_END), OPEN_BUS);
+    }
+
+    #[test]
+    fn rejects_oversized_rom() {
+        assert!(BasicMMU::new(vec![0x00; ROM_END as usize + 1]).is_ok());
+        assert!(BasicMMU::new(vec![0x00; ROM_END as usize + 2]).is_err());
     }
 }
@@ -1,9 +0,0 @@
-pub struct GamePad {
-    //TODO
-}
-
-impl GamePad {
-    pub fn new() -> GamePad {
-        GamePad {}
-    }
-}
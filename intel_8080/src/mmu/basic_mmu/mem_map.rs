@@ -7,4 +7,9 @@ pub const WRAM_END: u16 = 0x23ff;
 pub const VRAM_START: u16 = 0x2400;
 pub const VRAM_END: u16 = 0x3fff;
 
-//pub const RAM_MIRROR_START: u16 = 0x4000;
+/// WRAM and VRAM repeat at 0x4000-0x5fff; A14 is not decoded for RAM.
+pub const RAM_MIRROR_START: u16 = 0x4000;
+pub const RAM_MIRROR_END: u16 = 0x5fff;
+
+/// Value read back from addresses nothing responds to.
+pub const OPEN_BUS: u8 = 0xff;
@@ -0,0 +1,341 @@
+//! Declarative memory maps.
+//!
+//! A board describes its address space with `MemoryMap::builder()`, listing
+//! ROM, RAM, mirrored and unmapped ranges and memory mapped devices. The
+//! resulting map resolves addresses through a table of 256 byte pages, so
+//! regions must start and end on page boundaries.
+//!
+//! Declarations are applied in order and later ones replace earlier ones
+//! where they overlap, which lets a device be mapped over part of a RAM
+//! block. A mirror copies whatever is mapped over its source range at the
+//! point it is declared. Anything left undeclared reads as open bus.
+
+use log::error;
+
+use super::{Mmu, Rom};
+
+use failure::{bail, Error};
+
+const PAGE_SIZE: usize = 0x100;
+const PAGES: usize = 0x100;
+
+/// Hardware that responds to reads and writes in a range of the address
+/// space. Offsets are relative to the start of the device's region.
+pub trait MemoryDevice {
+    fn read(&self, offset: u16) -> u8;
+    fn write(&mut self, offset: u16, value: u8);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Target {
+    /// Reads return the given open bus value, writes are ignored.
+    Unmapped(u8),
+    Rom(usize),
+    Ram(usize),
+    Device(usize),
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Page {
+    target: Target,
+    /// Offset of the page's first byte within its ROM, RAM or device.
+    offset: usize,
+}
+
+enum Declaration {
+    Rom(u16, u16, Rom),
+    Ram(u16, u16),
+    Mirror(u16, u16, u16),
+    Device(u16, u16, Box<dyn MemoryDevice>),
+    Unmapped(u16, u16, u8),
+}
+
+pub struct MemoryMapBuilder {
+    declarations: Vec<Declaration>,
+    open_bus: u8,
+}
+
+impl MemoryMapBuilder {
+    /// Maps `rom` at `start..=end`. Reads past the end of a short ROM return
+    /// open bus and writes are ignored.
+    pub fn rom<T: Into<Rom>>(mut self, start: u16, end: u16, rom: T) -> MemoryMapBuilder {
+        self.declarations.push(Declaration::Rom(start, end, rom.into()));
+        self
+    }
+
+    /// Maps zeroed RAM at `start..=end`.
+    pub fn ram(mut self, start: u16, end: u16) -> MemoryMapBuilder {
+        self.declarations.push(Declaration::Ram(start, end));
+        self
+    }
+
+    /// Makes `start..=end` an alias of the same sized range at `source`.
+    pub fn mirror(mut self, start: u16, end: u16, source: u16) -> MemoryMapBuilder {
+        self.declarations.push(Declaration::Mirror(start, end, source));
+        self
+    }
+
+    pub fn device<D: MemoryDevice + 'static>(
+        mut self,
+        start: u16,
+        end: u16,
+        device: D,
+    ) -> MemoryMapBuilder {
+        self.declarations
+            .push(Declaration::Device(start, end, Box::new(device)));
+        self
+    }
+
+    /// Leaves `start..=end` unmapped, reading back as `value`.
+    pub fn unmapped(mut self, start: u16, end: u16, value: u8) -> MemoryMapBuilder {
+        self.declarations.push(Declaration::Unmapped(start, end, value));
+        self
+    }
+
+    /// Sets the value read from undeclared addresses and past the end of
+    /// short ROMs. Defaults to 0xff, as on a bus with pull-ups.
+    pub fn open_bus(self, open_bus: u8) -> MemoryMapBuilder {
+        MemoryMapBuilder { open_bus, ..self }
+    }
+
+    /// #Errors
+    /// Fails if a region does not start and end on a 256 byte page boundary,
+    /// a ROM is larger than its region or a mirror's source runs past 0xffff.
+    pub fn build(self) -> Result<MemoryMap, Error> {
+        let mut map = MemoryMap {
+            pages: vec![
+                Page {
+                    target: Target::Unmapped(self.open_bus),
+                    offset: 0,
+                };
+                PAGES
+            ],
+            roms: Vec::new(),
+            rams: Vec::new(),
+            devices: Vec::new(),
+            open_bus: self.open_bus,
+            rom_len: 0,
+        };
+        for declaration in self.declarations {
+            match declaration {
+                Declaration::Rom(start, end, rom) => {
+                    let pages = pages(start, end)?;
+                    if rom.len() > region_len(start, end) {
+                        bail!(
+                            "ROM of {} bytes does not fit in 0x{:04x}-0x{:04x}",
+                            rom.len(),
+                            start,
+                            end
+                        );
+                    }
+                    map.rom_len = map.rom_len.max(start as usize + rom.len());
+                    map.roms.push(rom);
+                    map.fill(pages, Target::Rom(map.roms.len() - 1));
+                }
+                Declaration::Ram(start, end) => {
+                    let pages = pages(start, end)?;
+                    map.rams.push(vec![0; region_len(start, end)].into_boxed_slice());
+                    map.fill(pages, Target::Ram(map.rams.len() - 1));
+                }
+                Declaration::Mirror(start, end, source) => {
+                    let pages = pages(start, end)?;
+                    let source_end = match source.checked_add(end - start) {
+                        Some(source_end) => source_end,
+                        None => bail!("mirror source 0x{:04x} runs past 0xffff", source),
+                    };
+                    let source_pages = map.pages[self::pages(source, source_end)?].to_vec();
+                    for (page, source) in pages.zip(source_pages) {
+                        map.pages[page] = source;
+                    }
+                }
+                Declaration::Device(start, end, device) => {
+                    let pages = pages(start, end)?;
+                    map.devices.push(device);
+                    map.fill(pages, Target::Device(map.devices.len() - 1));
+                }
+                Declaration::Unmapped(start, end, value) => {
+                    let pages = pages(start, end)?;
+                    map.fill(pages, Target::Unmapped(value));
+                }
+            }
+        }
+        Ok(map)
+    }
+}
+
+fn region_len(start: u16, end: u16) -> usize {
+    end as usize - start as usize + 1
+}
+
+fn pages(start: u16, end: u16) -> Result<std::ops::Range<usize>, Error> {
+    let aligned =
+        (start as usize).is_multiple_of(PAGE_SIZE) && (end as usize + 1).is_multiple_of(PAGE_SIZE);
+    if end < start || !aligned {
+        bail!(
+            "region 0x{:04x}-0x{:04x} is not aligned to {} byte pages",
+            start,
+            end,
+            PAGE_SIZE
+        );
+    }
+    Ok(start as usize / PAGE_SIZE..end as usize / PAGE_SIZE + 1)
+}
+
+pub struct MemoryMap {
+    pages: Vec<Page>,
+    roms: Vec<Rom>,
+    rams: Vec<Box<[u8]>>,
+    devices: Vec<Box<dyn MemoryDevice>>,
+    open_bus: u8,
+    rom_len: usize,
+}
+
+impl MemoryMap {
+    pub fn builder() -> MemoryMapBuilder {
+        MemoryMapBuilder {
+            declarations: Vec::new(),
+            open_bus: 0xff,
+        }
+    }
+
+    fn fill(&mut self, pages: std::ops::Range<usize>, target: Target) {
+        let first = pages.start;
+        for page in pages {
+            self.pages[page] = Page {
+                target,
+                offset: (page - first) * PAGE_SIZE,
+            };
+        }
+    }
+}
+
+impl Mmu for MemoryMap {
+    fn read_byte(&self, addr: u16) -> u8 {
+        let page = self.pages[addr as usize / PAGE_SIZE];
+        let offset = page.offset + addr as usize % PAGE_SIZE;
+        match page.target {
+            Target::Unmapped(value) => value,
+            Target::Rom(rom) => {
+                let rom = &self.roms[rom];
+                if offset < rom.len() {
+                    rom.read_byte(offset as u16)
+                } else {
+                    self.open_bus
+                }
+            }
+            Target::Ram(ram) => self.rams[ram][offset],
+            Target::Device(device) => self.devices[device].read(offset as u16),
+        }
+    }
+
+    fn write_byte(&mut self, addr: u16, value: u8) {
+        let page = self.pages[addr as usize / PAGE_SIZE];
+        let offset = page.offset + addr as usize % PAGE_SIZE;
+        match page.target {
+            Target::Unmapped(_) => {}
+            Target::Rom(_) => error!("Attempting to write to ROM"),
+            Target::Ram(ram) => self.rams[ram][offset] = value,
+            Target::Device(device) => self.devices[device].write(offset as u16, value),
+        }
+    }
+
+    /// Address just past the end of the highest mapped ROM data.
+    fn rom_len(&self) -> usize {
+        self.rom_len
+    }
+
+    /// Fills ROM as well as RAM. Loading past the end of a short ROM grows it
+    /// up to the size of its region.
+    fn load_byte(&mut self, addr: u16, value: u8) {
+        let page = self.pages[addr as usize / PAGE_SIZE];
+        let offset = page.offset + addr as usize % PAGE_SIZE;
+        match page.target {
+            Target::Rom(rom) => {
+                self.roms[rom].load_byte(offset as u16, value, self.open_bus);
+                let start = addr as usize - offset;
+                self.rom_len = self.rom_len.max(start + self.roms[rom].len());
+            }
+            _ => self.write_byte(addr, value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MemoryDevice, MemoryMap};
+    use crate::mmu::Mmu;
+
+    struct Latch(u8);
+
+    impl MemoryDevice for Latch {
+        fn read(&self, offset: u16) -> u8 {
+            self.0.wrapping_add(offset as u8)
+        }
+
+        fn write(&mut self, _offset: u16, value: u8) {
+            self.0 = value;
+        }
+    }
+
+    #[test]
+    fn maps_regions() {
+        let mut map = MemoryMap::builder()
+            .rom(0x0000, 0x0fff, [0x11, 0x22])
+            .ram(0x1000, 0x11ff)
+            .mirror(0x1200, 0x15ff, 0x1000)
+            .device(0x8000, 0x80ff, Latch(0x40))
+            .unmapped(0x9000, 0x90ff, 0x00)
+            .build()
+            .unwrap();
+
+        assert_eq!(map.rom_len(), 2);
+        assert_eq!(map.read_byte(0x0001), 0x22);
+        assert_eq!(map.read_byte(0x0002), 0xff);
+        map.write_byte(0x0000, 0x99);
+        assert_eq!(map.read_byte(0x0000), 0x11);
+
+        map.write_byte(0x1001, 0x5a);
+        assert_eq!(map.read_byte(0x1001), 0x5a);
+        assert_eq!(map.read_byte(0x1201), 0x5a);
+        map.write_byte(0x13ff, 0xa5);
+        assert_eq!(map.read_byte(0x11ff), 0xa5);
+        // The mirror's second half aliases whatever was at 0x1200-0x13ff,
+        // which was unmapped.
+        assert_eq!(map.read_byte(0x1401), 0xff);
+
+        assert_eq!(map.read_byte(0x8003), 0x43);
+        map.write_byte(0x8000, 0x10);
+        assert_eq!(map.read_byte(0x8001), 0x11);
+
+        assert_eq!(map.read_byte(0x9000), 0x00);
+        assert_eq!(map.read_byte(0xffff), 0xff);
+    }
+
+    #[test]
+    fn later_declarations_override() {
+        let map = MemoryMap::builder()
+            .open_bus(0x00)
+            .ram(0x0000, 0x3fff)
+            .device(0x1000, 0x10ff, Latch(0x80))
+            .build()
+            .unwrap();
+        assert_eq!(map.read_byte(0x0fff), 0x00);
+        assert_eq!(map.read_byte(0x1000), 0x80);
+        assert_eq!(map.read_byte(0x1100), 0x00);
+        assert_eq!(map.read_byte(0x4000), 0x00);
+    }
+
+    #[test]
+    fn rejects_bad_regions() {
+        assert!(MemoryMap::builder().ram(0x0000, 0x0fef).build().is_err());
+        assert!(MemoryMap::builder().ram(0x0010, 0x00ff).build().is_err());
+        assert!(MemoryMap::builder()
+            .rom(0x0000, 0x00ff, vec![0; 0x101])
+            .build()
+            .is_err());
+        assert!(MemoryMap::builder()
+            .mirror(0x0000, 0x0fff, 0xf100)
+            .build()
+            .is_err());
+    }
+}
@@ -0,0 +1,197 @@
+//! Memory access observers.
+//!
+//! Every `Interconnect` keeps its memory in an `Observed` wrapper, so tools
+//! such as watchpoints and the code/data logger can see each access as it
+//! happens, whoever makes it: the CPU, a program loader or emulated
+//! firmware such as the CP/M BIOS. With no observer registered an access
+//! only pays for checking that the observer list is empty.
+
+use super::Mmu;
+use std::{cell::RefCell, ops::Deref, rc::Rc};
+
+/// Why the CPU touched a memory location.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MemoryAccessKind {
+    /// Opcode byte of an instruction.
+    Fetch,
+    /// Immediate data or address bytes following an opcode.
+    Operand,
+    /// Data read by an instruction, including stack pops.
+    Read,
+    /// Data written by an instruction, including stack pushes.
+    Write,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MemoryAccess {
+    pub kind: MemoryAccessKind,
+    pub addr: u16,
+    pub value: u8,
+}
+
+/// Receives every memory access.
+///
+/// Observers registered with `Emulator::add_observer` are told about each
+/// access at the moment it is made: a read once the value is known, a write
+/// before it reaches memory. Accesses made outside the CPU are reported as
+/// `Read` and `Write`. Instructions injected by the interrupt controller have
+/// no fetch or operand accesses.
+pub trait MemoryObserver {
+    fn observe(&mut self, access: MemoryAccess);
+}
+
+impl<T: MemoryObserver + ?Sized> MemoryObserver for Box<T> {
+    fn observe(&mut self, access: MemoryAccess) {
+        (**self).observe(access)
+    }
+}
+
+/// Lets an observer be shared with the caller, so its results can be read
+/// back after the emulator has run.
+impl<T: MemoryObserver + ?Sized> MemoryObserver for Rc<RefCell<T>> {
+    fn observe(&mut self, access: MemoryAccess) {
+        self.borrow_mut().observe(access)
+    }
+}
+
+/// Collects every access in memory.
+impl MemoryObserver for Vec<MemoryAccess> {
+    fn observe(&mut self, access: MemoryAccess) {
+        self.push(access);
+    }
+}
+
+/// An `Mmu` that reports every access to its observers before passing it
+/// on to the memory it wraps.
+pub struct Observed<T: Mmu> {
+    mmu: T,
+    // Only the observers sit behind a `RefCell`, so an empty list is seen
+    // without touching a borrow flag.
+    observers: Vec<RefCell<Box<dyn MemoryObserver>>>,
+}
+
+impl<T: Mmu> Observed<T> {
+    pub fn new(mmu: T) -> Observed<T> {
+        Observed {
+            mmu,
+            observers: Vec::new(),
+        }
+    }
+
+    pub fn add_observer<V: MemoryObserver + 'static>(&mut self, observer: V) {
+        self.observers.push(RefCell::new(Box::new(observer)));
+    }
+
+    pub fn clear_observers(&mut self) {
+        self.observers.clear();
+    }
+
+    #[inline]
+    fn notify(&self, kind: MemoryAccessKind, addr: u16, value: u8) {
+        for observer in &self.observers {
+            observer
+                .borrow_mut()
+                .observe(MemoryAccess { kind, addr, value });
+        }
+    }
+}
+
+/// Gives read access to the wrapped memory, for board specific state such
+/// as the selected banks. Writes must go through the `Mmu` methods so they
+/// are observed.
+impl<T: Mmu> Deref for Observed<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.mmu
+    }
+}
+
+impl<T: Mmu> Mmu for Observed<T> {
+    fn read_byte(&self, addr: u16) -> u8 {
+        self.read_access(MemoryAccessKind::Read, addr)
+    }
+
+    fn write_byte(&mut self, addr: u16, value: u8) {
+        self.notify(MemoryAccessKind::Write, addr, value);
+        self.mmu.write_byte(addr, value)
+    }
+
+    fn rom_len(&self) -> usize {
+        self.mmu.rom_len()
+    }
+
+    fn load_byte(&mut self, addr: u16, value: u8) {
+        self.notify(MemoryAccessKind::Write, addr, value);
+        self.mmu.load_byte(addr, value)
+    }
+
+    fn port_write(&mut self, port: u8, value: u8) -> bool {
+        self.mmu.port_write(port, value)
+    }
+
+    fn read_access(&self, kind: MemoryAccessKind, addr: u16) -> u8 {
+        let value = self.mmu.read_access(kind, addr);
+        self.notify(kind, addr, value);
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MemoryAccess, MemoryAccessKind::*};
+    use crate::{loader, mmu::Mmu, Emulator};
+    use std::{cell::RefCell, rc::Rc};
+
+    #[test]
+    fn observes_fetch_operand_and_data() {
+        let bytecode = [
+            0x21, 0x00, 0x20, // LXI H, 0x2000
+            0x7e, //             MOV A,M
+            0x3c, //             INR A
+            0x77, //             MOV M,A
+        ];
+        let accesses = Rc::new(RefCell::new(Vec::<MemoryAccess>::new()));
+        let mut system = Emulator::new(bytecode).unwrap();
+        system.mmu_mut().write_byte(0x2000, 0x41);
+        system.add_observer(accesses.clone());
+        system.run();
+
+        let access = |kind, addr, value| MemoryAccess { kind, addr, value };
+        assert_eq!(
+            *accesses.borrow(),
+            vec![
+                access(Fetch, 0x0000, 0x21),
+                access(Operand, 0x0001, 0x00),
+                access(Operand, 0x0002, 0x20),
+                access(Fetch, 0x0003, 0x7e),
+                access(Read, 0x2000, 0x41),
+                access(Fetch, 0x0004, 0x3c),
+                access(Fetch, 0x0005, 0x77),
+                access(Write, 0x2000, 0x42),
+            ]
+        );
+    }
+
+    #[test]
+    fn observes_accesses_from_outside_the_cpu() {
+        let accesses = Rc::new(RefCell::new(Vec::<MemoryAccess>::new()));
+        let mut system = Emulator::new([0x76]).unwrap();
+        system.add_observer(accesses.clone());
+        system.mmu_mut().write_byte(0x2000, 0x41);
+        loader::load_binary(&mut system.interconnect.mmu, &[0x42], 0x2001, 0x2001).unwrap();
+        assert_eq!(system.mmu().read_byte(0x2001), 0x42);
+        system.clear_observers();
+        system.mmu_mut().write_byte(0x2002, 0x43);
+
+        let access = |kind, addr, value| MemoryAccess { kind, addr, value };
+        assert_eq!(
+            *accesses.borrow(),
+            vec![
+                access(Write, 0x2000, 0x41),
+                access(Write, 0x2001, 0x42),
+                access(Read, 0x2001, 0x42),
+            ]
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use super::Mmu;
+
+const SIZE: usize = 0x10000;
+
+/// 64K of RAM filling the whole address space, as on a CP/M machine.
+pub struct Ram {
+    bytes: Box<[u8]>,
+}
+
+impl Ram {
+    pub fn new() -> Ram {
+        Ram {
+            bytes: vec![0; SIZE].into_boxed_slice(),
+        }
+    }
+}
+
+impl Default for Ram {
+    fn default() -> Ram {
+        Ram::new()
+    }
+}
+
+impl Mmu for Ram {
+    fn read_byte(&self, addr: u16) -> u8 {
+        self.bytes[addr as usize]
+    }
+
+    fn write_byte(&mut self, addr: u16, value: u8) {
+        self.bytes[addr as usize] = value;
+    }
+
+    /// Programs may run from anywhere, so all of memory counts as code.
+    fn rom_len(&self) -> usize {
+        SIZE
+    }
+}
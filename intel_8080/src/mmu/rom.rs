@@ -16,6 +16,18 @@ impl Rom {
     pub(crate) fn len(&self) -> usize {
         self.bytes.len()
     }
+
+    /// Overwrites a byte, growing the ROM with `fill` if `addr` is past its
+    /// end.
+    pub(crate) fn load_byte(&mut self, addr: u16, value: u8, fill: u8) {
+        let addr = addr as usize;
+        if addr >= self.bytes.len() {
+            let mut bytes = self.bytes.to_vec();
+            bytes.resize(addr + 1, fill);
+            self.bytes = bytes.into_boxed_slice();
+        }
+        self.bytes[addr] = value;
+    }
 }
 
 impl<T> From<T> for Rom
@@ -1,5 +1,8 @@
-use crate::instruction::Instruction;
+use crate::{instruction::Instruction, io::IO};
 
+/// The CPU's single interrupt input. Devices raise an interrupt on it while
+/// they are clocked, and an interrupt controller chip on the IO side is asked
+/// for its vector when nothing has been raised.
 #[derive(Default)]
 pub struct InterruptController {
     interrupt: Option<Instruction>,
@@ -20,4 +23,11 @@ impl InterruptController {
             None => None,
         }
     }
+
+    /// Takes the interrupt the CPU is to service next: the one raised on the
+    /// controller, or failing that one acknowledged by the `IO`.
+    pub fn acknowledge<U: IO>(&mut self, io: &mut U) -> Option<Instruction> {
+        self.consume_interrupt()
+            .or_else(|| io.acknowledge_interrupt())
+    }
 }
@@ -0,0 +1,399 @@
+//! Execution profiler built on the tracing hooks.
+//!
+//! `Profiler` is a `Tracer`: register it with `Emulator::add_tracer` (wrapped
+//! in an `Rc<RefCell<_>>` to read the results back) and it accumulates
+//! instruction and cycle counts per address, per called routine and per
+//! interrupt handler, along with a call graph.
+//!
+//! Routines are tracked with a shadow call stack. A frame is pushed when a
+//! CALL, RST or interrupt pushes a return address, and popped once the stack
+//! pointer climbs back to where it was before the call, which also handles
+//! routines that discard their return address instead of returning.
+
+use crate::{
+    symbols::SymbolTable,
+    trace::{TraceRecord, Tracer},
+};
+
+use failure::Error;
+use std::{collections::HashMap, fmt::Write, rc::Rc};
+
+/// Counts for a single instruction address.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct AddressStats {
+    pub instructions: u64,
+    pub cycles: u64,
+}
+
+/// Counts for a routine, keyed by its entry address.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct RoutineStats {
+    /// Number of times the routine was entered.
+    pub calls: u64,
+    /// Cycles spent in the routine and everything it called.
+    pub inclusive_cycles: u64,
+    /// Cycles spent in the routine's own instructions.
+    pub exclusive_cycles: u64,
+}
+
+/// Calls from one routine to another.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct EdgeStats {
+    pub calls: u64,
+    /// Cycles spent in the callee, including its own calls, when entered from
+    /// this caller.
+    pub cycles: u64,
+}
+
+/// Entry address of a routine on the shadow call stack. `None` stands for the
+/// top level code that was running before any call was made.
+pub type Routine = Option<u16>;
+
+#[derive(Copy, Clone, Debug)]
+struct Frame {
+    routine: u16,
+    /// Stack pointer before the return address was pushed.
+    return_sp: u16,
+    interrupt: bool,
+}
+
+/// A call-like instruction waiting for the next record to tell whether it
+/// pushed a return address.
+#[derive(Copy, Clone, Debug)]
+struct PendingCall {
+    sp: u16,
+    interrupt: bool,
+}
+
+#[derive(Default)]
+pub struct Profiler {
+    addresses: HashMap<u16, AddressStats>,
+    routines: HashMap<u16, RoutineStats>,
+    interrupts: HashMap<u16, RoutineStats>,
+    edges: HashMap<(Routine, u16), EdgeStats>,
+    stack: Vec<Frame>,
+    pending: Option<PendingCall>,
+    instructions: u64,
+    cycles: u64,
+    symbols: Option<Rc<SymbolTable>>,
+}
+
+impl Profiler {
+    pub fn new() -> Profiler {
+        Profiler::default()
+    }
+
+    /// Names addresses in the report using `symbols`.
+    pub fn with_symbols(self, symbols: Rc<SymbolTable>) -> Profiler {
+        Profiler {
+            symbols: Some(symbols),
+            ..self
+        }
+    }
+
+    /// Entry addresses of the routines currently being executed, outermost
+    /// first.
+    pub fn call_stack(&self) -> Vec<u16> {
+        self.stack.iter().map(|f| f.routine).collect()
+    }
+
+    pub fn total_instructions(&self) -> u64 {
+        self.instructions
+    }
+
+    pub fn total_cycles(&self) -> u64 {
+        self.cycles
+    }
+
+    /// Per-address counts, most cycles first.
+    pub fn hot_spots(&self) -> Vec<(u16, AddressStats)> {
+        let mut spots: Vec<_> = self.addresses.iter().map(|(&a, &s)| (a, s)).collect();
+        spots.sort_by(|a, b| b.1.cycles.cmp(&a.1.cycles).then(a.0.cmp(&b.0)));
+        spots
+    }
+
+    /// Per-routine counts, most inclusive cycles first.
+    pub fn routines(&self) -> Vec<(u16, RoutineStats)> {
+        sorted_routines(&self.routines)
+    }
+
+    /// Counts for routines entered through an interrupt, keyed by the vector
+    /// address, most inclusive cycles first.
+    pub fn interrupt_handlers(&self) -> Vec<(u16, RoutineStats)> {
+        sorted_routines(&self.interrupts)
+    }
+
+    /// Caller/callee pairs, most callee cycles first.
+    pub fn call_graph(&self) -> Vec<(Routine, u16, EdgeStats)> {
+        let mut edges: Vec<_> = self
+            .edges
+            .iter()
+            .map(|(&(caller, callee), &stats)| (caller, callee, stats))
+            .collect();
+        edges.sort_by(|a, b| {
+            b.2.cycles
+                .cmp(&a.2.cycles)
+                .then(a.0.cmp(&b.0))
+                .then(a.1.cmp(&b.1))
+        });
+        edges
+    }
+
+    /// Renders a plain text report listing the `limit` hottest addresses and
+    /// routines, the interrupt handlers and the call graph.
+    pub fn report(&self, limit: usize) -> String {
+        let mut out = String::new();
+        let percent = |cycles: u64| match self.cycles {
+            0 => 0.0,
+            total => cycles as f64 * 100.0 / total as f64,
+        };
+        writeln!(
+            out,
+            "{} instructions, {} cycles",
+            self.instructions, self.cycles
+        )
+        .unwrap();
+
+        writeln!(out, "\nHot spots:").unwrap();
+        writeln!(
+            out,
+            "  {:>6}  {:>12}  {:>12}  {:>6}",
+            "addr", "count", "cycles", "%"
+        )
+        .unwrap();
+        for (addr, stats) in self.hot_spots().into_iter().take(limit) {
+            writeln!(
+                out,
+                "  {:04x}    {:>12}  {:>12}  {:>6.2}  {}",
+                addr,
+                stats.instructions,
+                stats.cycles,
+                percent(stats.cycles),
+                self.label(addr)
+            )
+            .unwrap();
+        }
+
+        let routine_table = |out: &mut String, title: &str, routines: Vec<(u16, RoutineStats)>| {
+            writeln!(out, "\n{}:", title).unwrap();
+            writeln!(
+                out,
+                "  {:>6}  {:>10}  {:>12}  {:>6}  {:>12}  {:>6}",
+                "addr", "calls", "inclusive", "%", "exclusive", "%"
+            )
+            .unwrap();
+            for (addr, stats) in routines.into_iter().take(limit) {
+                writeln!(
+                    out,
+                    "  {:04x}    {:>10}  {:>12}  {:>6.2}  {:>12}  {:>6.2}  {}",
+                    addr,
+                    stats.calls,
+                    stats.inclusive_cycles,
+                    percent(stats.inclusive_cycles),
+                    stats.exclusive_cycles,
+                    percent(stats.exclusive_cycles),
+                    self.label(addr)
+                )
+                .unwrap();
+            }
+        };
+        routine_table(&mut out, "Routines", self.routines());
+        routine_table(&mut out, "Interrupt handlers", self.interrupt_handlers());
+
+        writeln!(out, "\nCall graph:").unwrap();
+        for (caller, callee, stats) in self.call_graph() {
+            let caller = match caller {
+                Some(addr) => self.name(addr),
+                None => "<top>".to_string(),
+            };
+            writeln!(
+                out,
+                "  {} -> {}  calls {:>10}  cycles {:>12}",
+                caller,
+                self.name(callee),
+                stats.calls,
+                stats.cycles
+            )
+            .unwrap();
+        }
+        out.lines()
+            .map(|line| format!("{}\n", line.trim_end()))
+            .collect()
+    }
+
+    fn label(&self, addr: u16) -> String {
+        self.symbols
+            .as_ref()
+            .and_then(|s| s.label(addr))
+            .unwrap_or_default()
+    }
+
+    fn name(&self, addr: u16) -> String {
+        match &self.symbols {
+            Some(symbols) => symbols.describe(addr),
+            None => format!("{:04x}", addr),
+        }
+    }
+
+    fn current_routine(&self) -> Routine {
+        self.stack.last().map(|f| f.routine)
+    }
+
+    fn enter(&mut self, routine: u16, return_sp: u16, interrupt: bool) {
+        let caller = self.current_routine();
+        self.routines.entry(routine).or_default().calls += 1;
+        if interrupt {
+            self.interrupts.entry(routine).or_default().calls += 1;
+        }
+        self.edges.entry((caller, routine)).or_default().calls += 1;
+        self.stack.push(Frame {
+            routine,
+            return_sp,
+            interrupt,
+        });
+    }
+
+    fn attribute(&mut self, cycles: u64) {
+        if let Some(top) = self.stack.last() {
+            self.routines
+                .entry(top.routine)
+                .or_default()
+                .exclusive_cycles += cycles;
+        }
+        // Recursive routines appear on the stack more than once but should
+        // only be charged once.
+        let mut seen_routines = Vec::with_capacity(self.stack.len());
+        let mut seen_edges = Vec::with_capacity(self.stack.len());
+        let mut caller = None;
+        for frame in &self.stack {
+            if !seen_routines.contains(&frame.routine) {
+                seen_routines.push(frame.routine);
+                self.routines
+                    .entry(frame.routine)
+                    .or_default()
+                    .inclusive_cycles += cycles;
+                if frame.interrupt {
+                    self.interrupts
+                        .entry(frame.routine)
+                        .or_default()
+                        .inclusive_cycles += cycles;
+                }
+            }
+            let edge = (caller, frame.routine);
+            if !seen_edges.contains(&edge) {
+                seen_edges.push(edge);
+                self.edges.entry(edge).or_default().cycles += cycles;
+            }
+            caller = Some(frame.routine);
+        }
+        if let Some(top) = self.stack.last().filter(|f| f.interrupt) {
+            self.interrupts
+                .entry(top.routine)
+                .or_default()
+                .exclusive_cycles += cycles;
+        }
+    }
+}
+
+fn sorted_routines(routines: &HashMap<u16, RoutineStats>) -> Vec<(u16, RoutineStats)> {
+    let mut routines: Vec<_> = routines.iter().map(|(&a, &s)| (a, s)).collect();
+    routines.sort_by(|a, b| {
+        b.1.inclusive_cycles
+            .cmp(&a.1.inclusive_cycles)
+            .then(a.0.cmp(&b.0))
+    });
+    routines
+}
+
+impl Tracer for Profiler {
+    fn trace(&mut self, record: &TraceRecord) -> Result<(), Error> {
+        use crate::instruction::Opcode::*;
+
+        let sp = record.registers.sp;
+        if let Some(call) = self.pending.take() {
+            if sp == call.sp.wrapping_sub(2) {
+                self.enter(record.pc, call.sp, call.interrupt);
+            }
+        }
+        while let Some(frame) = self.stack.last() {
+            if sp < frame.return_sp {
+                break;
+            }
+            self.stack.pop();
+        }
+
+        let cycles = u64::from(record.cycles);
+        self.instructions += 1;
+        self.cycles += cycles;
+        let stats = self.addresses.entry(record.pc).or_default();
+        stats.instructions += 1;
+        stats.cycles += cycles;
+        self.attribute(cycles);
+
+        let is_call = matches!(
+            record.instruction.opcode(),
+            CALL | CNZ | CZ | CNC | CC | CPO | CPE | CP | CM | RST(_)
+        );
+        if is_call || record.interrupt {
+            self.pending = Some(PendingCall {
+                sp,
+                interrupt: record.interrupt,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Profiler;
+    use crate::Emulator;
+    use std::{cell::RefCell, rc::Rc};
+
+    #[test]
+    fn profiles_calls() {
+        let bytecode = [
+            0x31, 0x00, 0x24, // 0000: LXI SP, 0x2400
+            0xcd, 0x0a, 0x00, // 0003: CALL 0x000a
+            0xcd, 0x0a, 0x00, // 0006: CALL 0x000a
+            0x00, //             0009: NOP
+            0xcd, 0x0e, 0x00, // 000a: CALL 0x000e
+            0xc9, //             000d: RET
+            0x00, //             000e: NOP
+            0xc9, //             000f: RET
+        ];
+        let profiler = Rc::new(RefCell::new(Profiler::new()));
+        let mut system = Emulator::new(bytecode).unwrap();
+        system.add_tracer(profiler.clone());
+        for _ in 0..12 {
+            system.step();
+        }
+        let profiler = profiler.borrow();
+
+        // LXI, CALL, [CALL, [NOP, RET], RET], CALL, [CALL, [NOP, RET], RET], NOP
+        assert_eq!(profiler.total_instructions(), 12);
+        assert_eq!(
+            profiler.total_cycles(),
+            10 + 17 + 2 * (17 + 4 + 10 + 10) + 17 + 4
+        );
+
+        let routines = profiler.routines();
+        assert_eq!(routines[0].0, 0x000a);
+        assert_eq!(routines[0].1.calls, 2);
+        assert_eq!(routines[0].1.inclusive_cycles, 2 * (17 + 4 + 10 + 10));
+        assert_eq!(routines[0].1.exclusive_cycles, 2 * (17 + 10));
+        assert_eq!(routines[1].0, 0x000e);
+        assert_eq!(routines[1].1.exclusive_cycles, 2 * (4 + 10));
+
+        let graph = profiler.call_graph();
+        assert_eq!(graph[0].0, None);
+        assert_eq!(graph[0].1, 0x000a);
+        assert_eq!(graph[0].2.calls, 2);
+        assert_eq!(graph[1].0, Some(0x000a));
+        assert_eq!(graph[1].1, 0x000e);
+
+        let hot = profiler.hot_spots();
+        assert_eq!(hot[0].0, 0x000a);
+        assert_eq!(hot[0].1.instructions, 2);
+    }
+}
@@ -0,0 +1,398 @@
+//! Loading ROM images from dumps of their individual chips.
+//!
+//! A `RomSet` describes which chip files make up a program ROM, how large
+//! each one is, its CRC32 and where it sits in the address space, in the same
+//! terms as MAME's ROM definitions. `RomSet::load` assembles the image from a
+//! directory or a .zip archive, checking every chip along the way.
+
+use failure::{format_err, Error};
+use std::{
+    fs::{self, File},
+    io::Read,
+    path::{Path, PathBuf},
+};
+use zip::ZipArchive;
+
+pub mod database;
+// `failure_derive` puts its impls inside an anonymous const.
+#[allow(non_local_definitions)]
+mod error;
+pub use self::error::RomSetError;
+
+/// One ROM chip of a set.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Chip {
+    /// File name of the chip dump, matched case-insensitively.
+    pub name: &'static str,
+    /// Offset of the chip's first byte in the assembled image.
+    pub offset: usize,
+    pub size: usize,
+    pub crc32: u32,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RomSet {
+    /// Short name, which is also the usual name of the set's .zip file.
+    pub name: &'static str,
+    pub description: &'static str,
+    pub chips: &'static [Chip],
+}
+
+/// Space Invaders (Midway, SV Version).
+pub const INVADERS: RomSet = RomSet {
+    name: "invaders",
+    description: "Space Invaders / Space Invaders M",
+    chips: &[
+        Chip {
+            name: "invaders.h",
+            offset: 0x0000,
+            size: 0x0800,
+            crc32: 0x734f_5ad8,
+        },
+        Chip {
+            name: "invaders.g",
+            offset: 0x0800,
+            size: 0x0800,
+            crc32: 0x6bfa_ca4a,
+        },
+        Chip {
+            name: "invaders.f",
+            offset: 0x1000,
+            size: 0x0800,
+            crc32: 0x0cce_ad96,
+        },
+        Chip {
+            name: "invaders.e",
+            offset: 0x1800,
+            size: 0x0800,
+            crc32: 0x14e5_38b0,
+        },
+    ],
+};
+
+/// Space Invaders (TV Version), a Midway revision with a TV test pattern.
+pub const SITV: RomSet = RomSet {
+    name: "sitv",
+    description: "Space Invaders (TV Version)",
+    chips: &[
+        Chip {
+            name: "tv0h.s1",
+            offset: 0x0000,
+            size: 0x0800,
+            crc32: 0xfef1_8aad,
+        },
+        Chip {
+            name: "tv02.rp1",
+            offset: 0x0800,
+            size: 0x0800,
+            crc32: 0x3c75_9a90,
+        },
+        Chip {
+            name: "tv03.n1",
+            offset: 0x1000,
+            size: 0x0800,
+            crc32: 0x0ad3_657f,
+        },
+        Chip {
+            name: "tv04.m1",
+            offset: 0x1800,
+            size: 0x0800,
+            crc32: 0xcd2c_67f6,
+        },
+    ],
+};
+
+/// Space Invaders Part II (Taito). The fifth chip sits above RAM at 0x4000.
+pub const INVADPT2: RomSet = RomSet {
+    name: "invadpt2",
+    description: "Space Invaders Part II (Taito)",
+    chips: &[
+        Chip {
+            name: "pv01",
+            offset: 0x0000,
+            size: 0x0800,
+            crc32: 0x7288_a511,
+        },
+        Chip {
+            name: "pv02",
+            offset: 0x0800,
+            size: 0x0800,
+            crc32: 0x097d_d8d5,
+        },
+        Chip {
+            name: "pv03",
+            offset: 0x1000,
+            size: 0x0800,
+            crc32: 0x1766_337e,
+        },
+        Chip {
+            name: "pv04",
+            offset: 0x1800,
+            size: 0x0800,
+            crc32: 0x8f0e_62e0,
+        },
+        Chip {
+            name: "pv05",
+            offset: 0x4000,
+            size: 0x0800,
+            crc32: 0x19b5_05e9,
+        },
+    ],
+};
+
+impl RomSet {
+    /// Size of the assembled image.
+    pub fn len(&self) -> usize {
+        self.chips
+            .iter()
+            .map(|c| c.offset + c.size)
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Loads and verifies the set from a directory or .zip file.
+    pub fn load<P: AsRef<Path>>(&self, path: P) -> Result<Vec<u8>, Error> {
+        self.load_from(&mut RomSource::open(path)?)
+    }
+
+    /// Assembles the image from chips found in `source`.
+    ///
+    /// A chip is looked up by name first. Failing that, any file of the right
+    /// size and CRC32 is used, so renamed dumps still load.
+    ///
+    /// #Errors
+    /// Fails with a `RomSetError` if a chip is missing, or is present under its
+    /// own name with the wrong size or CRC32.
+    pub fn load_from(&self, source: &mut RomSource) -> Result<Vec<u8>, Error> {
+        let mut image = vec![0; self.len()];
+        for chip in self.chips {
+            let data = match source.read(chip.name)? {
+                Some(data) => data,
+                None => match source.find(chip.size, chip.crc32)? {
+                    Some(data) => data,
+                    None => {
+                        return Err(RomSetError::MissingChip {
+                            set: self.name,
+                            chip: chip.name,
+                        }
+                        .into())
+                    }
+                },
+            };
+            chip.verify(&data)?;
+            image[chip.offset..chip.offset + chip.size].copy_from_slice(&data);
+        }
+        Ok(image)
+    }
+}
+
+impl Chip {
+    pub fn verify(&self, data: &[u8]) -> Result<(), RomSetError> {
+        if data.len() != self.size {
+            return Err(RomSetError::BadSize {
+                chip: self.name,
+                expected: self.size,
+                actual: data.len(),
+            });
+        }
+        let crc32 = crc32fast::hash(data);
+        if crc32 != self.crc32 {
+            return Err(RomSetError::BadChecksum {
+                chip: self.name,
+                expected: self.crc32,
+                actual: crc32,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// A directory or .zip archive holding ROM chip dumps.
+pub enum RomSource {
+    Directory(PathBuf),
+    Zip(ZipArchive<File>),
+}
+
+impl RomSource {
+    /// Opens `path` as a directory if it is one, and as a .zip archive
+    /// otherwise.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<RomSource, Error> {
+        let path = path.as_ref();
+        if path.is_dir() {
+            Ok(RomSource::Directory(path.to_path_buf()))
+        } else {
+            let file = File::open(path)
+                .map_err(|e| format_err!("unable to open {}: {}", path.display(), e))?;
+            let archive = ZipArchive::new(file)
+                .map_err(|e| format_err!("{} is not a zip archive: {}", path.display(), e))?;
+            Ok(RomSource::Zip(archive))
+        }
+    }
+
+    /// Names of every file in the source, without any directory part.
+    pub fn names(&mut self) -> Result<Vec<String>, Error> {
+        let mut names = Vec::new();
+        match self {
+            RomSource::Directory(dir) => {
+                for entry in fs::read_dir(dir)? {
+                    let entry = entry?;
+                    if entry.file_type()?.is_file() {
+                        names.push(entry.file_name().to_string_lossy().into_owned());
+                    }
+                }
+            }
+            RomSource::Zip(archive) => {
+                for i in 0..archive.len() {
+                    let file = archive.by_index(i)?;
+                    if !file.is_dir() {
+                        names.push(base_name(file.name()).to_string());
+                    }
+                }
+            }
+        }
+        names.sort();
+        Ok(names)
+    }
+
+    /// Reads the file called `name`, ignoring case, if there is one.
+    pub fn read(&mut self, name: &str) -> Result<Option<Vec<u8>>, Error> {
+        let found = self
+            .names()?
+            .into_iter()
+            .find(|n| n.eq_ignore_ascii_case(name));
+        let found = match found {
+            Some(found) => found,
+            None => return Ok(None),
+        };
+        let mut data = Vec::new();
+        match self {
+            RomSource::Directory(dir) => data = fs::read(dir.join(found))?,
+            RomSource::Zip(archive) => {
+                for i in 0..archive.len() {
+                    let mut file = archive.by_index(i)?;
+                    if !file.is_dir() && base_name(file.name()) == found {
+                        file.read_to_end(&mut data)?;
+                        break;
+                    }
+                }
+            }
+        }
+        Ok(Some(data))
+    }
+
+    /// Finds a file by content rather than name.
+    pub fn find(&mut self, size: usize, crc32: u32) -> Result<Option<Vec<u8>>, Error> {
+        for name in self.names()? {
+            if let Some(data) = self.read(&name)? {
+                if data.len() == size && crc32fast::hash(&data) == crc32 {
+                    return Ok(Some(data));
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+fn base_name(name: &str) -> &str {
+    name.rsplit(['/', '\\']).next().unwrap_or(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Chip, RomSet, RomSetError};
+    use std::{
+        fs::{self, File},
+        io::Write,
+        path::PathBuf,
+    };
+    use zip::{write::FileOptions, ZipWriter};
+
+    const CHIP_A: [u8; 4] = [0x01, 0x02, 0x03, 0x04];
+    const CHIP_B: [u8; 4] = [0xa0, 0xb0, 0xc0, 0xd0];
+
+    fn test_set() -> RomSet {
+        RomSet {
+            name: "test",
+            description: "Test set",
+            chips: Box::leak(Box::new([
+                Chip {
+                    name: "test.a",
+                    offset: 0,
+                    size: 4,
+                    crc32: crc32fast::hash(&CHIP_A),
+                },
+                Chip {
+                    name: "test.b",
+                    offset: 4,
+                    size: 4,
+                    crc32: crc32fast::hash(&CHIP_B),
+                },
+            ])),
+        }
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("i8080_rom_set_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn loads_directory() {
+        let dir = temp_dir("dir");
+        fs::write(dir.join("TEST.A"), CHIP_A).unwrap();
+        // Renamed dumps are found by CRC32.
+        fs::write(dir.join("renamed.bin"), CHIP_B).unwrap();
+        let image = test_set().load(&dir).unwrap();
+        assert_eq!(image, [CHIP_A, CHIP_B].concat());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn loads_zip() {
+        let dir = temp_dir("zip");
+        let path = dir.join("test.zip");
+        let mut zip = ZipWriter::new(File::create(&path).unwrap());
+        for (name, data) in &[("test/test.b", &CHIP_B), ("test.a", &CHIP_A)] {
+            zip.start_file(*name, FileOptions::default()).unwrap();
+            zip.write_all(&data[..]).unwrap();
+        }
+        zip.finish().unwrap();
+        let image = test_set().load(&path).unwrap();
+        assert_eq!(image, [CHIP_A, CHIP_B].concat());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn reports_bad_dumps() {
+        let dir = temp_dir("bad");
+        fs::write(dir.join("test.a"), CHIP_A).unwrap();
+        let err = test_set().load(&dir).unwrap_err();
+        match err.downcast::<RomSetError>().unwrap() {
+            RomSetError::MissingChip { chip, .. } => assert_eq!(chip, "test.b"),
+            e => panic!("unexpected error {}", e),
+        }
+
+        fs::write(dir.join("test.b"), [0xa0, 0xb0, 0xc0]).unwrap();
+        let err = test_set().load(&dir).unwrap_err();
+        assert_eq!(err.to_string(), "test.b: bad dump, 3 bytes but expected 4");
+
+        fs::write(dir.join("test.b"), [0xa0, 0xb0, 0xc0, 0xd1]).unwrap();
+        let err = test_set().load(&dir).unwrap_err();
+        assert!(err.to_string().starts_with("test.b: bad dump, CRC32 is"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn sets_match_layouts() {
+        assert_eq!(super::INVADERS.len(), 0x2000);
+        assert_eq!(super::SITV.len(), 0x2000);
+        assert_eq!(super::INVADPT2.len(), 0x4800);
+    }
+}
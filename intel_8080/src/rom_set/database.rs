@@ -0,0 +1,279 @@
+//! Known games and the boards they run on.
+//!
+//! Each `Game` pairs a `RomSet` with the memory layout, port assignments and
+//! default DIP switch settings of its board, so a machine can be built from
+//! whatever chip dumps `identify` recognises.
+
+use super::{
+    RomSet, RomSource, INVADERS as INVADERS_ROMS, INVADPT2 as INVADPT2_ROMS, SITV as SITV_ROMS,
+};
+use crate::{
+    interconnect::Interconnect,
+    io::basic_io::{BasicIO, PortMap, SPACE_INVADERS_INPUTS, SPACE_INVADERS_PORTS},
+    mmu::{basic_mmu::mem_map::*, basic_mmu::BasicMMU, MemoryMap, Rom},
+    Emulator,
+};
+
+use failure::{bail, Error};
+
+/// Address ranges of a board, all inclusive.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MemoryLayout {
+    pub rom: (u16, u16),
+    pub ram: &'static [(u16, u16)],
+    /// `(start, end, source)` ranges aliasing the range at `source`.
+    pub mirrors: &'static [(u16, u16, u16)],
+    pub open_bus: u8,
+}
+
+impl MemoryLayout {
+    /// #Errors
+    /// Fails if `rom` does not fit the ROM region.
+    pub fn build<T: Into<Rom>>(&self, rom: T) -> Result<MemoryMap, Error> {
+        let mut builder = MemoryMap::builder().rom(self.rom.0, self.rom.1, rom);
+        for &(start, end) in self.ram {
+            builder = builder.ram(start, end);
+        }
+        for &(start, end, source) in self.mirrors {
+            builder = builder.mirror(start, end, source);
+        }
+        builder.open_bus(self.open_bus).build()
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Game {
+    pub rom_set: RomSet,
+    pub memory: MemoryLayout,
+    pub ports: PortMap,
+    /// Idle values of the input ports, including the default DIP switch
+    /// settings.
+    pub inputs: [u8; 3],
+}
+
+/// Space Invaders on the Midway 8080 board.
+pub const INVADERS: Game = Game {
+    rom_set: INVADERS_ROMS,
+    memory: MemoryLayout {
+        rom: (ROM_START, ROM_END),
+        ram: &[(WRAM_START, WRAM_END), (VRAM_START, VRAM_END)],
+        mirrors: &[(RAM_MIRROR_START, RAM_MIRROR_END, WRAM_START)],
+        open_bus: OPEN_BUS,
+    },
+    ports: SPACE_INVADERS_PORTS,
+    inputs: SPACE_INVADERS_INPUTS,
+};
+
+/// The TV Version runs on the same board as the original.
+pub const SITV: Game = Game {
+    rom_set: SITV_ROMS,
+    ..INVADERS
+};
+
+/// Space Invaders Part II on Taito's colour board: a fifth ROM at 0x4000 in
+/// place of the RAM mirror, and colour RAM at 0xc000.
+pub const INVADPT2: Game = Game {
+    rom_set: INVADPT2_ROMS,
+    memory: MemoryLayout {
+        rom: (ROM_START, 0x5fff),
+        ram: &[
+            (WRAM_START, WRAM_END),
+            (VRAM_START, VRAM_END),
+            (0xc000, 0xdfff),
+        ],
+        mirrors: &[],
+        open_bus: OPEN_BUS,
+    },
+    ports: SPACE_INVADERS_PORTS,
+    inputs: SPACE_INVADERS_INPUTS,
+};
+
+pub const GAMES: &[Game] = &[INVADERS, SITV, INVADPT2];
+
+impl Game {
+    /// Builds the game's machine around an image loaded from its ROM set.
+    ///
+    /// #Errors
+    /// Fails if `rom` does not fit the board's ROM region.
+    pub fn emulator<T: Into<Rom>>(&self, rom: T) -> Result<Emulator<BasicMMU, BasicIO>, Error> {
+        let mmu = BasicMMU::from_map(self.memory.build(rom)?);
+        let io = BasicIO::new(self.ports, self.inputs);
+        Ok(Emulator::with_interconnect(Interconnect::from_parts(
+            mmu, io,
+        )))
+    }
+}
+
+/// Looks up a game by its ROM set name, ignoring case.
+pub fn find(name: &str) -> Option<&'static Game> {
+    GAMES
+        .iter()
+        .find(|g| g.rom_set.name.eq_ignore_ascii_case(name))
+}
+
+/// Works out which known game the dumps in `source` belong to and loads its
+/// image.
+///
+/// A chip counts as present if a file has its name, or its size and CRC32.
+/// The game with a complete set, or failing that the most chips present, is
+/// loaded.
+///
+/// #Errors
+/// Fails if no chip of any known game is present, or with the best match's
+/// `RomSetError` if its dumps are incomplete or bad.
+pub fn identify(source: &mut RomSource) -> Result<(&'static Game, Vec<u8>), Error> {
+    identify_from(GAMES, source)
+}
+
+fn identify_from<'a>(
+    games: &'a [Game],
+    source: &mut RomSource,
+) -> Result<(&'a Game, Vec<u8>), Error> {
+    let mut files = Vec::new();
+    for name in source.names()? {
+        if let Some(data) = source.read(&name)? {
+            files.push((name, data.len(), crc32fast::hash(&data)));
+        }
+    }
+    match best_match(games, &files) {
+        Some(game) => Ok((game, game.rom_set.load_from(source)?)),
+        None => {
+            let known: Vec<_> = games.iter().map(|g| g.rom_set.name).collect();
+            bail!(
+                "no known ROM set found; known sets are {}",
+                known.join(", ")
+            )
+        }
+    }
+}
+
+/// The game whose chips best match `files`, given as name, size and CRC32.
+/// Complete sets win over partial ones, then the set with the most chips.
+fn best_match<'a>(games: &'a [Game], files: &[(String, usize, u32)]) -> Option<&'a Game> {
+    let matched = |game: &Game| {
+        game.rom_set
+            .chips
+            .iter()
+            .filter(|chip| {
+                files.iter().any(|(name, size, crc32)| {
+                    name.eq_ignore_ascii_case(chip.name)
+                        || (*size == chip.size && *crc32 == chip.crc32)
+                })
+            })
+            .count()
+    };
+    games
+        .iter()
+        .map(|game| (matched(game), game))
+        .filter(|&(count, _)| count > 0)
+        .max_by_key(|&(count, game)| (count == game.rom_set.chips.len(), count))
+        .map(|(_, game)| game)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{best_match, find, identify_from, Game, GAMES, INVADERS, INVADPT2, SITV};
+    use crate::{
+        mmu::Mmu,
+        rom_set::{Chip, RomSet, RomSource},
+    };
+    use std::fs;
+
+    const FIRST: [u8; 2] = [0x3e, 0x2a];
+    const SECOND: [u8; 2] = [0x00, 0x00];
+
+    fn game(name: &'static str, chips: &[(&'static str, &[u8])]) -> Game {
+        let chips: Vec<Chip> = chips
+            .iter()
+            .enumerate()
+            .map(|(i, (chip, data))| Chip {
+                name: chip,
+                offset: i * 2,
+                size: data.len(),
+                crc32: crc32fast::hash(data),
+            })
+            .collect();
+        Game {
+            rom_set: RomSet {
+                name,
+                description: name,
+                chips: Box::leak(chips.into_boxed_slice()),
+            },
+            ..INVADERS
+        }
+    }
+
+    #[test]
+    fn identifies_games() {
+        let games = [
+            game("first", &[("first.a", &FIRST)]),
+            game("second", &[("first.a", &FIRST), ("second.b", &SECOND)]),
+        ];
+        let dir = std::env::temp_dir().join(format!("i8080_database_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let identify = |games| identify_from(games, &mut RomSource::open(&dir).unwrap());
+        assert!(identify(&games).is_err());
+
+        fs::write(dir.join("first.a"), FIRST).unwrap();
+        let (game, rom) = identify(&games).unwrap();
+        assert_eq!(game.rom_set.name, "first");
+        assert_eq!(rom, FIRST);
+
+        fs::write(dir.join("renamed"), SECOND).unwrap();
+        let (game, rom) = identify(&games).unwrap();
+        assert_eq!(game.rom_set.name, "second");
+        assert_eq!(rom, [FIRST, SECOND].concat());
+
+        let mut system = game.emulator(rom).unwrap();
+        system.run();
+        assert_eq!(system.cpu().registers().a, 0x2a);
+        assert_eq!(system.mmu().rom_len(), 4);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn tells_known_games_apart() {
+        let dumps = |game: &Game, renamed: bool| -> Vec<(String, usize, u32)> {
+            game.rom_set
+                .chips
+                .iter()
+                .map(|chip| match renamed {
+                    true => (format!("{}.bin", chip.offset), chip.size, chip.crc32),
+                    false => (chip.name.to_string(), 0, 0),
+                })
+                .collect()
+        };
+        for &game in &[INVADERS, SITV, INVADPT2] {
+            assert_eq!(best_match(GAMES, &dumps(&game, false)), Some(&game));
+            assert_eq!(best_match(GAMES, &dumps(&game, true)), Some(&game));
+        }
+        // An incomplete set is still recognised, and fails when it loads.
+        let partial = &dumps(&INVADPT2, true)[..4];
+        assert_eq!(best_match(GAMES, partial), Some(&INVADPT2));
+        assert_eq!(best_match(GAMES, &[]), None);
+    }
+
+    #[test]
+    fn builds_part_ii_with_rom_above_ram() {
+        let mut rom = vec![0; INVADPT2.rom_set.len()];
+        rom[0x0000..0x0003].copy_from_slice(&[0xc3, 0x00, 0x40]); // JMP 0x4000
+        rom[0x4000..0x4006].copy_from_slice(&[
+            0x3e, 0x5a, //       MVI A, 0x5a
+            0x32, 0x00, 0xc0, // STA 0xc000
+            0x76, //             HLT
+        ]);
+        let mut system = INVADPT2.emulator(rom).unwrap();
+        system.run();
+        assert!(system.cpu().halted());
+        assert_eq!(system.mmu().read_byte(0xc000), 0x5a);
+    }
+
+    #[test]
+    fn finds_by_name() {
+        assert_eq!(find("Invaders"), Some(&INVADERS));
+        assert_eq!(find("invadpt2"), Some(&INVADPT2));
+        assert_eq!(find("galaxian"), None);
+    }
+}
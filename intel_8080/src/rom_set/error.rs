@@ -0,0 +1,28 @@
+use failure::Fail;
+
+#[derive(Debug, Fail)]
+pub enum RomSetError {
+    #[fail(display = "{}: missing ROM chip {}", set, chip)]
+    MissingChip {
+        set: &'static str,
+        chip: &'static str,
+    },
+    #[fail(
+        display = "{}: bad dump, {} bytes but expected {}",
+        chip, actual, expected
+    )]
+    BadSize {
+        chip: &'static str,
+        expected: usize,
+        actual: usize,
+    },
+    #[fail(
+        display = "{}: bad dump, CRC32 is {:08x} but expected {:08x}",
+        chip, actual, expected
+    )]
+    BadChecksum {
+        chip: &'static str,
+        expected: u32,
+        actual: u32,
+    },
+}
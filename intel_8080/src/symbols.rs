@@ -0,0 +1,290 @@
+//! Symbol tables mapping labels to addresses.
+//!
+//! `SymbolTable::parse` accepts several common formats, detected line by line:
+//!
+//! * `label = addr` or `label EQU addr` assignments
+//! * assembler `.sym` listings with whitespace separated `addr label` (or
+//!   `label addr`) pairs, several to a line
+//! * MAME debugger comment files (`.cmt`), where each
+//!   `<comment address="...">text</comment>` entry names its address with a
+//!   label made from the comment: runs of characters a label cannot hold
+//!   become `_`, so `game & loop` is `game_loop`
+//!
+//! Addresses may be written as `0x1234`, `$1234`, `1234h` or bare `1234`;
+//! bare numbers are hex, as in every assembler listing. MAME comment
+//! addresses are decimal. Lines starting with `;` or `#` are ignored.
+
+use failure::{bail, Error};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    path::Path,
+};
+
+/// Furthest distance past a symbol that `label` will still print as
+/// `label+offset` rather than a bare address.
+const MAX_OFFSET: u16 = 0x100;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SymbolTable {
+    by_addr: BTreeMap<u16, String>,
+    by_name: HashMap<String, u16>,
+}
+
+impl SymbolTable {
+    pub fn new() -> SymbolTable {
+        SymbolTable::default()
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<SymbolTable, Error> {
+        let path = path.as_ref();
+        match fs::read_to_string(path) {
+            Ok(text) => SymbolTable::parse(&text),
+            Err(e) => bail!("unable to read {}: {}", path.display(), e),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<SymbolTable, Error> {
+        let mut table = SymbolTable::new();
+        for (number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with(';') || line.starts_with('#') {
+                continue;
+            }
+            let symbols = if line.starts_with('<') {
+                parse_mame_comment(line)
+            } else if let Some((name, addr)) = split_assignment(line) {
+                parse_addr(addr).map(|addr| vec![(name.to_string(), addr)])
+            } else {
+                parse_pairs(line)
+            };
+            match symbols {
+                Some(symbols) => {
+                    for (name, addr) in symbols {
+                        table.insert(&name, addr);
+                    }
+                }
+                None => bail!("line {}: unrecognised symbol entry: {}", number + 1, line),
+            }
+        }
+        Ok(table)
+    }
+
+    /// Adds a symbol. If the address already has a name the first one is kept
+    /// for display, but both names resolve.
+    pub fn insert(&mut self, name: &str, addr: u16) {
+        self.by_addr.entry(addr).or_insert_with(|| name.to_string());
+        self.by_name.insert(name.to_string(), addr);
+    }
+
+    pub fn len(&self) -> usize {
+        self.by_name.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_name.is_empty()
+    }
+
+    /// Name of the symbol at exactly `addr`.
+    pub fn name(&self, addr: u16) -> Option<&str> {
+        self.by_addr.get(&addr).map(String::as_str)
+    }
+
+    /// Address of the symbol called `name`.
+    pub fn addr(&self, name: &str) -> Option<u16> {
+        self.by_name.get(name).cloned()
+    }
+
+    /// Nearest symbol at or before `addr`, with the distance from it.
+    pub fn lookup(&self, addr: u16) -> Option<(&str, u16)> {
+        self.by_addr
+            .range(..=addr)
+            .next_back()
+            .map(|(&start, name)| (name.as_str(), addr - start))
+    }
+
+    /// Formats `addr` as `label` or `label+0x12`, if a symbol is close enough.
+    pub fn label(&self, addr: u16) -> Option<String> {
+        match self.lookup(addr)? {
+            (name, 0) => Some(name.to_string()),
+            (name, offset) if offset < MAX_OFFSET => Some(format!("{}+0x{:x}", name, offset)),
+            _ => None,
+        }
+    }
+
+    /// Formats `addr` as a `label`, falling back to `0x1234`.
+    pub fn describe(&self, addr: u16) -> String {
+        self.label(addr)
+            .unwrap_or_else(|| format!("0x{:04x}", addr))
+    }
+
+    /// Resolves a user supplied location, either a symbol name or an address
+    /// in any of the formats accepted by `parse`.
+    pub fn resolve(&self, location: &str) -> Option<u16> {
+        self.addr(location).or_else(|| parse_addr(location))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (u16, &str)> {
+        self.by_addr.iter().map(|(&a, n)| (a, n.as_str()))
+    }
+}
+
+/// Parses `0x1234`, `$1234`, `1234h` or bare hex `1234`.
+pub fn parse_addr(text: &str) -> Option<u16> {
+    let text = text.trim();
+    let digits = if let Some(hex) = text
+        .strip_prefix("0x")
+        .or_else(|| text.strip_prefix("0X"))
+        .or_else(|| text.strip_prefix('$'))
+    {
+        hex
+    } else if let Some(hex) = text.strip_suffix('h').or_else(|| text.strip_suffix('H')) {
+        hex
+    } else {
+        text
+    };
+    if digits.is_empty() || digits.len() > 5 {
+        return None;
+    }
+    u16::from_str_radix(digits, 16).ok()
+}
+
+fn is_label(text: &str) -> bool {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' || c == '.' || c == '?' || c == '@' => {}
+        _ => return false,
+    }
+    chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.' || c == '?' || c == '@')
+}
+
+/// Splits `label = addr`, `label: EQU addr` and similar assignments.
+fn split_assignment(line: &str) -> Option<(&str, &str)> {
+    let (name, value) = match line.find('=') {
+        Some(i) => (&line[..i], &line[i + 1..]),
+        None => {
+            let mut words = line.split_whitespace();
+            let name = words.next()?;
+            if !words.next()?.eq_ignore_ascii_case("equ") {
+                return None;
+            }
+            let value = words.next()?;
+            if words.next().is_some() {
+                return None;
+            }
+            (name, value)
+        }
+    };
+    let name = name.trim().trim_end_matches(':');
+    if is_label(name) {
+        Some((name, value.trim()))
+    } else {
+        None
+    }
+}
+
+fn parse_pairs(line: &str) -> Option<Vec<(String, u16)>> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    if !words.len().is_multiple_of(2) {
+        return None;
+    }
+    words
+        .chunks(2)
+        .map(|pair| {
+            let (first, second) = (pair[0], pair[1].trim_end_matches(':'));
+            match (parse_addr(first), parse_addr(second)) {
+                (Some(addr), _) if is_label(second) => Some((second.to_string(), addr)),
+                (_, Some(addr)) if is_label(first.trim_end_matches(':')) => {
+                    Some((first.trim_end_matches(':').to_string(), addr))
+                }
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Reads one line of a MAME `.cmt` file. Lines other than `<comment>`
+/// entries are part of the XML structure and yield no symbols.
+fn parse_mame_comment(line: &str) -> Option<Vec<(String, u16)>> {
+    if !line.starts_with("<comment ") {
+        return Some(Vec::new());
+    }
+    let attr = line.find("address=\"")? + "address=\"".len();
+    let attr_end = attr + line[attr..].find('"')?;
+    let addr = line[attr..attr_end].parse::<u16>().ok()?;
+    let text_start = attr_end + line[attr_end..].find('>')? + 1;
+    let text_end = text_start + line[text_start..].find("</comment>")?;
+    let text = line[text_start..text_end]
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&");
+    let label = comment_label(&text);
+    Some(label.map(|label| (label, addr)).into_iter().collect())
+}
+
+/// Turns comment text into a label, or `None` if nothing usable is left.
+fn comment_label(text: &str) -> Option<String> {
+    let mut label = String::new();
+    for word in text.split(|c: char| !(c.is_ascii_alphanumeric() || c == '_')) {
+        if word.is_empty() {
+            continue;
+        }
+        if !label.is_empty() {
+            label.push('_');
+        }
+        label.push_str(word);
+    }
+    match label.chars().next() {
+        None => None,
+        Some(c) if c.is_ascii_digit() => Some(format!("_{}", label)),
+        Some(_) => Some(label),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_addr, SymbolTable};
+
+    #[test]
+    fn parses_formats() {
+        let table = SymbolTable::parse(
+            "; assignments\n\
+             RESET = 0x0000\n\
+             isr1: EQU 0008h\n\
+             0010 RST2     0018 RST3\n\
+             DrawSprite 15d3\n\
+             <mamecommentfile version=\"1\">\n\
+             <comment address=\"256\" color=\"16711680\">game &amp; loop</comment>\n\
+             <comment address=\"512\" color=\"255\">2nd \"pass\", ok?</comment>\n\
+             <comment address=\"768\" color=\"255\">--</comment>\n\
+             </mamecommentfile>\n",
+        )
+        .unwrap();
+        assert_eq!(table.addr("RESET"), Some(0x0000));
+        assert_eq!(table.addr("isr1"), Some(0x0008));
+        assert_eq!(table.addr("RST2"), Some(0x0010));
+        assert_eq!(table.addr("RST3"), Some(0x0018));
+        assert_eq!(table.addr("DrawSprite"), Some(0x15d3));
+        assert_eq!(table.name(0x0100), Some("game_loop"));
+        assert_eq!(table.name(0x0200), Some("_2nd_pass_ok"));
+        assert_eq!(table.name(0x0300), None);
+        assert!(SymbolTable::parse("what is this").is_err());
+    }
+
+    #[test]
+    fn describes_and_resolves() {
+        let mut table = SymbolTable::new();
+        table.insert("start", 0x0100);
+        table.insert("main", 0x0100);
+        assert_eq!(table.describe(0x0100), "start");
+        assert_eq!(table.describe(0x0112), "start+0x12");
+        assert_eq!(table.describe(0x0400), "0x0400");
+        assert_eq!(table.describe(0x0050), "0x0050");
+        assert_eq!(table.resolve("main"), Some(0x0100));
+        assert_eq!(table.resolve("$2000"), Some(0x2000));
+        assert_eq!(parse_addr("1fffH"), Some(0x1fff));
+        assert_eq!(parse_addr("10000"), None);
+    }
+}
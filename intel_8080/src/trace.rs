@@ -0,0 +1,469 @@
+//! Per-instruction execution tracing.
+//!
+//! Tracers registered with `Emulator::add_tracer` receive a `TraceRecord` for
+//! every executed instruction, holding the CPU state before the instruction
+//! ran and every memory and port access it made.
+
+use crate::{
+    i8080::Registers,
+    instruction::{Instruction, Syntax},
+    io::IO,
+    mmu::Mmu,
+    pic::InterruptController,
+    symbols::SymbolTable,
+};
+
+pub mod compare;
+
+use failure::Error;
+use std::{cell::RefCell, fmt::Write as FmtWrite, io::Write, rc::Rc};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AccessKind {
+    MemoryRead,
+    MemoryWrite,
+    PortIn,
+    PortOut,
+}
+
+impl AccessKind {
+    fn tag(self) -> char {
+        match self {
+            AccessKind::MemoryRead => 'R',
+            AccessKind::MemoryWrite => 'W',
+            AccessKind::PortIn => 'I',
+            AccessKind::PortOut => 'O',
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            AccessKind::MemoryRead => "read",
+            AccessKind::MemoryWrite => "write",
+            AccessKind::PortIn => "in",
+            AccessKind::PortOut => "out",
+        }
+    }
+}
+
+/// A single memory or port access made while executing an instruction.
+///
+/// For port accesses `addr` holds the port number.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Access {
+    pub kind: AccessKind,
+    pub addr: u16,
+    pub value: u8,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceRecord {
+    /// Number of instructions executed before this one.
+    pub step: u64,
+    /// Address the instruction was fetched from.
+    pub pc: u16,
+    /// Raw opcode and operand bytes.
+    pub bytes: Vec<u8>,
+    pub instruction: Instruction,
+    /// Set when the instruction was injected by the interrupt controller.
+    pub interrupt: bool,
+    /// CPU state before the instruction executed.
+    pub registers: Registers,
+    /// Clock cycles the instruction took.
+    pub cycles: u8,
+    /// Memory and port accesses in the order they were made, excluding the
+    /// instruction fetch itself.
+    pub accesses: Vec<Access>,
+}
+
+pub trait Tracer {
+    fn trace(&mut self, record: &TraceRecord) -> Result<(), Error>;
+}
+
+impl<T: Tracer + ?Sized> Tracer for Box<T> {
+    fn trace(&mut self, record: &TraceRecord) -> Result<(), Error> {
+        (**self).trace(record)
+    }
+}
+
+/// Lets a tracer be shared with the caller, so its results can be read back
+/// after the emulator has run.
+impl<T: Tracer + ?Sized> Tracer for Rc<RefCell<T>> {
+    fn trace(&mut self, record: &TraceRecord) -> Result<(), Error> {
+        self.borrow_mut().trace(record)
+    }
+}
+
+/// Collects every record in memory.
+impl Tracer for Vec<TraceRecord> {
+    fn trace(&mut self, record: &TraceRecord) -> Result<(), Error> {
+        self.push(record.clone());
+        Ok(())
+    }
+}
+
+/// Writes one JSON object per instruction.
+///
+/// All numbers are written as fixed width lowercase hex strings so the output
+/// can be grepped and diffed as easily as it can be parsed.
+pub struct JsonLinesTracer<W: Write> {
+    out: W,
+    syntax: Syntax,
+    symbols: Option<Rc<SymbolTable>>,
+}
+
+impl<W: Write> JsonLinesTracer<W> {
+    pub fn new(out: W) -> JsonLinesTracer<W> {
+        JsonLinesTracer {
+            out,
+            syntax: Syntax::Intel,
+            symbols: None,
+        }
+    }
+
+    pub fn with_syntax(self, syntax: Syntax) -> JsonLinesTracer<W> {
+        JsonLinesTracer { syntax, ..self }
+    }
+
+    /// Adds a `symbol` field naming the PC and prints operands by name.
+    pub fn with_symbols(self, symbols: Rc<SymbolTable>) -> JsonLinesTracer<W> {
+        JsonLinesTracer {
+            symbols: Some(symbols),
+            ..self
+        }
+    }
+}
+
+impl<W: Write> Tracer for JsonLinesTracer<W> {
+    fn trace(&mut self, record: &TraceRecord) -> Result<(), Error> {
+        let r = &record.registers;
+        let mut accesses = String::new();
+        for (i, access) in record.accesses.iter().enumerate() {
+            if i > 0 {
+                accesses.push(',');
+            }
+            write!(
+                accesses,
+                r#"{{"kind":"{}","addr":"{:04x}","value":"{:02x}"}}"#,
+                access.kind.name(),
+                access.addr,
+                access.value
+            )?;
+        }
+        let disassembly = instruction(record, self.syntax, self.symbols.as_deref());
+        let symbol = match &self.symbols {
+            Some(symbols) => format!(
+                r#""symbol":{},"#,
+                quote(&symbols.label(record.pc).unwrap_or_default(), Quoting::Json)
+            ),
+            None => String::new(),
+        };
+        writeln!(
+            self.out,
+            concat!(
+                r#"{{"step":{},"pc":"{:04x}",{}"bytes":"{}","instruction":{},"interrupt":{},"cycles":{},"#,
+                r#""a":"{:02x}","f":"{:02x}","b":"{:02x}","c":"{:02x}","d":"{:02x}","e":"{:02x}","#,
+                r#""h":"{:02x}","l":"{:02x}","sp":"{:04x}","accesses":[{}]}}"#
+            ),
+            record.step,
+            record.pc,
+            symbol,
+            hex(&record.bytes),
+            quote(&disassembly, Quoting::Json),
+            record.interrupt,
+            record.cycles,
+            r.a,
+            u8::from(r.flags),
+            r.b,
+            r.c,
+            r.d,
+            r.e,
+            r.h,
+            r.l,
+            r.sp,
+            accesses,
+        )?;
+        Ok(())
+    }
+}
+
+/// Writes a CSV file with a header row and one row per instruction.
+///
+/// Accesses are packed into the last column as space separated
+/// `<kind>:<addr>=<value>` entries, where kind is one of `R`, `W`, `I` or `O`.
+pub struct CsvTracer<W: Write> {
+    out: W,
+    syntax: Syntax,
+    symbols: Option<Rc<SymbolTable>>,
+    wrote_header: bool,
+}
+
+impl<W: Write> CsvTracer<W> {
+    pub fn new(out: W) -> CsvTracer<W> {
+        CsvTracer {
+            out,
+            syntax: Syntax::Intel,
+            symbols: None,
+            wrote_header: false,
+        }
+    }
+
+    pub fn with_syntax(self, syntax: Syntax) -> CsvTracer<W> {
+        CsvTracer { syntax, ..self }
+    }
+
+    /// Adds a `symbol` column naming the PC and prints operands by name.
+    pub fn with_symbols(self, symbols: Rc<SymbolTable>) -> CsvTracer<W> {
+        CsvTracer {
+            symbols: Some(symbols),
+            ..self
+        }
+    }
+}
+
+impl<W: Write> Tracer for CsvTracer<W> {
+    fn trace(&mut self, record: &TraceRecord) -> Result<(), Error> {
+        if !self.wrote_header {
+            let symbol = if self.symbols.is_some() { "symbol," } else { "" };
+            writeln!(
+                self.out,
+                "step,pc,{}bytes,instruction,interrupt,cycles,a,f,b,c,d,e,h,l,sp,accesses",
+                symbol
+            )?;
+            self.wrote_header = true;
+        }
+        let r = &record.registers;
+        let disassembly = instruction(record, self.syntax, self.symbols.as_deref());
+        let symbol = match &self.symbols {
+            Some(symbols) => format!(
+                "{},",
+                quote(&symbols.label(record.pc).unwrap_or_default(), Quoting::Csv)
+            ),
+            None => String::new(),
+        };
+        let accesses: Vec<String> = record
+            .accesses
+            .iter()
+            .map(|a| format!("{}:{:04x}={:02x}", a.kind.tag(), a.addr, a.value))
+            .collect();
+        writeln!(
+            self.out,
+            "{},{:04x},{}{},{},{},{},{:02x},{:02x},{:02x},{:02x},{:02x},{:02x},{:02x},{:02x},{:04x},{}",
+            record.step,
+            record.pc,
+            symbol,
+            hex(&record.bytes),
+            quote(&disassembly, Quoting::Csv),
+            record.interrupt,
+            record.cycles,
+            r.a,
+            u8::from(r.flags),
+            r.b,
+            r.c,
+            r.d,
+            r.e,
+            r.h,
+            r.l,
+            r.sp,
+            accesses.join(" "),
+        )?;
+        Ok(())
+    }
+}
+
+fn instruction(record: &TraceRecord, syntax: Syntax, symbols: Option<&SymbolTable>) -> String {
+    let display = record.instruction.display(syntax);
+    let text = match symbols {
+        Some(symbols) => display.with_symbols(symbols).to_string(),
+        None => display.to_string(),
+    };
+    text.trim_end().to_string()
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Quoting {
+    Json,
+    Csv,
+}
+
+/// Quotes `text` as a JSON string or a CSV field, escaping whatever in it
+/// would otherwise end the string or field early. Symbol names can hold
+/// anything.
+fn quote(text: &str, quoting: Quoting) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for c in text.chars() {
+        match (quoting, c) {
+            (Quoting::Json, '"') | (Quoting::Json, '\\') => {
+                quoted.push('\\');
+                quoted.push(c);
+            }
+            (Quoting::Json, c) if c.is_control() => {
+                let _ = write!(quoted, "\\u{:04x}", u32::from(c));
+            }
+            (Quoting::Csv, '"') => quoted.push_str("\"\""),
+            (_, c) => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Wraps an `Mmu` or `IO` and logs every access made through it.
+pub(crate) struct Recorder<'a, T> {
+    inner: &'a mut T,
+    log: &'a RefCell<Vec<Access>>,
+}
+
+impl<'a, T> Recorder<'a, T> {
+    pub(crate) fn new(inner: &'a mut T, log: &'a RefCell<Vec<Access>>) -> Recorder<'a, T> {
+        Recorder { inner, log }
+    }
+
+    fn record(&self, kind: AccessKind, addr: u16, value: u8) {
+        self.log.borrow_mut().push(Access { kind, addr, value });
+    }
+}
+
+impl<'a, T: Mmu> Mmu for Recorder<'a, T> {
+    fn read_byte(&self, addr: u16) -> u8 {
+        let value = self.inner.read_byte(addr);
+        self.record(AccessKind::MemoryRead, addr, value);
+        value
+    }
+
+    fn write_byte(&mut self, addr: u16, value: u8) {
+        self.record(AccessKind::MemoryWrite, addr, value);
+        self.inner.write_byte(addr, value);
+    }
+
+    fn rom_len(&self) -> usize {
+        self.inner.rom_len()
+    }
+
+    fn load_byte(&mut self, addr: u16, value: u8) {
+        self.inner.load_byte(addr, value)
+    }
+
+    fn port_write(&mut self, port: u8, value: u8) -> bool {
+        let handled = self.inner.port_write(port, value);
+        if handled {
+            self.record(AccessKind::PortOut, u16::from(port), value);
+        }
+        handled
+    }
+}
+
+impl<'a, U: IO> IO for Recorder<'a, U> {
+    fn read_port(&self, port: u8) -> u8 {
+        let value = self.inner.read_port(port);
+        self.record(AccessKind::PortIn, u16::from(port), value);
+        value
+    }
+
+    fn write_port(&mut self, port: u8, value: u8) {
+        self.record(AccessKind::PortOut, u16::from(port), value);
+        self.inner.write_port(port, value);
+    }
+
+    fn clock(&mut self, cycles: u64, interrupts: &mut InterruptController) {
+        self.inner.clock(cycles, interrupts);
+    }
+
+    fn acknowledge_interrupt(&mut self) -> Option<Instruction> {
+        self.inner.acknowledge_interrupt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Access, AccessKind, CsvTracer, JsonLinesTracer, TraceRecord, Tracer};
+    use crate::{mmu::Mmu, symbols::SymbolTable, Emulator};
+    use std::{cell::RefCell, rc::Rc};
+
+    #[test]
+    fn records_state_and_accesses() {
+        let bytecode = [
+            0x21, 0x00, 0x20, // LXI H, 0x2000
+            0x7e, // MOV A,M
+            0x77, // MOV M,A
+            0xd3, 0x04, // OUT 4
+        ];
+        let records = Rc::new(RefCell::new(Vec::<TraceRecord>::new()));
+        let mut system = Emulator::new(bytecode).unwrap();
+        system.add_tracer(records.clone());
+        system.mmu_mut().write_byte(0x2000, 0x5a);
+        system.run();
+
+        let records = records.borrow();
+        assert_eq!(records.len(), 4);
+        assert_eq!(records[0].bytes, vec![0x21, 0x00, 0x20]);
+        assert_eq!(records[1].pc, 0x0003);
+        assert_eq!(records[1].registers.h, 0x20);
+        assert_eq!(
+            records[1].accesses,
+            vec![Access {
+                kind: AccessKind::MemoryRead,
+                addr: 0x2000,
+                value: 0x5a
+            }]
+        );
+        assert_eq!(records[2].accesses[0].kind, AccessKind::MemoryWrite);
+        assert_eq!(
+            records[3].accesses,
+            vec![Access {
+                kind: AccessKind::PortOut,
+                addr: 0x04,
+                value: 0x5a
+            }]
+        );
+    }
+
+    #[test]
+    fn writes_json_lines() {
+        let bytecode = [0x3e, 0x12]; // MVI A, 0x12
+        let records = Rc::new(RefCell::new(Vec::<TraceRecord>::new()));
+        let mut system = Emulator::new(bytecode).unwrap();
+        system.add_tracer(records.clone());
+        system.run();
+
+        let mut tracer = JsonLinesTracer::new(Vec::new());
+        tracer.trace(&records.borrow()[0]).unwrap();
+        assert_eq!(
+            String::from_utf8(tracer.out).unwrap(),
+            concat!(
+                r#"{"step":0,"pc":"0000","bytes":"3e12","instruction":"MVI    A, 0x12","#,
+                r#""interrupt":false,"cycles":7,"a":"00","f":"02","b":"00","c":"00","d":"00","e":"00","#,
+                r#""h":"00","l":"00","sp":"0000","accesses":[]}"#,
+                "\n"
+            )
+        );
+    }
+
+    #[test]
+    fn escapes_symbol_names() {
+        let bytecode = [0x3e, 0x12]; // MVI A, 0x12
+        let records = Rc::new(RefCell::new(Vec::<TraceRecord>::new()));
+        let mut system = Emulator::new(bytecode).unwrap();
+        system.add_tracer(records.clone());
+        system.run();
+        let mut symbols = SymbolTable::new();
+        symbols.insert(r#"say "hi", \ now"#, 0x0000);
+        let symbols = Rc::new(symbols);
+
+        let mut json = JsonLinesTracer::new(Vec::new()).with_symbols(symbols.clone());
+        json.trace(&records.borrow()[0]).unwrap();
+        let json = String::from_utf8(json.out).unwrap();
+        assert!(json.contains(r#""symbol":"say \"hi\", \\ now","bytes""#));
+
+        let mut csv = CsvTracer::new(Vec::new()).with_symbols(symbols);
+        csv.trace(&records.borrow()[0]).unwrap();
+        let csv = String::from_utf8(csv.out).unwrap();
+        let row = csv.lines().nth(1).unwrap();
+        assert!(row.starts_with(r#"0,0000,"say ""hi"", \ now",3e12,"MVI    A, 0x12",false,"#));
+    }
+}
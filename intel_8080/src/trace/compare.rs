@@ -0,0 +1,520 @@
+//! Finds the first step where two execution traces disagree.
+//!
+//! Each non-empty line of a trace is one step. Lines are read in any of:
+//!
+//! * the JSON lines written by `JsonLinesTracer`,
+//! * CSV with a header row naming the columns, such as `CsvTracer` output,
+//! * free-form `key: value` or `key=value` text, e.g.
+//!   `PC: 0100, AF: 0002, BC: 0000, DE: 0000, HL: 0000, SP: f000`.
+//!
+//! Recognised keys are `pc`, `sp`, `a`, `f`, `b`, `c`, `d`, `e`, `h`, `l` and
+//! the pairs `af`/`psw`, `bc`, `de` and `hl`, in any case. Values are hex, with
+//! an optional `0x` prefix or `h` suffix. Only registers present in both lines
+//! are compared, and the undefined bits of F are ignored.
+
+use failure::Error;
+use std::{
+    collections::VecDeque,
+    fmt::{self, Display},
+    io::BufRead,
+};
+
+/// Defined bits of the flag register: S, Z, AC, P and CY.
+const FLAG_MASK: u16 = 0xd5;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Field {
+    Pc,
+    Sp,
+    A,
+    F,
+    B,
+    C,
+    D,
+    E,
+    H,
+    L,
+}
+
+impl Field {
+    fn width(self) -> usize {
+        match self {
+            Field::Pc | Field::Sp => 4,
+            _ => 2,
+        }
+    }
+}
+
+impl Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Field::Pc => "PC",
+            Field::Sp => "SP",
+            Field::A => "A",
+            Field::F => "F",
+            Field::B => "B",
+            Field::C => "C",
+            Field::D => "D",
+            Field::E => "E",
+            Field::H => "H",
+            Field::L => "L",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Register values parsed from a single trace line.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TraceState {
+    fields: Vec<(Field, u16)>,
+}
+
+impl TraceState {
+    pub fn get(&self, field: Field) -> Option<u16> {
+        self.fields
+            .iter()
+            .find(|(f, _)| *f == field)
+            .map(|(_, v)| *v)
+    }
+
+    fn set(&mut self, field: Field, value: u16) {
+        let value = match field {
+            Field::F => value & FLAG_MASK,
+            _ => value,
+        };
+        match self.fields.iter_mut().find(|(f, _)| *f == field) {
+            Some(entry) => entry.1 = value,
+            None => self.fields.push((field, value)),
+        }
+    }
+
+    fn insert(&mut self, key: &str, value: &str) {
+        let value = match parse_hex(value) {
+            Some(v) => v,
+            None => return,
+        };
+        let (high, low) = ((value >> 8) & 0xff, value & 0xff);
+        match key.to_ascii_lowercase().as_str() {
+            "pc" => self.set(Field::Pc, value),
+            "sp" => self.set(Field::Sp, value),
+            "a" => self.set(Field::A, value),
+            "f" => self.set(Field::F, value),
+            "b" => self.set(Field::B, value),
+            "c" => self.set(Field::C, value),
+            "d" => self.set(Field::D, value),
+            "e" => self.set(Field::E, value),
+            "h" => self.set(Field::H, value),
+            "l" => self.set(Field::L, value),
+            "af" | "psw" => {
+                self.set(Field::A, high);
+                self.set(Field::F, low);
+            }
+            "bc" => {
+                self.set(Field::B, high);
+                self.set(Field::C, low);
+            }
+            "de" => {
+                self.set(Field::D, high);
+                self.set(Field::E, low);
+            }
+            "hl" => {
+                self.set(Field::H, high);
+                self.set(Field::L, low);
+            }
+            _ => {}
+        }
+    }
+
+    /// Fields present in both states whose values differ.
+    pub fn differences(&self, other: &TraceState) -> Vec<Difference> {
+        let mut differences: Vec<Difference> = self
+            .fields
+            .iter()
+            .filter_map(|&(field, ours)| match other.get(field) {
+                Some(reference) if reference != ours => Some(Difference {
+                    field,
+                    ours,
+                    reference,
+                }),
+                _ => None,
+            })
+            .collect();
+        differences.sort_by_key(|d| d.field);
+        differences
+    }
+}
+
+fn parse_hex(value: &str) -> Option<u16> {
+    let value = value.trim();
+    let value = value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+        .unwrap_or(value);
+    let value = value
+        .strip_suffix('h')
+        .or_else(|| value.strip_suffix('H'))
+        .unwrap_or(value);
+    u16::from_str_radix(value, 16).ok()
+}
+
+/// Splits a JSON object line into its top level `"key": value` pairs.
+fn json_pairs(line: &str) -> Vec<(String, String)> {
+    let mut pairs = Vec::new();
+    let mut chars = line.trim().trim_start_matches('{').chars().peekable();
+    let read_string = |chars: &mut std::iter::Peekable<std::str::Chars>| {
+        let mut s = String::new();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => {
+                    if let Some(escaped) = chars.next() {
+                        s.push(escaped);
+                    }
+                }
+                '"' => break,
+                c => s.push(c),
+            }
+        }
+        s
+    };
+    while let Some(c) = chars.next() {
+        if c != '"' {
+            continue;
+        }
+        let key = read_string(&mut chars);
+        while let Some(' ') | Some(':') = chars.peek() {
+            chars.next();
+        }
+        let value = match chars.peek() {
+            Some('"') => {
+                chars.next();
+                read_string(&mut chars)
+            }
+            Some('[') => {
+                // Nested arrays hold accesses, which are not compared.
+                let mut depth = 0;
+                for c in chars.by_ref() {
+                    match c {
+                        '[' => depth += 1,
+                        ']' if depth == 1 => break,
+                        ']' => depth -= 1,
+                        _ => {}
+                    }
+                }
+                continue;
+            }
+            _ => chars
+                .by_ref()
+                .take_while(|&c| c != ',' && c != '}')
+                .collect(),
+        };
+        pairs.push((key, value));
+    }
+    pairs
+}
+
+/// Splits free-form text into `key: value` / `key=value` pairs.
+fn text_pairs(line: &str) -> Vec<(String, String)> {
+    let mut pairs = Vec::new();
+    let mut tokens = line
+        .split(|c: char| c.is_whitespace() || c == ',' || c == '|')
+        .filter(|t| !t.is_empty());
+    while let Some(token) = tokens.next() {
+        match token.find([':', '=']) {
+            Some(i) if i + 1 == token.len() => {
+                if let Some(value) = tokens.next() {
+                    pairs.push((token[..i].to_string(), value.to_string()));
+                }
+            }
+            Some(i) => pairs.push((token[..i].to_string(), token[i + 1..].to_string())),
+            None => {}
+        }
+    }
+    pairs
+}
+
+/// Splits a CSV row, honouring double quoted fields and the doubled quotes
+/// inside them.
+fn split_csv(line: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                fields.last_mut().unwrap().push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(String::new()),
+            c => fields.last_mut().unwrap().push(c),
+        }
+    }
+    fields
+}
+
+/// Reads one trace, keeping track of a CSV header when there is one.
+struct TraceReader<R: BufRead> {
+    lines: std::io::Lines<R>,
+    number: usize,
+    header: Option<Vec<String>>,
+}
+
+impl<R: BufRead> TraceReader<R> {
+    fn new(input: R) -> TraceReader<R> {
+        TraceReader {
+            lines: input.lines(),
+            number: 0,
+            header: None,
+        }
+    }
+
+    fn parse(&mut self, text: &str) -> TraceState {
+        let trimmed = text.trim();
+        let pairs = if trimmed.starts_with('{') {
+            json_pairs(trimmed)
+        } else if let Some(header) = &self.header {
+            header.iter().cloned().zip(split_csv(trimmed)).collect()
+        } else if !trimmed.contains([':', '=']) && trimmed.contains(',') {
+            let columns: Vec<String> = trimmed.split(',').map(|c| c.trim().to_string()).collect();
+            if columns.iter().any(|c| c.eq_ignore_ascii_case("pc")) {
+                self.header = Some(columns);
+            }
+            Vec::new()
+        } else {
+            text_pairs(trimmed)
+        };
+        let mut state = TraceState::default();
+        for (key, value) in pairs {
+            state.insert(&key, &value);
+        }
+        state
+    }
+
+    /// Returns the next line that carries a PC.
+    fn next_step(&mut self) -> Result<Option<TraceLine>, Error> {
+        while let Some(text) = self.lines.next() {
+            let text = text?;
+            self.number += 1;
+            let state = self.parse(&text);
+            if state.get(Field::Pc).is_some() {
+                return Ok(Some(TraceLine {
+                    number: self.number,
+                    text,
+                    state,
+                }));
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceLine {
+    /// 1 based line number in the trace file.
+    pub number: usize,
+    pub text: String,
+    pub state: TraceState,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Difference {
+    pub field: Field,
+    pub ours: u16,
+    pub reference: u16,
+}
+
+impl Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let width = self.field.width();
+        write!(
+            f,
+            "{}: ours {:0w$x}, reference {:0w$x}",
+            self.field,
+            self.ours,
+            self.reference,
+            w = width
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Divergence {
+    /// 0 based index of the first step that differs.
+    pub step: usize,
+    pub ours: TraceLine,
+    pub reference: TraceLine,
+    pub differences: Vec<Difference>,
+    /// Matching steps leading up to the divergence, oldest first.
+    pub before: Vec<(TraceLine, TraceLine)>,
+    /// Steps following the divergence, as far as both traces go.
+    pub after: Vec<(TraceLine, TraceLine)>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Side {
+    Ours,
+    Reference,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Comparison {
+    /// Both traces have the same number of steps and agree on all of them.
+    Identical {
+        steps: usize,
+    },
+    /// The traces agree for `steps` steps, after which `shorter` ends.
+    Truncated {
+        steps: usize,
+        shorter: Side,
+    },
+    Diverged(Box<Divergence>),
+}
+
+/// Compares two traces step by step, returning the first divergence along
+/// with up to `context` steps on either side of it.
+pub fn compare<A: BufRead, B: BufRead>(
+    ours: A,
+    reference: B,
+    context: usize,
+) -> Result<Comparison, Error> {
+    let mut ours = TraceReader::new(ours);
+    let mut reference = TraceReader::new(reference);
+    let mut before = VecDeque::with_capacity(context + 1);
+    let mut step = 0;
+    loop {
+        let (o, r) = match (ours.next_step()?, reference.next_step()?) {
+            (Some(o), Some(r)) => (o, r),
+            (None, None) => return Ok(Comparison::Identical { steps: step }),
+            (None, Some(_)) => {
+                return Ok(Comparison::Truncated {
+                    steps: step,
+                    shorter: Side::Ours,
+                })
+            }
+            (Some(_), None) => {
+                return Ok(Comparison::Truncated {
+                    steps: step,
+                    shorter: Side::Reference,
+                })
+            }
+        };
+        let differences = o.state.differences(&r.state);
+        if !differences.is_empty() {
+            let mut after = Vec::with_capacity(context);
+            while after.len() < context {
+                match (ours.next_step()?, reference.next_step()?) {
+                    (Some(o), Some(r)) => after.push((o, r)),
+                    _ => break,
+                }
+            }
+            return Ok(Comparison::Diverged(Box::new(Divergence {
+                step,
+                ours: o,
+                reference: r,
+                differences,
+                before: before.into_iter().collect(),
+                after,
+            })));
+        }
+        if context > 0 {
+            if before.len() == context {
+                before.pop_front();
+            }
+            before.push_back((o, r));
+        }
+        step += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compare, split_csv, Comparison, Field, Side, TraceReader};
+    use std::io::Cursor;
+
+    fn parse(line: &str) -> super::TraceState {
+        TraceReader::new(Cursor::new("")).parse(line)
+    }
+
+    #[test]
+    fn parses_text_formats() {
+        let state = parse("PC: 0100, AF: 12d7, BC: 0304, DE: 0506, HL: 0708, SP: f000");
+        assert_eq!(state.get(Field::Pc), Some(0x0100));
+        assert_eq!(state.get(Field::A), Some(0x12));
+        assert_eq!(state.get(Field::F), Some(0xd5));
+        assert_eq!(state.get(Field::L), Some(0x08));
+        assert_eq!(state.get(Field::Sp), Some(0xf000));
+
+        let state = parse("A:01 F:02 B:ff pc=0x0040 sp=23feh");
+        assert_eq!(state.get(Field::Pc), Some(0x0040));
+        assert_eq!(state.get(Field::B), Some(0xff));
+        assert_eq!(state.get(Field::Sp), Some(0x23fe));
+        assert_eq!(state.get(Field::C), None);
+    }
+
+    #[test]
+    fn parses_json_lines() {
+        let state = parse(concat!(
+            r#"{"step":3,"pc":"0003","bytes":"c3d418","instruction":"JMP    0x18d4","#,
+            r#""interrupt":false,"a":"0a","f":"02","b":"00","c":"00","d":"00","e":"00","#,
+            r#""h":"00","l":"00","sp":"2400","accesses":[{"kind":"read","addr":"2000","value":"aa"}]}"#
+        ));
+        assert_eq!(state.get(Field::Pc), Some(0x0003));
+        assert_eq!(state.get(Field::A), Some(0x0a));
+        assert_eq!(state.get(Field::Sp), Some(0x2400));
+    }
+
+    #[test]
+    fn parses_quoted_csv_fields() {
+        assert_eq!(
+            split_csv(r#"1,"say ""hi"", \ then",0002"#),
+            ["1", r#"say "hi", \ then"#, "0002"]
+        );
+        let mut reader = TraceReader::new(Cursor::new(""));
+        reader.parse("step,symbol,pc,a");
+        let state = reader.parse(r#"0,"x,""y",0100,05"#);
+        assert_eq!(state.get(Field::Pc), Some(0x0100));
+        assert_eq!(state.get(Field::A), Some(0x05));
+    }
+
+    #[test]
+    fn reports_first_divergence() {
+        let ours = "PC:0000 A:00\nPC:0001 A:00\nPC:0002 A:01\nPC:0003 A:02\n";
+        let reference = concat!(
+            "step,pc,instruction,a\n",
+            "0,0000,\"NOP\",00\n",
+            "1,0001,\"MVI    A, 0x02\",00\n",
+            "2,0002,\"NOP\",02\n",
+            "3,0004,\"NOP\",03\n"
+        );
+        let comparison = compare(Cursor::new(ours), Cursor::new(reference), 1).unwrap();
+        let divergence = match comparison {
+            Comparison::Diverged(d) => d,
+            c => panic!("expected a divergence, got {:?}", c),
+        };
+        assert_eq!(divergence.step, 2);
+        assert_eq!(divergence.ours.number, 3);
+        assert_eq!(divergence.reference.number, 4);
+        assert_eq!(divergence.differences.len(), 1);
+        assert_eq!(divergence.differences[0].field, Field::A);
+        assert_eq!(divergence.before.len(), 1);
+        assert_eq!(divergence.before[0].0.text, "PC:0001 A:00");
+        assert_eq!(divergence.after.len(), 1);
+    }
+
+    #[test]
+    fn reports_truncation() {
+        let ours = "PC:0000\nPC:0001\n";
+        let reference = "PC:0000\n";
+        assert_eq!(
+            compare(Cursor::new(ours), Cursor::new(reference), 3).unwrap(),
+            Comparison::Truncated {
+                steps: 1,
+                shorter: Side::Reference
+            }
+        );
+        assert_eq!(
+            compare(Cursor::new(ours), Cursor::new(ours), 3).unwrap(),
+            Comparison::Identical { steps: 2 }
+        );
+    }
+}
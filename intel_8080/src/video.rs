@@ -0,0 +1,237 @@
+//! The Midway 8080 video hardware.
+//!
+//! The board shifts VRAM at 0x2400-0x3fff out to the monitor one bit per
+//! pixel, 32 bytes to a scan line, least significant bit first. The monitor
+//! is mounted on its side, turned 90° anticlockwise, so each scan line
+//! becomes a column of the picture running from the bottom up: the player
+//! sees 224 columns of 256 pixels.
+//!
+//! The beam also times the game: the board interrupts the 2 MHz CPU with
+//! RST 1 when the beam reaches line 96 of the picture, mid-screen, and RST
+//! 2 when it reaches line 224 and vertical blanking starts, 60 times a
+//! second. `Beam` keeps that schedule, and `BasicIO` raises the interrupts
+//! as the emulator clocks it.
+
+use crate::{
+    instruction::{Instruction, Opcode},
+    mmu::Mmu,
+};
+
+use failure::Error;
+use std::{fs::File, io::BufWriter, io::Write, path::Path};
+
+/// Width of the picture as the player sees it.
+pub const WIDTH: usize = 224;
+/// Height of the picture as the player sees it.
+pub const HEIGHT: usize = 256;
+
+pub const VRAM_START: u16 = 0x2400;
+pub const VRAM_LEN: usize = WIDTH * HEIGHT / 8;
+/// Bytes in a scan line, which becomes a column of the picture.
+const LINE_LEN: usize = HEIGHT / 8;
+
+/// RGBA of lit and dark pixels.
+const ON: [u8; 4] = [0xff, 0xff, 0xff, 0xff];
+const OFF: [u8; 4] = [0x00, 0x00, 0x00, 0xff];
+
+pub const CPU_HZ: u64 = 2_000_000;
+pub const FRAME_HZ: u64 = 60;
+/// Scan lines in a frame, counting those in vertical blanking.
+pub const LINES: u64 = 262;
+pub const MID_SCREEN_LINE: u64 = 96;
+pub const VBLANK_LINE: u64 = 224;
+
+/// Where the beam is in the frame, in CPU cycles, and which interrupts it
+/// has raised on the way.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Beam {
+    frame: u64,
+    position: u64,
+    /// Interrupts raised so far this frame.
+    raised: u8,
+}
+
+impl Beam {
+    /// Frames completed.
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    /// The scan line the beam is on.
+    pub fn line(&self) -> u64 {
+        self.position * LINES / self.frame_len()
+    }
+
+    /// Cycles in the current frame. Frames are 33333 or 33334 cycles long,
+    /// so that 60 of them take exactly a second.
+    fn frame_len(&self) -> u64 {
+        (self.frame + 1) * CPU_HZ / FRAME_HZ - self.frame * CPU_HZ / FRAME_HZ
+    }
+
+    /// Moves the beam on by `cycles`. Returns the interrupt raised by the
+    /// line it reaches, if any.
+    pub fn advance(&mut self, cycles: u64) -> Option<Instruction> {
+        self.position += cycles;
+        while self.position >= self.frame_len() {
+            self.position -= self.frame_len();
+            self.frame += 1;
+            self.raised = 0;
+        }
+        let rst = match (self.raised, self.line()) {
+            (0, line) if line >= MID_SCREEN_LINE => 1,
+            (1, line) if line >= VBLANK_LINE => 2,
+            _ => return None,
+        };
+        self.raised += 1;
+        Some(Instruction::new_unary(Opcode::RST(rst)).unwrap())
+    }
+}
+
+/// One picture, upright, as one byte per pixel: 1 lit and 0 dark. Rows run
+/// from the top of the screen.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    pixels: Vec<u8>,
+}
+
+impl Frame {
+    /// Renders a frame from the contents of VRAM.
+    ///
+    /// #Panics
+    /// Panics if `vram` is not `VRAM_LEN` bytes long.
+    pub fn from_vram(vram: &[u8]) -> Frame {
+        assert_eq!(vram.len(), VRAM_LEN, "VRAM is {} bytes", VRAM_LEN);
+        let mut pixels = vec![0; WIDTH * HEIGHT];
+        for (i, &byte) in vram.iter().enumerate() {
+            let x = i / LINE_LEN;
+            for bit in 0..8 {
+                let y = HEIGHT - 1 - (i % LINE_LEN * 8 + bit);
+                pixels[y * WIDTH + x] = byte >> bit & 1;
+            }
+        }
+        Frame { pixels }
+    }
+
+    /// Renders the frame held in VRAM of `mmu`.
+    pub fn from_mmu<T: Mmu>(mmu: &T) -> Frame {
+        let vram: Vec<u8> = (0..VRAM_LEN as u16)
+            .map(|offset| mmu.read_byte(VRAM_START + offset))
+            .collect();
+        Frame::from_vram(&vram)
+    }
+
+    /// Whether the pixel `x` from the left and `y` from the top is lit.
+    ///
+    /// #Panics
+    /// Panics if the pixel is off the screen.
+    pub fn pixel(&self, x: usize, y: usize) -> bool {
+        assert!(x < WIDTH && y < HEIGHT, "({}, {}) is off the screen", x, y);
+        self.pixels[y * WIDTH + x] != 0
+    }
+
+    /// The pixels, row by row, as 0 for dark and 1 for lit.
+    pub fn indexed(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// The pixels, row by row, as white on black RGBA.
+    pub fn to_rgba(&self) -> Vec<u8> {
+        self.pixels
+            .iter()
+            .flat_map(|&pixel| match pixel {
+                0 => OFF,
+                _ => ON,
+            })
+            .collect()
+    }
+
+    /// Encodes the frame as an RGBA PNG.
+    ///
+    /// #Errors
+    /// Fails if writing to `out` fails.
+    pub fn write_png<W: Write>(&self, out: W) -> Result<(), Error> {
+        let mut encoder = png::Encoder::new(out, WIDTH as u32, HEIGHT as u32);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.to_rgba())?;
+        writer.finish()?;
+        Ok(())
+    }
+
+    /// Saves the frame as a PNG screenshot.
+    ///
+    /// #Errors
+    /// Fails if the file cannot be written.
+    pub fn save_png<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        self.write_png(BufWriter::new(File::create(path)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Frame, HEIGHT, VRAM_LEN, WIDTH};
+    use crate::{mmu::MemoryAccess, Emulator};
+    use std::{cell::RefCell, rc::Rc};
+
+    #[test]
+    fn turns_scan_lines_into_columns() {
+        let mut vram = vec![0; VRAM_LEN];
+        // First pixel of the first scan line: bottom left.
+        vram[0] = 0x01;
+        // Last pixel of the first scan line: top left.
+        vram[31] = 0x80;
+        // Pixel 10 of the last scan line: right edge, 10 up from the bottom.
+        vram[VRAM_LEN - 32 + 1] = 0x04;
+        let frame = Frame::from_vram(&vram);
+        assert!(frame.pixel(0, HEIGHT - 1));
+        assert!(frame.pixel(0, 0));
+        assert!(frame.pixel(WIDTH - 1, HEIGHT - 11));
+        assert_eq!(frame.indexed().iter().filter(|&&p| p != 0).count(), 3);
+        let rgba = frame.to_rgba();
+        assert_eq!(rgba.len(), WIDTH * HEIGHT * 4);
+        assert_eq!(rgba[..8], [0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0xff]);
+
+        let mut png = Vec::new();
+        frame.write_png(&mut png).unwrap();
+        assert_eq!(png[..8], b"\x89PNG\r\n\x1a\n"[..]);
+    }
+
+    #[test]
+    fn frames_are_not_observed() {
+        let accesses = Rc::new(RefCell::new(Vec::<MemoryAccess>::new()));
+        let mut emulator = Emulator::new([0x76]).unwrap();
+        emulator.add_observer(accesses.clone());
+        emulator.frame();
+        assert!(accesses.borrow().is_empty());
+    }
+
+    #[test]
+    fn interrupts_twice_a_frame() {
+        let mut rom = vec![0; 0x13];
+        rom[..7].copy_from_slice(&[
+            0x31, 0x00, 0x24, // LXI SP,0x2400
+            0xfb, //             EI
+            0xc3, 0x04, 0x00, // JMP 0x0004
+        ]);
+        // RST 1 counts in B and RST 2 in C.
+        rom[0x08..0x0b].copy_from_slice(&[0x04, 0xfb, 0xc9]);
+        rom[0x10..0x13].copy_from_slice(&[0x0c, 0xfb, 0xc9]);
+        let mut emulator = Emulator::new(rom).unwrap();
+
+        while emulator.cpu().registers().b == 0 {
+            emulator.try_step().unwrap();
+        }
+        // Line 96 is 12213 cycles in, and the interrupt waits for the JMP.
+        assert!((12213..12213 + 30).contains(&emulator.cpu().cycles()));
+        assert_eq!(emulator.beam().line(), 96);
+
+        emulator.run_frame().unwrap();
+        emulator.run_frame().unwrap();
+        emulator.run_frame().unwrap();
+        assert_eq!(emulator.beam().frame(), 3);
+        let registers = emulator.cpu().registers();
+        assert_eq!((registers.b, registers.c), (3, 3));
+        assert!((100_000..100_000 + 30).contains(&emulator.cpu().cycles()));
+    }
+}
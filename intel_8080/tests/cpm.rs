@@ -0,0 +1,21 @@
+extern crate i8080_emulator;
+
+use std::fs;
+
+use i8080_emulator::cpm::{BufferConsole, Cpm, Exit};
+
+#[test]
+fn cpu_diagnostic_passes() {
+    let mut program = fs::read("tests/test.rom.org").unwrap();
+
+    // The diagnostic's stack starts at 0x06ad and grows down over its own
+    // scratch variables; move it to 0x07ad.
+    assert_eq!(program[0xad], 0x06);
+    program[0xad] = 0x07;
+
+    let mut cpm = Cpm::new(BufferConsole::new(""));
+    cpm.load_com(&program, "").unwrap();
+
+    assert_eq!(cpm.run().unwrap(), Exit::WarmBoot);
+    assert_eq!(cpm.console().text(), "\x0c\n CPU IS OPERATIONAL");
+}
@@ -6,6 +6,7 @@ use std::io::Read;
 use i8080_emulator::Emulator;
 
 #[test]
+#[allow(clippy::unbuffered_bytes)]
 fn it_works() {
     let file = File::open("tests/test.rom").unwrap();
     let mut bytecode: Vec<u8> = file.bytes().filter_map(|b| b.ok()).collect();
@@ -15,7 +16,7 @@ fn it_works() {
     bytecode[0x59d] = 0xc2;
     bytecode[0x59e] = 0x05;
 
-    let mut emulator = Emulator::new(bytecode);
+    let mut emulator = Emulator::new(bytecode).unwrap();
     for _ in 0..1000 {
         if let Err(e) = emulator.try_step() {
             panic!("{}", e)